@@ -69,6 +69,7 @@ fn main() -> Result<()> {
                     staking_code_id: orc.contract_map.code_id("cw20_stake")?,
                     unstaking_duration: Some(cw_utils::Duration::Time(1209600)),
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
                 active_threshold: None,
             })?,
@@ -96,6 +97,8 @@ fn main() -> Result<()> {
                                 refund_policy: DepositRefundPolicy::OnlyPassed,
                             }),
                             open_proposal_submission: false,
+                            deposit_min_lock: None,
+                            dao_reimburses_passed_deposits: false,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -104,6 +107,25 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                max_execution_attempts: None,
+                max_msgs_per_execution: None,
+                audited_only: false,
+                max_proposal_msgs: None,
+                text_limits: None,
+                min_proposer_power: None,
+                tie_policy: None,
+                allow_self_dao: false,
+                blackout: None,
+                best_effort_execution: false,
+                unstake_cooldown: None,
+                proposer_must_execute: false,
+                proposer_execution_grace_period: None,
+                proposer_nft_gate: None,
+                fallback_voting_module: None,
+                require_discussion_url: false,
+                abstain_quorum_weight: None,
+                confirmation_phase: None,
+                execution_delay: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             label: "DAO DAO Proposal Module".to_string(),
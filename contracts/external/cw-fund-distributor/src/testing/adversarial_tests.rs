@@ -96,6 +96,7 @@ fn setup_test(initial_balances: Vec<Cw20Coin>) -> BaseTest {
                     staking_code_id: stake_cw20_id,
                     unstaking_duration: None,
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             },
             &[],
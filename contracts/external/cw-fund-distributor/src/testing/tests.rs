@@ -80,6 +80,7 @@ fn setup_test(initial_balances: Vec<Cw20Coin>) -> BaseTest {
                     staking_code_id: stake_cw20_id,
                     unstaking_duration: None,
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             },
             &[],
@@ -289,6 +290,7 @@ fn test_instantiate_fails_zero_voting_power() {
                     staking_code_id: stake_cw20_id,
                     unstaking_duration: None,
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             },
             &[],
@@ -97,6 +97,7 @@ pub fn get_cw20_init_msg(code_ids: CodeIds) -> cw20_staked_balance_voting_v1::ms
             staking_code_id: code_ids.cw20_stake,
             unstaking_duration: None,
             initial_dao_balance: Some(Uint128::new(100)),
+            auto_stake: false,
         },
         active_threshold: None,
     }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+use cosmwasm_std::{Addr, Deps, StdResult, Timestamp, Uint128};
 
 use crate::{types::SingleProposalData, ContractError};
 
@@ -83,6 +83,17 @@ pub fn query_proposal_v1(
                 status: v1_status_to_v2(proposal.status),
                 votes: v1_votes_to_v2(proposal.votes),
                 allow_revoting: proposal.allow_revoting,
+                execution_attempts: 0,
+                execution_height: None,
+                executed_msgs: 0,
+                only_members_execute: None,
+                created_at: Timestamp::default(),
+                precondition: None,
+                tie_policy: None,
+                discussion_url: None,
+                abstain_quorum_weight: None,
+                phase: None,
+                passed_at: None,
             })
         })
         .collect::<Result<Vec<dao_proposal_single::proposal::SingleChoiceProposal>, ContractError>>(
@@ -91,11 +91,17 @@ pub fn execute_propose(
             title,
             description,
             msgs,
+            precondition,
+            proposal_duration,
+            discussion_url,
         } => ProposeMsg {
             title,
             description,
             msgs,
             proposer: Some(info.sender.to_string()),
+            precondition,
+            proposal_duration,
+            discussion_url,
         },
     };
 
@@ -119,6 +125,8 @@ pub fn execute_propose(
                 Ok(SubMsg::new(execute_msg))
             })?;
 
+    pre_propose_base.add_held_deposit(deps.storage, &config.deposit_info)?;
+
     // Save the proposal and its information as pending.
     PENDING_PROPOSALS.save(
         deps.storage,
@@ -202,17 +210,20 @@ pub fn execute_reject(
         .ok_or(PreProposeError::ProposalNotFound {})?;
 
     PENDING_PROPOSALS.remove(deps.storage, id);
+    PrePropose::default().remove_held_deposit(deps.storage, &deposit)?;
 
     let messages = if let Some(ref deposit_info) = deposit {
         // Refund can be issued if proposal if deposits are always
         // refunded. `OnlyPassed` and `Never` refund deposit policies
         // do not apply here.
         if deposit_info.refund_policy == DepositRefundPolicy::Always {
+            PrePropose::default().record_refunded_deposit(deps.storage, deposit_info)?;
             deposit_info.get_return_deposit_message(&proposer)?
         } else {
             // If the proposer doesn't get the deposit, the DAO does.
             let dao = PrePropose::default().dao.load(deps.storage)?;
-            deposit_info.get_return_deposit_message(&dao)?
+            PrePropose::default().record_forfeited_deposit(deps.storage, deposit_info)?;
+            deposit_info.get_forfeit_deposit_messages(&proposer, &dao)?
         }
     } else {
         vec![]
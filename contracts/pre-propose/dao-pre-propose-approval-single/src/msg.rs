@@ -1,9 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
 use dao_pre_propose_base::msg::{
     ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
 };
-use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+use dao_voting::{precondition::Precondition, proposal::SingleChoiceProposeMsg as ProposeMsg};
 
 #[cw_serde]
 pub enum ApproverProposeMessage {
@@ -20,6 +21,9 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        precondition: Option<Precondition>,
+        proposal_duration: Option<Duration>,
+        discussion_url: Option<String>,
     },
 }
 
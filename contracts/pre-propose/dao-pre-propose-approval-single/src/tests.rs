@@ -65,6 +65,8 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    deposit_min_lock: None,
+                    dao_reimburses_passed_deposits: false,
                     extension: InstantiateExt {
                         approver: "approver".to_string(),
                     },
@@ -75,6 +77,25 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     }
 }
 
@@ -182,6 +203,9 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             },
         },
         funds,
@@ -307,6 +331,8 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -328,6 +354,8 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -998,6 +1026,9 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
                 },
             },
             &[],
@@ -1146,6 +1177,9 @@ fn test_no_deposit_required_members_submission() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
                 },
             },
             &[],
@@ -1194,6 +1228,8 @@ fn test_instantiate_with_zero_native_deposit() {
                             refund_policy: DepositRefundPolicy::OnlyPassed,
                         }),
                         open_proposal_submission: false,
+                        deposit_min_lock: None,
+                        dao_reimburses_passed_deposits: false,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
                         },
@@ -1204,6 +1240,25 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         }
     };
 
@@ -1257,6 +1312,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             refund_policy: DepositRefundPolicy::OnlyPassed,
                         }),
                         open_proposal_submission: false,
+                        deposit_min_lock: None,
+                        dao_reimburses_passed_deposits: false,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
                         },
@@ -1267,6 +1324,25 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         }
     };
 
@@ -1302,7 +1378,9 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1332,9 +1410,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             open_proposal_submission: true,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1368,7 +1449,8 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
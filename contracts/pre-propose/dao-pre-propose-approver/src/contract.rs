@@ -35,6 +35,8 @@ pub fn instantiate(
     let base_instantiate_msg = BaseInstantiateMsg {
         deposit_info: None,
         open_proposal_submission: false,
+        deposit_min_lock: None,
+        dao_reimburses_passed_deposits: false,
         extension: Empty {},
     };
     // Default pre-propose-base instantiation
@@ -89,6 +89,8 @@ fn get_proposal_module_approval_single_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    deposit_min_lock: None,
+                    dao_reimburses_passed_deposits: false,
                     extension: InstantiateExt {
                         approver: APPROVER.to_string(),
                     },
@@ -99,6 +101,25 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     }
 }
 
@@ -130,6 +151,25 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     }
 }
 
@@ -301,6 +341,9 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             },
         },
         funds,
@@ -450,6 +493,8 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -471,6 +516,8 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -1143,6 +1190,9 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
                 },
             },
             &[],
@@ -1281,7 +1331,9 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1318,9 +1370,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             open_proposal_submission: true,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1356,7 +1411,8 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
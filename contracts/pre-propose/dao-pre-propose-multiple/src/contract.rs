@@ -87,16 +87,31 @@ pub fn execute(
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock,
+            dao_reimburses_passed_deposits,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock,
+            dao_reimburses_passed_deposits,
         },
+        ExecuteMsg::UpdateDepositInfo { deposit_info } => {
+            ExecuteInternal::UpdateDepositInfo { deposit_info }
+        }
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
         }
         ExecuteMsg::RemoveProposalSubmittedHook { address } => {
             ExecuteInternal::RemoveProposalSubmittedHook { address }
         }
+        ExecuteMsg::ClaimDeposit { proposal_id } => ExecuteInternal::ClaimDeposit { proposal_id },
+        ExecuteMsg::ReleaseDeposit {
+            proposal_id,
+            recipient,
+        } => ExecuteInternal::ReleaseDeposit {
+            proposal_id,
+            recipient,
+        },
         ExecuteBase::ProposalCompletedHook {
             proposal_id,
             new_status,
@@ -68,6 +68,8 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    deposit_min_lock: None,
+                    dao_reimburses_passed_deposits: false,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -76,6 +78,13 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     }
 }
 
@@ -361,6 +370,8 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -382,6 +393,8 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -1055,6 +1068,8 @@ fn test_instantiate_with_zero_native_deposit() {
                             refund_policy: DepositRefundPolicy::OnlyPassed,
                         }),
                         open_proposal_submission: false,
+                        deposit_min_lock: None,
+                        dao_reimburses_passed_deposits: false,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1063,6 +1078,13 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         }
     };
 
@@ -1116,6 +1138,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             refund_policy: DepositRefundPolicy::OnlyPassed,
                         }),
                         open_proposal_submission: false,
+                        deposit_min_lock: None,
+                        dao_reimburses_passed_deposits: false,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1124,6 +1148,13 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         }
     };
 
@@ -1159,7 +1190,9 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1192,9 +1225,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             open_proposal_submission: true,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1224,7 +1260,8 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
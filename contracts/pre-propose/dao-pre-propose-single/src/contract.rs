@@ -5,13 +5,14 @@ use cosmwasm_std::{
     Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
 };
 use cw2::set_contract_version;
+use cw_utils::Duration;
 
 use dao_pre_propose_base::{
     error::PreProposeError,
     msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
     state::PreProposeContract,
 };
-use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+use dao_voting::{precondition::Precondition, proposal::SingleChoiceProposeMsg as ProposeMsg};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -26,6 +27,17 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        precondition: Option<Precondition>,
+        proposal_duration: Option<Duration>,
+        discussion_url: Option<String>,
+        /// If true, the deposit of this proposal is not refunded
+        /// automatically. Instead, the proposal's messages are expected
+        /// to move it with a `ReleaseDeposit` message to this
+        /// module. If the deposit has not been released by the time
+        /// the proposal completes, it is handled according to the
+        /// deposit's refund policy.
+        #[serde(default)]
+        manual_deposit_handling: bool,
     },
 }
 
@@ -57,7 +69,7 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
@@ -67,6 +79,7 @@ pub fn execute(
     // we transform an external message which omits that field into an
     // internal message which sets it.
     type ExecuteInternal = ExecuteBase<ProposeMessageInternal, Empty>;
+    let mut manual_deposit_handling = false;
     let internalized = match msg {
         ExecuteMsg::Propose {
             msg:
@@ -74,31 +87,56 @@ pub fn execute(
                     title,
                     description,
                     msgs,
+                    precondition,
+                    proposal_duration,
+                    discussion_url,
+                    manual_deposit_handling: manual,
                 },
-        } => ExecuteInternal::Propose {
-            msg: ProposeMessageInternal::Propose(ProposeMsg {
-                // Fill in proposer based on message sender.
-                proposer: Some(info.sender.to_string()),
-                title,
-                description,
-                msgs,
-            }),
-        },
+        } => {
+            manual_deposit_handling = manual;
+            ExecuteInternal::Propose {
+                msg: ProposeMessageInternal::Propose(ProposeMsg {
+                    // Fill in proposer based on message sender.
+                    proposer: Some(info.sender.to_string()),
+                    title,
+                    description,
+                    msgs,
+                    precondition,
+                    proposal_duration,
+                    discussion_url,
+                }),
+            }
+        }
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock,
+            dao_reimburses_passed_deposits,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock,
+            dao_reimburses_passed_deposits,
         },
+        ExecuteMsg::UpdateDepositInfo { deposit_info } => {
+            ExecuteInternal::UpdateDepositInfo { deposit_info }
+        }
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
         }
         ExecuteMsg::RemoveProposalSubmittedHook { address } => {
             ExecuteInternal::RemoveProposalSubmittedHook { address }
         }
+        ExecuteMsg::ClaimDeposit { proposal_id } => ExecuteInternal::ClaimDeposit { proposal_id },
+        ExecuteMsg::ReleaseDeposit {
+            proposal_id,
+            recipient,
+        } => ExecuteInternal::ReleaseDeposit {
+            proposal_id,
+            recipient,
+        },
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
@@ -108,7 +146,12 @@ pub fn execute(
         },
     };
 
-    PrePropose::default().execute(deps, env, info, internalized)
+    let pre_propose = PrePropose::default();
+    let response = pre_propose.execute(deps.branch(), env, info, internalized)?;
+    if manual_deposit_handling {
+        pre_propose.set_manual_deposit_handling(deps)?;
+    }
+    Ok(response)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -1,17 +1,28 @@
-use cosmwasm_std::{coins, from_slice, to_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, from_slice, to_binary, to_vec, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Response, StdResult, Storage, Uint128, WasmMsg,
+};
 use cps::query::ProposalResponse;
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
-use cw_denom::UncheckedDenom;
+use cw_denom::{CheckedDenom, UncheckedDenom};
 use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_core::state::ProposalModule;
 use dao_interface::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{DepositAccountingResponse, DepositInfoResponse, HeldDeposit, HeldDepositsResponse},
+    state::Config,
+};
 use dao_proposal_single as cps;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, DepositRefundPolicy, DepositToken, StakedDepositExecuteMsg,
+        UncheckedDepositInfo,
+    },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
@@ -66,6 +77,8 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    deposit_min_lock: None,
+                    dao_reimburses_passed_deposits: false,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -74,6 +87,25 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     }
 }
 
@@ -186,6 +218,10 @@ fn make_proposal(
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+                manual_deposit_handling: false,
             },
         },
         funds,
@@ -329,6 +365,20 @@ fn get_deposit_info(app: &App, module: Addr, id: u64) -> DepositInfoResponse {
         .unwrap()
 }
 
+fn get_held_deposits(app: &App, module: Addr) -> Vec<HeldDeposit> {
+    let res: HeldDepositsResponse = app
+        .wrap()
+        .query_wasm_smart(module, &QueryMsg::HeldDeposits {})
+        .unwrap();
+    res.deposits
+}
+
+fn get_deposit_accounting(app: &App, module: Addr) -> DepositAccountingResponse {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::DepositAccounting {})
+        .unwrap()
+}
+
 fn update_config(
     app: &mut App,
     module: Addr,
@@ -342,6 +392,8 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -363,6 +415,8 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         },
         &[],
     )
@@ -662,6 +716,83 @@ fn test_cw20_failed_passed_refund() {
     )
 }
 
+#[test]
+fn test_cw20_insufficient_allowance() {
+    let mut app = App::default();
+
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Cw20(cw20_address.to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+
+    let propose = |app: &mut App| {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
+                    manual_deposit_handling: false,
+                },
+            },
+            &[],
+        )
+    };
+
+    // No allowance at all.
+    propose(&mut app).unwrap_err();
+
+    // An allowance smaller than the deposit.
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(9),
+    );
+    propose(&mut app).unwrap_err();
+
+    // Nothing was taken and no proposal was created.
+    let balance = get_balance_cw20(&app, cw20_address.clone(), "ekez");
+    assert_eq!(balance, Uint128::new(10));
+    let next_id: u64 = app
+        .wrap()
+        .query_wasm_smart(&proposal_single, &cps::msg::QueryMsg::NextProposalId {})
+        .unwrap();
+    assert_eq!(next_id, 1);
+
+    // Topping the allowance up to the deposit amount lets the
+    // proposal through.
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(1),
+    );
+    propose(&mut app).unwrap();
+    let balance = get_balance_cw20(&app, cw20_address, "ekez");
+    assert_eq!(balance, Uint128::zero());
+}
+
 // See: <https://github.com/DA0-DA0/dao-contracts/pull/465#discussion_r960092321>
 #[test]
 fn test_multiple_open_proposals() {
@@ -745,6 +876,673 @@ fn test_multiple_open_proposals() {
     assert_eq!(20, balance.u128());
 }
 
+#[test]
+fn test_held_deposits() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+
+    assert_eq!(get_held_deposits(&app, pre_propose.clone()), vec![]);
+
+    mint_natives(&mut app, "ekez", coins(30, "ujuno"));
+    let ids: Vec<u64> = (0..3)
+        .map(|_| {
+            make_proposal(
+                &mut app,
+                pre_propose.clone(),
+                proposal_single.clone(),
+                "ekez",
+                &coins(10, "ujuno"),
+            )
+        })
+        .collect();
+
+    let held = |app: &App| {
+        let held = get_held_deposits(app, pre_propose.clone());
+        assert_eq!(held.len(), 1);
+        assert_eq!(held[0].denom, CheckedDenom::Native("ujuno".to_string()));
+        held[0].amount.u128()
+    };
+    assert_eq!(held(&app), 30);
+
+    // Passing a proposal does not release its deposit.
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", ids[0], Vote::Yes);
+    assert_eq!(Status::Passed, new_status);
+    assert_eq!(held(&app), 30);
+
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", ids[0]);
+    assert_eq!(held(&app), 20);
+
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", ids[1], Vote::No);
+    assert_eq!(Status::Rejected, new_status);
+    assert_eq!(held(&app), 20);
+
+    close_proposal(&mut app, proposal_single.clone(), "ekez", ids[1]);
+    assert_eq!(held(&app), 10);
+
+    // The held total matches the module's balance.
+    let balance = get_balance_native(&app, pre_propose.as_str(), "ujuno");
+    assert_eq!(balance.u128(), 10);
+
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", ids[2], Vote::No);
+    assert_eq!(Status::Rejected, new_status);
+    close_proposal(&mut app, proposal_single, "ekez", ids[2]);
+    assert_eq!(get_held_deposits(&app, pre_propose), vec![]);
+}
+
+#[test]
+fn test_deposit_accounting() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        }),
+        false,
+    );
+
+    assert_eq!(
+        get_deposit_accounting(&app, pre_propose.clone()),
+        DepositAccountingResponse {
+            total_refunded: vec![],
+            total_forfeited: vec![],
+            currently_held: vec![],
+        }
+    );
+
+    mint_natives(&mut app, "ekez", coins(40, "ujuno"));
+    let ids: Vec<u64> = (0..4)
+        .map(|_| {
+            make_proposal(
+                &mut app,
+                pre_propose.clone(),
+                proposal_single.clone(),
+                "ekez",
+                &coins(10, "ujuno"),
+            )
+        })
+        .collect();
+
+    let ujuno = |amount: u128| {
+        vec![HeldDeposit {
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(amount),
+        }]
+    };
+    let totals = |app: &App| {
+        let res = get_deposit_accounting(app, pre_propose.clone());
+        (res.total_refunded, res.total_forfeited, res.currently_held)
+    };
+    assert_eq!(totals(&app), (vec![], vec![], ujuno(40)));
+
+    // Passed proposals are refunded.
+    vote(&mut app, proposal_single.clone(), "ekez", ids[0], Vote::Yes);
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", ids[0]);
+    assert_eq!(totals(&app), (ujuno(10), vec![], ujuno(30)));
+
+    // Rejected proposals are forfeited.
+    vote(&mut app, proposal_single.clone(), "ekez", ids[1], Vote::No);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", ids[1]);
+    assert_eq!(totals(&app), (ujuno(10), ujuno(10), ujuno(20)));
+
+    vote(&mut app, proposal_single.clone(), "ekez", ids[2], Vote::No);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", ids[2]);
+    assert_eq!(totals(&app), (ujuno(10), ujuno(20), ujuno(10)));
+
+    vote(&mut app, proposal_single.clone(), "ekez", ids[3], Vote::Yes);
+    execute_proposal(&mut app, proposal_single, "ekez", ids[3]);
+    assert_eq!(totals(&app), (ujuno(20), ujuno(20), vec![]));
+
+    // The totals match where the deposits went.
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno").u128(), 20);
+}
+
+#[test]
+fn test_dao_reimburses_passed_deposits() {
+    let mut app = App::default();
+
+    let deposit_info = UncheckedDepositInfo {
+        denom: DepositToken::Token {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+        },
+        amount: Uint128::new(10),
+        refund_policy: DepositRefundPolicy::Never,
+    };
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, Some(deposit_info.clone()), false);
+
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info: Some(deposit_info),
+            open_proposal_submission: false,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: true,
+        },
+        &[],
+    )
+    .unwrap();
+    assert!(get_config(&app, pre_propose.clone()).dao_reimburses_passed_deposits);
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let passed = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let rejected = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let balances = |app: &App| {
+        (
+            get_balance_native(app, "ekez", "ujuno").u128(),
+            get_balance_native(app, core_addr.as_str(), "ujuno").u128(),
+            get_balance_native(app, pre_propose.as_str(), "ujuno").u128(),
+        )
+    };
+    assert_eq!(balances(&app), (0, 0, 20));
+
+    // Despite the refund policy, the proposer of a passed proposal
+    // is made whole and the DAO's balance is unchanged.
+    vote(&mut app, proposal_single.clone(), "ekez", passed, Vote::Yes);
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", passed);
+    assert_eq!(balances(&app), (10, 0, 10));
+
+    // Rejected proposals' deposits still go to the DAO.
+    vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        rejected,
+        Vote::No,
+    );
+    close_proposal(&mut app, proposal_single, "ekez", rejected);
+    assert_eq!(balances(&app), (10, 10, 0));
+
+    let accounting = get_deposit_accounting(&app, pre_propose);
+    let ujuno = vec![HeldDeposit {
+        denom: CheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(10),
+    }];
+    assert_eq!(accounting.total_refunded, ujuno);
+    assert_eq!(accounting.total_forfeited, ujuno);
+}
+
+#[test]
+fn test_manual_deposit_handling() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+
+    let propose_manual = |app: &mut App, msgs: Vec<CosmosMsg>| -> u64 {
+        let id: u64 = app
+            .wrap()
+            .query_wasm_smart(&proposal_single, &cps::msg::QueryMsg::NextProposalId {})
+            .unwrap();
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs,
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
+                    manual_deposit_handling: true,
+                },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap();
+        id
+    };
+
+    // The first proposal sends its own deposit to someone other than
+    // the proposer.
+    let released = propose_manual(
+        &mut app,
+        vec![WasmMsg::Execute {
+            contract_addr: pre_propose.to_string(),
+            msg: to_binary(&ExecuteMsg::ReleaseDeposit {
+                proposal_id: 1,
+                recipient: "keze".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    );
+    assert_eq!(released, 1);
+    // The second does nothing with its deposit.
+    let stranded = propose_manual(&mut app, vec![]);
+
+    let balances = |app: &App| {
+        (
+            get_balance_native(app, "ekez", "ujuno").u128(),
+            get_balance_native(app, "keze", "ujuno").u128(),
+            get_balance_native(app, pre_propose.as_str(), "ujuno").u128(),
+        )
+    };
+    assert_eq!(balances(&app), (0, 0, 20));
+
+    // Only the DAO may release deposits.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::ReleaseDeposit {
+                proposal_id: released,
+                recipient: "ekez".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    // No automatic refund is issued despite the refund policy, the
+    // proposal's message moves the deposit instead.
+    vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        released,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", released);
+    assert_eq!(balances(&app), (0, 10, 10));
+    let deposit = get_deposit_info(&app, pre_propose.clone(), released);
+    assert_eq!(deposit.deposit_info, None);
+
+    // A released deposit may not be released again.
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::ReleaseDeposit {
+                proposal_id: released,
+                recipient: "ekez".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotManualDeposit { id: released });
+
+    // A deposit that is not released by the time its proposal
+    // completes falls back to the refund policy rather than being
+    // stranded.
+    vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        stranded,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, proposal_single, "ekez", stranded);
+    assert_eq!(balances(&app), (10, 10, 0));
+
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr,
+            pre_propose.clone(),
+            &ExecuteMsg::ReleaseDeposit {
+                proposal_id: stranded,
+                recipient: "keze".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotManualDeposit { id: stranded });
+
+    let accounting = get_deposit_accounting(&app, pre_propose);
+    let ujuno = vec![HeldDeposit {
+        denom: CheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(10),
+    }];
+    assert_eq!(accounting.total_refunded, ujuno);
+    assert_eq!(accounting.total_forfeited, ujuno);
+    assert_eq!(accounting.currently_held, vec![]);
+}
+
+#[test]
+fn test_staked_deposit() {
+    // A staking contract that only tracks locked and forfeited stake.
+    #[cw_serde]
+    enum MockStakingQuery {
+        StakedToken {},
+        Locked { owner: String },
+        Forfeited { recipient: String },
+    }
+    fn load(storage: &dyn Storage, key: String) -> Uint128 {
+        storage
+            .get(key.as_bytes())
+            .map(|v| from_slice(&v).unwrap())
+            .unwrap_or_default()
+    }
+    fn mock_instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        token: Addr,
+    ) -> StdResult<Response> {
+        deps.storage.set(b"token", &to_vec(&token)?);
+        Ok(Response::default())
+    }
+    fn mock_execute(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: StakedDepositExecuteMsg,
+    ) -> StdResult<Response> {
+        let (owner, locked) = match msg {
+            StakedDepositExecuteMsg::LockStake { owner, amount } => {
+                let locked = load(deps.storage, format!("locked:{owner}")) + amount;
+                (owner, locked)
+            }
+            StakedDepositExecuteMsg::UnlockStake { owner, amount } => {
+                let locked = load(deps.storage, format!("locked:{owner}")).checked_sub(amount)?;
+                (owner, locked)
+            }
+            StakedDepositExecuteMsg::ForfeitStake {
+                owner,
+                amount,
+                recipient,
+            } => {
+                let key = format!("forfeited:{recipient}");
+                let forfeited = load(deps.storage, key.clone()) + amount;
+                deps.storage.set(key.as_bytes(), &to_vec(&forfeited)?);
+                let locked = load(deps.storage, format!("locked:{owner}")).checked_sub(amount)?;
+                (owner, locked)
+            }
+        };
+        deps.storage
+            .set(format!("locked:{owner}").as_bytes(), &to_vec(&locked)?);
+        Ok(Response::default())
+    }
+    fn mock_query(deps: Deps, _env: Env, msg: MockStakingQuery) -> StdResult<Binary> {
+        match msg {
+            MockStakingQuery::StakedToken {} => {
+                Ok(Binary::from(deps.storage.get(b"token").unwrap()))
+            }
+            MockStakingQuery::Locked { owner } => {
+                to_binary(&load(deps.storage, format!("locked:{owner}")))
+            }
+            MockStakingQuery::Forfeited { recipient } => {
+                to_binary(&load(deps.storage, format!("forfeited:{recipient}")))
+            }
+        }
+    }
+
+    let mut app = App::default();
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+    let staking_id = app.store_code(Box::new(ContractWrapper::new(
+        mock_execute,
+        mock_instantiate,
+        mock_query,
+    )));
+    let staking = app
+        .instantiate_contract(
+            staking_id,
+            Addr::unchecked("ekez"),
+            &cw20_address,
+            &[],
+            "mock staking",
+            None,
+        )
+        .unwrap();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Staked {
+                staking_contract: staking.to_string(),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        }),
+        false,
+    );
+    assert_eq!(
+        get_config(&app, pre_propose.clone()).deposit_info,
+        Some(CheckedDepositInfo {
+            denom: CheckedDenom::Cw20(cw20_address.clone()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            staking_contract: Some(staking.clone()),
+        })
+    );
+
+    let locked = |app: &App| -> u128 {
+        let locked: Uint128 = app
+            .wrap()
+            .query_wasm_smart(
+                &staking,
+                &MockStakingQuery::Locked {
+                    owner: "ekez".to_string(),
+                },
+            )
+            .unwrap();
+        locked.u128()
+    };
+
+    // Proposing locks stake without transferring tokens.
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    assert_eq!(locked(&app), 10);
+    assert_eq!(get_balance_cw20(&app, &cw20_address, "ekez").u128(), 10);
+    assert_eq!(get_held_deposits(&app, pre_propose.clone()), vec![]);
+
+    // Refunds unlock it.
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::Yes);
+    assert_eq!(Status::Passed, new_status);
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", id);
+    assert_eq!(locked(&app), 0);
+
+    // Deposits that are not refunded are forfeited to the DAO.
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    assert_eq!(locked(&app), 10);
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    assert_eq!(Status::Rejected, new_status);
+    close_proposal(&mut app, proposal_single, "ekez", id);
+    assert_eq!(locked(&app), 0);
+    let forfeited: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            &staking,
+            &MockStakingQuery::Forfeited {
+                recipient: core_addr.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(forfeited.u128(), 10);
+
+    // Staked deposits are refunded and forfeited without ever being
+    // held by the module.
+    let deposits = vec![HeldDeposit {
+        denom: CheckedDenom::Cw20(cw20_address),
+        amount: Uint128::new(10),
+    }];
+    assert_eq!(
+        get_deposit_accounting(&app, pre_propose),
+        DepositAccountingResponse {
+            total_refunded: deposits.clone(),
+            total_forfeited: deposits,
+            currently_held: vec![],
+        }
+    );
+}
+
+#[test]
+fn test_update_deposit_info_refunds_old_token() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let old_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+
+    // Only the DAO may update the deposit info.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateDepositInfo { deposit_info: None },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateDepositInfo {
+            deposit_info: Some(UncheckedDepositInfo {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native("uatom".to_string()),
+                },
+                amount: Uint128::new(5),
+                refund_policy: DepositRefundPolicy::Always,
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The rest of the config is unchanged.
+    let config = get_config(&app, pre_propose.clone());
+    assert!(!config.open_proposal_submission);
+    assert_eq!(
+        config.deposit_info,
+        Some(CheckedDepositInfo {
+            denom: CheckedDenom::Native("uatom".to_string()),
+            amount: Uint128::new(5),
+            refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
+        })
+    );
+
+    // New proposals pay the new deposit.
+    mint_natives(&mut app, "ekez", coins(5, "uatom"));
+    let new_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(5, "uatom"),
+    );
+    assert_eq!(get_balance_native(&app, "ekez", "uatom").u128(), 0);
+
+    // The old proposal keeps its deposit and is refunded in the old
+    // token.
+    let info = get_deposit_info(&app, pre_propose, old_id);
+    assert_eq!(
+        info.deposit_info.unwrap().denom,
+        CheckedDenom::Native("ujuno".to_string())
+    );
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", old_id, Vote::No);
+    assert_eq!(Status::Rejected, new_status);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", old_id);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno").u128(), 10);
+    assert_eq!(get_balance_native(&app, "ekez", "uatom").u128(), 0);
+
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", new_id, Vote::No);
+    assert_eq!(Status::Rejected, new_status);
+    close_proposal(&mut app, proposal_single, "ekez", new_id);
+    assert_eq!(get_balance_native(&app, "ekez", "uatom").u128(), 5);
+}
+
 #[test]
 fn test_set_version() {
     let mut app = App::default();
@@ -827,6 +1625,10 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
+                    manual_deposit_handling: false,
                 },
             },
             &[],
@@ -915,6 +1717,10 @@ fn test_no_deposit_required_members_submission() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
+                    manual_deposit_handling: false,
                 },
             },
             &[],
@@ -991,6 +1797,8 @@ fn test_instantiate_with_zero_native_deposit() {
                             refund_policy: DepositRefundPolicy::OnlyPassed,
                         }),
                         open_proposal_submission: false,
+                        deposit_min_lock: None,
+                        dao_reimburses_passed_deposits: false,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -999,6 +1807,25 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         }
     };
 
@@ -1052,6 +1879,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             refund_policy: DepositRefundPolicy::OnlyPassed,
                         }),
                         open_proposal_submission: false,
+                        deposit_min_lock: None,
+                        dao_reimburses_passed_deposits: false,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1060,6 +1889,25 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         }
     };
 
@@ -1095,7 +1943,9 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1128,9 +1978,12 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             open_proposal_submission: true,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -1160,7 +2013,8 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                staking_contract: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult,
-    Storage, SubMsg, WasmMsg,
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
@@ -10,36 +10,52 @@ use cw_hooks::Hooks;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
 use dao_interface::voting::IsActiveResponse;
+use dao_pre_propose_base::msg::DepositInfoResponse;
 use dao_pre_propose_multiple::contract::ExecuteMsg as PreProposeMsg;
 use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
 use dao_vote_hooks::new_vote_hooks;
 use dao_voting::{
+    deposit::CheckedDepositInfo,
     multiple_choice::{
-        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
+        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, TieBreak, VotingStrategy,
+        MAX_NUM_CHOICES,
     },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
+    proposal::{proposal_attributes, ParticipationResponse, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
     },
     status::Status,
-    voting::{get_total_power, get_voting_power, validate_voting_period},
+    text_limits::ProposalTextLimits,
+    threshold::{validate_quorum, PercentageThreshold},
+    voting::{does_vote_count_pass, get_total_power, get_voting_power, validate_voting_period},
 };
 
-use crate::{msg::MigrateMsg, state::CREATION_POLICY};
 use crate::{
+    ibc::{outcome_packet_message, ProposalOutcomePacket},
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        OptionTally, OutcomeBoundsResponse, ProposalConfigResponse, ProposalListResponse,
+        ProposalResponse, ProposalTallyResponse, RunnerupResponse, VoteInfo, VoteListResponse,
+        VoteResponse,
+    },
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        Ballot, Config, OutcomeChannel, BALLOTS, CONFIG, CONNECTED_CHANNELS, LEADER_HISTORY,
+        MAX_RUNOFF_DEPTH, OUTCOME_CHANNEL, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, RUNOFFS,
+        VOTE_HOOKS,
     },
     ContractError,
 };
+use crate::{msg::MigrateMsg, state::CREATION_POLICY};
 
 pub const CONTRACT_NAME: &str = "crates.io:dao-proposal-multiple";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The maximum number of proposals examined by a single
+/// `Participation` query.
+const MAX_PROPOSALS_SCANNED: u64 = 300;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -50,6 +66,16 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.voting_strategy.validate()?;
+    if let Some(min_participation) = &msg.min_participation_for_early_execution {
+        validate_quorum(min_participation)?;
+    }
+    if let Some(none_rejection_quorum) = &msg.none_rejection_quorum {
+        validate_quorum(none_rejection_quorum)?;
+    }
+    validate_max_proposal_msgs(msg.max_proposal_msgs)?;
+    if let Some(text_limits) = &msg.text_limits {
+        text_limits.validate()?;
+    }
 
     let dao = info.sender;
 
@@ -68,6 +94,13 @@ pub fn instantiate(
         allow_revoting: msg.allow_revoting,
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        min_participation_for_early_execution: msg.min_participation_for_early_execution,
+        tie_runoff: msg.tie_runoff,
+        max_proposal_msgs: msg.max_proposal_msgs,
+        text_limits: msg.text_limits,
+        none_rejection_quorum: msg.none_rejection_quorum,
+        tie_break: msg.tie_break.unwrap_or_default(),
+        min_proposer_power: msg.min_proposer_power,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -119,6 +152,13 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            min_participation_for_early_execution,
+            tie_runoff,
+            max_proposal_msgs,
+            text_limits,
+            none_rejection_quorum,
+            tie_break,
+            min_proposer_power,
         } => execute_update_config(
             deps,
             info,
@@ -129,10 +169,20 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            min_participation_for_early_execution,
+            tie_runoff,
+            max_proposal_msgs,
+            text_limits,
+            none_rejection_quorum,
+            tie_break,
+            min_proposer_power,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::UpdateOutcomeChannel { channel } => {
+            execute_update_outcome_channel(deps, info, channel)
+        }
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
@@ -196,7 +246,40 @@ pub fn execute_propose(
         return Err(ContractError::InactiveDao {});
     }
 
-    // Validate options.
+    if let Some(min) = config.min_proposer_power {
+        // Voting power at the current height is the power held at
+        // the end of the previous block, so power acquired in the
+        // block the proposal is created in does not count.
+        let power = get_voting_power(
+            deps.as_ref(),
+            proposer.clone(),
+            &config.dao,
+            Some(env.block.height),
+        )?;
+        if power < min {
+            return Err(ContractError::InsufficientProposerPower { power, min });
+        }
+    }
+
+    if let Some(text_limits) = &config.text_limits {
+        text_limits.check(&title, &description)?;
+    }
+
+    if let Some(max) = config.max_proposal_msgs {
+        for option in &options.options {
+            let count = option.msgs.len() as u64;
+            if count > max {
+                return Err(ContractError::TooManyProposalMsgs { count, max });
+            }
+        }
+    }
+
+    // Validate options. A single "None of the above" option is added
+    // to those given, so they must all be standard options.
+    let num_choices = options.options.len();
+    if num_choices < 2 || num_choices > MAX_NUM_CHOICES as usize {
+        return Err(ContractError::WrongNumberOfChoices {});
+    }
     let checked_multiple_choice_options = options.into_checked()?.options;
 
     let expiration = config.max_voting_period.after(&env.block);
@@ -217,6 +300,13 @@ pub fn execute_propose(
             votes: MultipleChoiceVotes::zero(checked_multiple_choice_options.len()),
             allow_revoting: config.allow_revoting,
             choices: checked_multiple_choice_options,
+            min_participation_for_early_execution: config.min_participation_for_early_execution,
+            none_rejection_quorum: config.none_rejection_quorum,
+            tie_break: Some(config.tie_break),
+            proposer_vote: None,
+            only_members_execute: Some(config.only_members_execute),
+            runoff_of: None,
+            runoff_depth: 0,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -248,15 +338,18 @@ pub fn execute_propose(
     }
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
+    LEADER_HISTORY.save(
+        deps.storage,
+        (id, env.block.height),
+        &proposal.leading_option()?,
+    )?;
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
     Ok(Response::default()
         .add_submessages(hooks)
         .add_attribute("action", "propose")
-        .add_attribute("sender", sender)
-        .add_attribute("proposal_id", id.to_string())
-        .add_attribute("status", proposal.status.to_string()))
+        .add_attributes(proposal_attributes(id, proposal.status, &sender)))
 }
 
 pub fn execute_vote(
@@ -273,7 +366,7 @@ pub fn execute_vote(
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     // Check that this is a valid vote.
-    if vote.option_id as usize >= prop.choices.len() {
+    if !vote.is_abstain() && vote.option_id as usize >= prop.choices.len() {
         return Err(ContractError::InvalidVote {});
     }
 
@@ -295,6 +388,16 @@ pub fn execute_vote(
         Some(prop.start_height),
     )?;
     if vote_power.is_zero() {
+        // Distinguish voters who gained voting power after the
+        // proposal was created from those who have none at all so
+        // that frontends may explain why the vote failed.
+        let current_power =
+            get_voting_power(deps.as_ref(), info.sender.clone(), &config.dao, None)?;
+        if !current_power.is_zero() {
+            return Err(ContractError::NoVotingPowerAtSnapshot {
+                snapshot_height: prop.start_height,
+            });
+        }
         return Err(ContractError::NotRegistered {});
     }
 
@@ -330,8 +433,17 @@ pub fn execute_vote(
     let old_status = prop.status;
 
     prop.votes.add_vote(vote, vote_power)?;
+    // The proposer's choice may be used to break ties.
+    if info.sender == prop.proposer {
+        prop.proposer_vote = (!vote.is_abstain()).then_some(vote.option_id);
+    }
     prop.update_status(&env.block)?;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    LEADER_HISTORY.save(
+        deps.storage,
+        (proposal_id, env.block.height),
+        &prop.leading_option()?,
+    )?;
     let new_status = prop.status;
     let change_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
@@ -351,10 +463,12 @@ pub fn execute_vote(
         .add_submessages(change_hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "vote")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attributes(proposal_attributes(proposal_id, prop.status, &info.sender))
+        // `position` predates `vote` and is kept for existing
+        // consumers.
         .add_attribute("position", vote.to_string())
-        .add_attribute("status", prop.status.to_string()))
+        .add_attribute("vote", vote.to_string())
+        .add_attribute("power", vote_power.to_string()))
 }
 
 pub fn execute_execute(
@@ -397,6 +511,8 @@ pub fn execute_execute(
     match vote_result {
         VoteResult::Tie => Err(ContractError::Tie {}), // We don't anticipate this case as the proposal would not be in passed state, checked above.
         VoteResult::SingleWinner(winning_choice) => {
+            let replied_to =
+                config.close_proposal_on_execution_failure && !winning_choice.msgs.is_empty();
             let response = if !winning_choice.msgs.is_empty() {
                 let execute_message = WasmMsg::Execute {
                     contract_addr: config.dao.to_string(),
@@ -406,9 +522,12 @@ pub fn execute_execute(
                     funds: vec![],
                 };
                 match config.close_proposal_on_execution_failure {
+                    // The outcome of executions that may fail is not
+                    // known until they are replied to, so it is
+                    // posted from there.
                     true => {
                         let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                        Response::default().add_submessage(SubMsg::reply_on_error(
+                        Response::default().add_submessage(SubMsg::reply_always(
                             execute_message,
                             masked_proposal_id,
                         ))
@@ -449,11 +568,27 @@ pub fn execute_execute(
                 }
             };
 
+            // The outcome is posted after the proposal's messages and
+            // hooks, unless the execution is replied to.
+            let outcome = if !replied_to {
+                outcome_packet_message(
+                    deps.storage,
+                    &env,
+                    &ProposalOutcomePacket {
+                        proposal_id,
+                        status: prop.status,
+                        winning_option: winning_choice.index,
+                    },
+                )?
+            } else {
+                None
+            };
+
             Ok(response
                 .add_submessages(hooks)
+                .add_submessages(outcome)
                 .add_attribute("action", "execute")
-                .add_attribute("sender", info.sender)
-                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attributes(proposal_attributes(proposal_id, prop.status, &info.sender))
                 .add_attribute("dao", config.dao))
         }
     }
@@ -507,11 +642,75 @@ pub fn execute_close(
             hooks
         }
     };
-    Ok(Response::default()
+    let response = Response::default()
         .add_submessages(hooks)
         .add_attribute("action", "close")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_attributes(proposal_attributes(proposal_id, prop.status, &info.sender));
+
+    // Rather than rejecting a tie outright, hold a runoff between the
+    // tied options if configured to do so.
+    let config = CONFIG.load(deps.storage)?;
+    if config.tie_runoff && prop.runoff_depth < MAX_RUNOFF_DEPTH {
+        if let Some(options) = prop.runoff_options()? {
+            let (runoff_id, hooks) =
+                create_runoff(deps, &env, config, proposal_id, &prop, options)?;
+            return Ok(response
+                .add_submessages(hooks)
+                .add_attribute("runoff_proposal_id", runoff_id.to_string()));
+        }
+    }
+
+    Ok(response)
+}
+
+/// Creates a runoff proposal between the tied options of the
+/// proposal with ID `tied_id`, returning the new proposal's ID and
+/// its proposal hooks. The runoff uses the module's current config.
+fn create_runoff(
+    deps: DepsMut,
+    env: &Env,
+    config: Config,
+    tied_id: u64,
+    tied: &MultipleChoiceProposal,
+    options: MultipleChoiceOptions,
+) -> Result<(u64, Vec<SubMsg>), ContractError> {
+    let choices = options.into_checked()?.options;
+    let total_power = get_total_power(deps.as_ref(), &config.dao, None)?;
+
+    let mut runoff = MultipleChoiceProposal {
+        title: format!("Runoff: {}", tied.title),
+        description: tied.description.clone(),
+        proposer: tied.proposer.clone(),
+        start_height: env.block.height,
+        min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+        expiration: config.max_voting_period.after(&env.block),
+        voting_strategy: config.voting_strategy,
+        total_power,
+        status: Status::Open,
+        votes: MultipleChoiceVotes::zero(choices.len()),
+        allow_revoting: config.allow_revoting,
+        choices,
+        min_participation_for_early_execution: config.min_participation_for_early_execution,
+        none_rejection_quorum: config.none_rejection_quorum,
+        tie_break: Some(config.tie_break),
+        proposer_vote: None,
+        only_members_execute: Some(config.only_members_execute),
+        runoff_of: Some(tied_id),
+        runoff_depth: tied.runoff_depth + 1,
+    };
+    runoff.update_status(&env.block)?;
+
+    let id = advance_proposal_id(deps.storage)?;
+    PROPOSALS.save(deps.storage, id, &runoff)?;
+    LEADER_HISTORY.save(
+        deps.storage,
+        (id, env.block.height),
+        &runoff.leading_option()?,
+    )?;
+    RUNOFFS.save(deps.storage, tied_id, &id)?;
+
+    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, runoff.proposer.as_str())?;
+    Ok((id, hooks))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -525,6 +724,13 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    min_participation_for_early_execution: Option<PercentageThreshold>,
+    tie_runoff: bool,
+    max_proposal_msgs: Option<u64>,
+    text_limits: Option<ProposalTextLimits>,
+    none_rejection_quorum: Option<PercentageThreshold>,
+    tie_break: Option<TieBreak>,
+    min_proposer_power: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -534,6 +740,16 @@ pub fn execute_update_config(
     }
 
     voting_strategy.validate()?;
+    if let Some(min_participation) = &min_participation_for_early_execution {
+        validate_quorum(min_participation)?;
+    }
+    if let Some(none_rejection_quorum) = &none_rejection_quorum {
+        validate_quorum(none_rejection_quorum)?;
+    }
+    validate_max_proposal_msgs(max_proposal_msgs)?;
+    if let Some(text_limits) = &text_limits {
+        text_limits.validate()?;
+    }
 
     let dao = deps.api.addr_validate(&dao)?;
 
@@ -550,6 +766,13 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            min_participation_for_early_execution,
+            tie_runoff,
+            max_proposal_msgs,
+            text_limits,
+            none_rejection_quorum,
+            tie_break: tie_break.unwrap_or_default(),
+            min_proposer_power,
         },
     )?;
 
@@ -558,6 +781,13 @@ pub fn execute_update_config(
         .add_attribute("sender", info.sender))
 }
 
+fn validate_max_proposal_msgs(max_proposal_msgs: Option<u64>) -> Result<(), ContractError> {
+    if max_proposal_msgs == Some(0) {
+        return Err(ContractError::ZeroMaxProposalMsgs {});
+    }
+    Ok(())
+}
+
 pub fn execute_update_proposal_creation_policy(
     deps: DepsMut,
     info: MessageInfo,
@@ -578,6 +808,42 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_update_outcome_channel(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel: Option<OutcomeChannel>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let channel_id = match channel {
+        Some(channel) => {
+            if channel.timeout_seconds == 0 {
+                return Err(ContractError::ZeroOutcomeTimeout {});
+            }
+            if !CONNECTED_CHANNELS.has(deps.storage, &channel.channel_id) {
+                return Err(ContractError::UnknownOutcomeChannel {
+                    channel_id: channel.channel_id,
+                });
+            }
+            let channel_id = channel.channel_id.clone();
+            OUTCOME_CHANNEL.save(deps.storage, &channel)?;
+            channel_id
+        }
+        None => {
+            OUTCOME_CHANNEL.remove(deps.storage);
+            "_none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_outcome_channel")
+        .add_attribute("sender", info.sender)
+        .add_attribute("channel_id", channel_id))
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -728,7 +994,17 @@ pub fn advance_proposal_id(store: &mut dyn Storage) -> StdResult<u64> {
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => query_config(deps),
+        QueryMsg::NormalizedThreshold {} => query_normalized_threshold(deps),
         QueryMsg::Proposal { proposal_id } => query_proposal(deps, env, proposal_id),
+        QueryMsg::ProposalConfig { proposal_id } => query_proposal_config(deps, proposal_id),
+        QueryMsg::Runoff { proposal_id } => query_runoff(deps, proposal_id),
+        QueryMsg::Runnerup { proposal_id } => query_runnerup(deps, proposal_id),
+        QueryMsg::ProposalTally { proposal_id } => query_proposal_tally(deps, proposal_id),
+        QueryMsg::OutcomeBounds { proposal_id } => query_outcome_bounds(deps, env, proposal_id),
+        QueryMsg::LeaderChangedSince {
+            proposal_id,
+            height,
+        } => query_leader_changed_since(deps, proposal_id, height),
         QueryMsg::ListProposals { start_after, limit } => {
             query_list_proposals(deps, env, start_after, limit)
         }
@@ -745,9 +1021,18 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_before,
             limit,
         } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::ProposalsByStatus {
+            status,
+            start_after,
+            limit,
+        } => query_proposals_by_status(deps, env, status, start_after, limit),
+        QueryMsg::Participation { start_after, limit } => {
+            query_participation(deps, env, start_after, limit)
+        }
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::OutcomeChannel {} => to_binary(&OUTCOME_CHANNEL.may_load(deps.storage)?),
         QueryMsg::Dao {} => query_dao(deps),
     }
 }
@@ -757,6 +1042,11 @@ pub fn query_config(deps: Deps) -> StdResult<Binary> {
     to_binary(&config)
 }
 
+pub fn query_normalized_threshold(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_binary(&config.voting_strategy.normalize())
+}
+
 pub fn query_dao(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_binary(&config.dao)
@@ -767,6 +1057,118 @@ pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
     to_binary(&proposal.into_response(&env.block, id)?)
 }
 
+pub fn query_proposal_config(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_binary(&ProposalConfigResponse {
+        voting_strategy: proposal.voting_strategy,
+        min_participation_for_early_execution: proposal.min_participation_for_early_execution,
+        allow_revoting: proposal.allow_revoting,
+        only_members_execute: proposal.only_members_execute,
+        deposit_info: query_proposal_deposit(deps, id)?,
+    })
+}
+
+/// Queries the module's pre-propose module, if any, for the deposit
+/// paid to create proposal ID.
+fn query_proposal_deposit(deps: Deps, id: u64) -> StdResult<Option<CheckedDepositInfo>> {
+    match CREATION_POLICY.load(deps.storage)? {
+        ProposalCreationPolicy::Anyone {} => Ok(None),
+        ProposalCreationPolicy::Module { addr } => {
+            let deposit: StdResult<DepositInfoResponse> = deps.querier.query_wasm_smart(
+                addr,
+                &dao_pre_propose_base::msg::QueryMsg::<Empty>::DepositInfo { proposal_id: id },
+            );
+            // The proposal may predate the current pre-propose module.
+            Ok(deposit.ok().and_then(|deposit| deposit.deposit_info))
+        }
+    }
+}
+
+pub fn query_runoff(deps: Deps, id: u64) -> StdResult<Binary> {
+    to_binary(&RUNOFFS.may_load(deps.storage, id)?)
+}
+
+pub fn query_runnerup(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let ranked = proposal.ranked_choices();
+    let response = match ranked.as_slice() {
+        [(_, first), (runner_up, votes), ..] if !first.is_zero() => RunnerupResponse {
+            option: Some((*runner_up).clone()),
+            votes: *votes,
+            margin: *first - *votes,
+        },
+        // Fewer than two options, or no votes cast.
+        _ => RunnerupResponse {
+            option: None,
+            votes: Uint128::zero(),
+            margin: Uint128::zero(),
+        },
+    };
+    to_binary(&response)
+}
+
+pub fn query_proposal_tally(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let options = proposal
+        .choices
+        .iter()
+        .map(|choice| OptionTally {
+            index: choice.index,
+            option_type: choice.option_type.clone(),
+            title: choice.title.clone(),
+            description: choice.description.clone(),
+            vote_count: proposal.votes.vote_weights[choice.index as usize],
+        })
+        .collect();
+    let quorum_met = does_vote_count_pass(
+        proposal.votes.total(),
+        proposal.total_power,
+        proposal.voting_strategy.get_quorum(),
+    );
+    to_binary(&ProposalTallyResponse {
+        vote_weights: proposal.votes.vote_weights,
+        options,
+        total_power: proposal.total_power,
+        quorum_met,
+    })
+}
+
+pub fn query_outcome_bounds(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let (best_case, worst_case) = proposal.outcome_bounds(&env.block)?;
+    to_binary(&OutcomeBoundsResponse {
+        best_case,
+        worst_case,
+        decided: best_case == worst_case,
+    })
+}
+
+pub fn query_leader_changed_since(deps: Deps, id: u64, height: u64) -> StdResult<Binary> {
+    let proposal = match PROPOSALS.may_load(deps.storage, id)? {
+        Some(proposal) if proposal.start_height <= height => proposal,
+        _ => return to_binary(&None::<bool>),
+    };
+    // The leader at HEIGHT is the one recorded by the last vote at or
+    // before it. Proposals created before leaders were recorded may
+    // have no such entry.
+    let leader_then = LEADER_HISTORY
+        .prefix(id)
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map(|(_, leader)| leader);
+    let changed = match leader_then {
+        Some(leader_then) => Some(leader_then != proposal.leading_option()?),
+        None => None,
+    };
+    to_binary(&changed)
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_binary(&policy)
@@ -810,10 +1212,63 @@ pub fn query_reverse_proposals(
     to_binary(&ProposalListResponse { proposals: props })
 }
 
+pub fn query_proposals_by_status(
+    deps: Deps,
+    env: Env,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.map_or(DEFAULT_LIMIT as usize, |limit| limit as usize);
+    // Stored statuses are only updated on vote, execute, and close so
+    // filter on the recomputed status of each proposal.
+    let props = PROPOSALS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.and_then(|(id, proposal)| proposal.into_response(&env.block, id)))
+        .filter(|item| match item {
+            Ok(response) => response.proposal.status == status,
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_binary(&ProposalListResponse { proposals: props })
+}
+
 pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
     to_binary(&next_proposal_id(deps.storage)?)
 }
 
+pub fn query_participation(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_PROPOSALS_SCANNED);
+    let participation = PROPOSALS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .try_fold(ParticipationResponse::default(), |acc, item| {
+            let (_, proposal) = item?;
+            if proposal.current_status(&env.block)? != Status::Open {
+                return Ok(acc);
+            }
+            acc.checked_add(ParticipationResponse {
+                open_proposals: 1,
+                votes_cast: proposal.votes.total(),
+                total_power: proposal.total_power,
+            })
+        })?;
+    to_binary(&participation)
+}
+
 pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
     let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
     to_binary(&proposal_count)
@@ -867,18 +1322,45 @@ pub fn query_info(deps: Deps) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
+        // Executions that close their proposal on failure are replied
+        // to whether or not they succeed, and post the proposal's
+        // outcome once it is known.
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
-                Some(mut prop) => {
-                    prop.status = Status::ExecutionFailed;
-                    Ok(prop)
+            let mut prop = PROPOSALS
+                .may_load(deps.storage, proposal_id)?
+                .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+            let failed = matches!(msg.result, SubMsgResult::Err(_));
+            if failed {
+                prop.status = Status::ExecutionFailed;
+                PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+            }
+            let outcome = match prop.calculate_vote_result()? {
+                VoteResult::SingleWinner(winning_choice) => outcome_packet_message(
+                    deps.storage,
+                    &env,
+                    &ProposalOutcomePacket {
+                        proposal_id,
+                        status: prop.status,
+                        winning_option: winning_choice.index,
+                    },
+                )?,
+                VoteResult::Tie => None,
+            };
+            let response = Response::new().add_submessages(outcome);
+            Ok(match failed {
+                true => {
+                    response.add_attribute("proposal execution failed", proposal_id.to_string())
                 }
-                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
-            })?;
-            Ok(Response::new().add_attribute("proposal execution failed", proposal_id.to_string()))
+                false => response.add_attribute("proposal_executed", proposal_id.to_string()),
+            })
+        }
+        // Outcomes are posted on a best-effort basis, so a packet that
+        // can not be sent does not fail execution.
+        TaggedReplyId::FailedOutcomePacket(proposal_id) => {
+            Ok(Response::new().add_attribute("outcome_packet_failed", proposal_id.to_string()))
         }
         TaggedReplyId::FailedProposalHook(idx) => {
             let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
@@ -904,6 +1386,12 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                     .add_attribute("update_pre_propose_module", res.contract_address)),
             }
         }
+        // This module does not hash state after execution or
+        // execute proposals in best-effort mode.
+        TaggedReplyId::ProposalExecutionStateHash(_)
+        | TaggedReplyId::ProposalMessageExecution(_) => {
+            Err(ContractError::InvalidReplyID { id: msg.id })
+        }
         TaggedReplyId::FailedPreProposeModuleHook => {
             let addr = match CREATION_POLICY.load(deps.storage)? {
                 ProposalCreationPolicy::Anyone {} => {
@@ -1,9 +1,11 @@
 use std::u64;
 
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
-use dao_voting::{reply::error::TagError, threshold::ThresholdError};
+use dao_voting::{
+    multiple_choice::MAX_NUM_CHOICES, reply::error::TagError, threshold::ThresholdError,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -26,6 +28,9 @@ pub enum ContractError {
     #[error("{0}")]
     VotingError(#[from] dao_voting::error::VotingError),
 
+    #[error("{0}")]
+    TextLimitError(#[from] dao_voting::text_limits::TextLimitError),
+
     #[error("Suggested proposal expiration is larger than the maximum proposal duration")]
     InvalidExpiration {},
 
@@ -41,6 +46,17 @@ pub enum ContractError {
     #[error("Not registered to vote (no voting power) at time of proposal creation.")]
     NotRegistered {},
 
+    #[error(
+        "No voting power at the proposal's snapshot height ({snapshot_height}). Voting power gained after a proposal is created may not be used to vote on it."
+    )]
+    NoVotingPowerAtSnapshot { snapshot_height: u64 },
+
+    #[error("max_proposal_msgs must be greater than zero")]
+    ZeroMaxProposalMsgs {},
+
+    #[error("Proposal option has too many messages ({count}). The maximum is ({max}).")]
+    TooManyProposalMsgs { count: u64, max: u64 },
+
     #[error("No vote exists for proposal ({id}) and voter ({voter})")]
     NoSuchVote { id: u64, voter: String },
 
@@ -65,7 +81,12 @@ pub enum ContractError {
     #[error("The DAO is currently inactive, you cannot create proposals.")]
     InactiveDao {},
 
-    #[error("Proposal must have at least two choices.")]
+    #[error(
+        "proposer has ({power}) voting power. at least ({min}) is needed to create a proposal"
+    )]
+    InsufficientProposerPower { power: Uint128, min: Uint128 },
+
+    #[error("Proposal must have between two and {max} choices.", max = MAX_NUM_CHOICES)]
     WrongNumberOfChoices {},
 
     #[error("Must have exactly one 'none of the above' option.")]
@@ -98,4 +119,13 @@ pub enum ContractError {
 
     #[error("received a reply failure with an invalid ID: ({id})")]
     InvalidReplyID { id: u64 },
+
+    #[error("outcome channels must be unordered and use version ({version})")]
+    InvalidOutcomeChannel { version: String },
+
+    #[error("outcome channel ({channel_id}) is not a connected channel of this module")]
+    UnknownOutcomeChannel { channel_id: String },
+
+    #[error("outcome packets must have a timeout greater than zero seconds")]
+    ZeroOutcomeTimeout {},
 }
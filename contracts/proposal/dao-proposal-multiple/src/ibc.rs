@@ -0,0 +1,163 @@
+use cosmwasm_schema::cw_serde;
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, DepsMut, Empty, Env, Ibc3ChannelOpenResponse, IbcBasicResponse,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcMsg,
+    IbcOrder, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse,
+    IbcTimeout, StdResult, Storage, SubMsg,
+};
+use dao_voting::{reply::mask_outcome_packet_proposal_id, status::Status};
+
+use crate::{
+    state::{CONNECTED_CHANNELS, OUTCOME_CHANNEL},
+    ContractError,
+};
+
+/// The version of the channels that proposal outcomes are posted
+/// over.
+pub const OUTCOME_VERSION: &str = "dao-proposal-outcome-1";
+
+/// The packet posted to the outcome channel when a proposal is
+/// executed.
+#[cw_serde]
+pub struct ProposalOutcomePacket {
+    pub proposal_id: u64,
+    pub status: Status,
+    /// The index of the proposal's winning option.
+    pub winning_option: u32,
+}
+
+/// The acknowledgement expected from the receiver of an outcome
+/// packet.
+#[cw_serde]
+pub enum OutcomeAck {
+    Result {},
+    Error(String),
+}
+
+/// Gets the message posting PACKET to the outcome channel, if one is
+/// configured. Failing to send the packet does not fail the
+/// transaction that posts it.
+pub fn outcome_packet_message(
+    storage: &dyn Storage,
+    env: &Env,
+    packet: &ProposalOutcomePacket,
+) -> StdResult<Option<SubMsg>> {
+    OUTCOME_CHANNEL
+        .may_load(storage)?
+        .map(|channel| {
+            Ok(SubMsg::reply_on_error(
+                IbcMsg::SendPacket {
+                    channel_id: channel.channel_id,
+                    data: to_binary(packet)?,
+                    timeout: IbcTimeout::with_timestamp(
+                        env.block.time.plus_seconds(channel.timeout_seconds),
+                    ),
+                },
+                mask_outcome_packet_proposal_id(packet.proposal_id),
+            ))
+        })
+        .transpose()
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    let channel = msg.channel();
+    let counterparty_version = msg.counterparty_version().unwrap_or(OUTCOME_VERSION);
+    if channel.order != IbcOrder::Unordered
+        || channel.version != OUTCOME_VERSION
+        || counterparty_version != OUTCOME_VERSION
+    {
+        return Err(ContractError::InvalidOutcomeChannel {
+            version: OUTCOME_VERSION.to_string(),
+        });
+    }
+    Ok(Some(Ibc3ChannelOpenResponse {
+        version: OUTCOME_VERSION.to_string(),
+    }))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel_id = &msg.channel().endpoint.channel_id;
+    CONNECTED_CHANNELS.save(deps.storage, channel_id, &Empty {})?;
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_connect")
+        .add_attribute("channel_id", channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel_id = &msg.channel().endpoint.channel_id;
+    CONNECTED_CHANNELS.remove(deps.storage, channel_id);
+    // Packets can not be sent over a closed channel, so stop posting
+    // outcomes to it.
+    if let Some(channel) = OUTCOME_CHANNEL.may_load(deps.storage)? {
+        if channel.channel_id == *channel_id {
+            OUTCOME_CHANNEL.remove(deps.storage);
+        }
+    }
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_close")
+        .add_attribute("channel_id", channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    // Outcomes are only ever sent from this module.
+    Ok(IbcReceiveResponse::new()
+        .set_ack(to_binary(&OutcomeAck::Error(
+            "proposal modules do not accept packets".to_string(),
+        ))?)
+        .add_attribute("method", "ibc_packet_receive"))
+}
+
+/// Outcomes are posted on a best-effort basis. Acknowledgements,
+/// including error acknowledgements, are only recorded in the
+/// response's attributes and outcomes are never resent.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let packet: ProposalOutcomePacket = from_binary(&msg.original_packet.data)?;
+    let ack = match from_binary(&msg.acknowledgement.data) {
+        Ok(OutcomeAck::Result {}) => "success".to_string(),
+        Ok(OutcomeAck::Error(error)) => error,
+        Err(_) => "unknown".to_string(),
+    };
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_packet_ack")
+        .add_attribute("proposal_id", packet.proposal_id.to_string())
+        .add_attribute("ack", ack))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let packet: ProposalOutcomePacket = from_binary(&msg.packet.data)?;
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_packet_timeout")
+        .add_attribute("proposal_id", packet.proposal_id.to_string()))
+}
@@ -2,6 +2,7 @@
 
 pub mod contract;
 mod error;
+pub mod ibc;
 pub mod msg;
 pub mod proposal;
 pub mod query;
@@ -1,11 +1,16 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
 use cw_utils::Duration;
 use dao_macros::proposal_module_query;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{MultipleChoiceOptions, MultipleChoiceVote, TieBreak, VotingStrategy},
     pre_propose::PreProposeInfo,
+    text_limits::ProposalTextLimits,
+    threshold::PercentageThreshold,
 };
 
+use crate::state::OutcomeChannel;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Voting params configuration
@@ -37,6 +42,40 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The minimum percentage of voting power that must have voted
+    /// before a proposal may pass early because its leading choice
+    /// can not be overtaken. Proposals below this turnout remain open
+    /// until they expire. If None, proposals may pass early regardless
+    /// of turnout.
+    pub min_participation_for_early_execution: Option<PercentageThreshold>,
+    /// If set to true, closing a proposal that was rejected because
+    /// its leading options tied creates a runoff proposal between
+    /// the tied options.
+    pub tie_runoff: bool,
+    /// The maximum number of messages each of a proposal's options
+    /// may contain. Proposals with an option containing more messages
+    /// are rejected at creation. If None, proposals are only limited
+    /// by `MAX_PROPOSAL_SIZE`.
+    pub max_proposal_msgs: Option<u64>,
+    /// Limits on the length of proposal titles and descriptions.
+    /// Proposals that exceed them are rejected at creation. If None,
+    /// they are only limited by `MAX_PROPOSAL_SIZE`.
+    pub text_limits: Option<ProposalTextLimits>,
+    /// The percentage of voting power that must have voted for "None
+    /// of the above" before a proposal may be rejected early because
+    /// "None of the above" can not be overtaken. Rejection on
+    /// expiration still uses the voting strategy's quorum. If None,
+    /// early rejection has no additional requirement.
+    pub none_rejection_quorum: Option<PercentageThreshold>,
+    /// How ties between the options with the most votes are
+    /// resolved. If None, ties are not resolved and tied proposals
+    /// are rejected.
+    pub tie_break: Option<TieBreak>,
+    /// The minimum voting power an address must have to create a
+    /// proposal. Power acquired in the block the proposal is created
+    /// in does not count. If None, any address permitted by the
+    /// proposal creation policy may propose.
+    pub min_proposer_power: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -116,6 +155,36 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// The minimum percentage of voting power that must have
+        /// voted before a proposal may pass early because its leading
+        /// choice can not be overtaken. This will only apply to
+        /// proposals created after the config update.
+        min_participation_for_early_execution: Option<PercentageThreshold>,
+        /// If set to true, closing a proposal that was rejected
+        /// because its leading options tied creates a runoff proposal
+        /// between the tied options.
+        tie_runoff: bool,
+        /// The maximum number of messages each of a proposal's
+        /// options may contain. Applies to proposals created after
+        /// the config update.
+        max_proposal_msgs: Option<u64>,
+        /// Limits on the length of proposal titles and
+        /// descriptions. Applies to proposals created after the
+        /// config update.
+        text_limits: Option<ProposalTextLimits>,
+        /// The percentage of voting power that must have voted for
+        /// "None of the above" before a proposal may be rejected
+        /// early because "None of the above" can not be overtaken.
+        /// Applies to proposals created after the config update.
+        none_rejection_quorum: Option<PercentageThreshold>,
+        /// How ties between the options with the most votes are
+        /// resolved. If None, ties are not resolved. Applies to
+        /// proposals created after the config update.
+        tie_break: Option<TieBreak>,
+        /// The minimum voting power an address must have to create a
+        /// proposal. If None, any address permitted by the proposal
+        /// creation policy may propose.
+        min_proposer_power: Option<Uint128>,
     },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
@@ -128,6 +197,12 @@ pub enum ExecuteMsg {
     UpdatePreProposeInfo {
         info: PreProposeInfo,
     },
+    /// Sets the IBC channel that the outcomes of executed proposals
+    /// are posted to. If None, outcomes are not posted. Only the DAO
+    /// may call this method.
+    UpdateOutcomeChannel {
+        channel: Option<OutcomeChannel>,
+    },
     AddProposalHook {
         address: String,
     },
@@ -149,9 +224,39 @@ pub enum QueryMsg {
     /// Gets the governance module's config.
     #[returns(crate::state::Config)]
     Config {},
+    /// Gets the module's current passing requirements in a form
+    /// shared by all proposal modules.
+    #[returns(::dao_voting::threshold::NormalizedThreshold)]
+    NormalizedThreshold {},
     /// Gets information about a proposal.
     #[returns(crate::query::ProposalResponse)]
     Proposal { proposal_id: u64 },
+    /// Gets the configuration that was in effect when a proposal was
+    /// created.
+    #[returns(crate::query::ProposalConfigResponse)]
+    ProposalConfig { proposal_id: u64 },
+    /// Gets the ID of the runoff proposal created when a tied
+    /// proposal was closed, if any.
+    #[returns(Option<u64>)]
+    Runoff { proposal_id: u64 },
+    /// Gets the option with the second most votes on a proposal and
+    /// the margin by which it trails the leading option.
+    #[returns(crate::query::RunnerupResponse)]
+    Runnerup { proposal_id: u64 },
+    /// Gets the voting power cast for each of a proposal's options
+    /// and whether its quorum has been met.
+    #[returns(crate::query::ProposalTallyResponse)]
+    ProposalTally { proposal_id: u64 },
+    /// Gets the best and worst status a proposal may end with given
+    /// the votes cast so far.
+    #[returns(crate::query::OutcomeBoundsResponse)]
+    OutcomeBounds { proposal_id: u64 },
+    /// Returns whether the leading option of a proposal differs from
+    /// its leading option at HEIGHT, treating a tie for the lead as
+    /// having no leader. None if the proposal did not exist at
+    /// HEIGHT or was created before leaders were recorded.
+    #[returns(Option<bool>)]
+    LeaderChangedSince { proposal_id: u64, height: u64 },
     /// Lists all the proposals that have been cast in this module.
     #[returns(crate::query::ProposalListResponse)]
     ListProposals {
@@ -165,6 +270,19 @@ pub enum QueryMsg {
         start_before: Option<u64>,
         limit: Option<u64>,
     },
+    /// Lists the proposals whose current status is STATUS, in
+    /// ascending order of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ProposalsByStatus {
+        /// The status to list proposals with.
+        status: ::dao_voting::status::Status,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u32>,
+    },
     /// Returns a voters position on a proposal.
     #[returns(crate::query::VoteResponse)]
     GetVote { proposal_id: u64, voter: String },
@@ -178,6 +296,20 @@ pub enum QueryMsg {
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
+    /// Gets the voting participation across the open proposals among
+    /// the `limit` proposals of this module with IDs after
+    /// `start_after`. Proposal IDs are sequential, so participation
+    /// across all open proposals is the sum of consecutive pages up
+    /// to `ProposalCount {}`.
+    #[returns(::dao_voting::proposal::ParticipationResponse)]
+    Participation {
+        /// The proposal ID to start examining proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to examine. If no limit
+        /// is set a max of 30 proposals will be examined. Limits
+        /// greater than 300 are treated as 300.
+        limit: Option<u64>,
+    },
     /// Gets the current proposal creation policy for this module.
     #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
     ProposalCreationPolicy {},
@@ -187,6 +319,10 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the IBC channel that the outcomes of executed proposals
+    /// are posted to, if any.
+    #[returns(Option<crate::state::OutcomeChannel>)]
+    OutcomeChannel {},
 }
 
 #[cw_serde]
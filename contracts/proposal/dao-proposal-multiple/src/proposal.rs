@@ -3,9 +3,11 @@ use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Uint128};
 use cw_utils::Expiration;
 use dao_voting::{
     multiple_choice::{
-        CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVotes, VotingStrategy,
+        CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
+        MultipleChoiceOptions, MultipleChoiceVotes, TieBreak, VotingStrategy,
     },
     status::Status,
+    threshold::PercentageThreshold,
     voting::does_vote_count_pass,
 };
 
@@ -43,6 +45,38 @@ pub struct MultipleChoiceProposal {
     /// When enabled, proposals can only be executed after the voting
     /// perid has ended and the proposal passed.
     pub allow_revoting: bool,
+    /// The minimum percentage of voting power that must have voted
+    /// before this proposal may pass before expiration.
+    #[serde(default)]
+    pub min_participation_for_early_execution: Option<PercentageThreshold>,
+    /// The percentage of voting power that must have voted for "None
+    /// of the above" before this proposal may be rejected before
+    /// expiration.
+    #[serde(default)]
+    pub none_rejection_quorum: Option<PercentageThreshold>,
+    /// How ties between the options with the most votes are
+    /// resolved. None for proposals created before ties could be
+    /// broken, whose ties are not resolved.
+    #[serde(default)]
+    pub tie_break: Option<TieBreak>,
+    /// The index of the option the proposer voted for, if any. Used
+    /// to break ties under `TieBreak::Proposer`.
+    #[serde(default)]
+    pub proposer_vote: Option<u32>,
+    /// Whether only members could execute passed proposals when this
+    /// proposal was created. None for proposals created before this
+    /// was recorded. Execution permissions are always determined by
+    /// the module's current config.
+    #[serde(default)]
+    pub only_members_execute: Option<bool>,
+    /// If this proposal is a runoff, the ID of the tied proposal it
+    /// was created from.
+    #[serde(default)]
+    pub runoff_of: Option<u64>,
+    /// The number of runoffs between this proposal and the original
+    /// proposal. Zero for proposals that are not runoffs.
+    #[serde(default)]
+    pub runoff_depth: u64,
 }
 
 pub enum VoteResult {
@@ -123,6 +157,20 @@ impl MultipleChoiceProposal {
                         if self.expiration.is_expired(block) {
                             return Ok(true);
                         } else {
+                            // If turnout is below the configured floor the
+                            // proposal waits for expiration, even with an
+                            // unbeatable lead.
+                            if let Some(min_participation) =
+                                self.min_participation_for_early_execution
+                            {
+                                if !does_vote_count_pass(
+                                    self.votes.total(),
+                                    self.total_power,
+                                    min_participation,
+                                ) {
+                                    return Ok(false);
+                                }
+                            }
                             // If the proposal is not expired but the leading choice cannot
                             // possibly be outwon by any other choices, the proposal has passed.
                             return self.is_choice_unbeatable(&winning_choice);
@@ -141,6 +189,12 @@ impl MultipleChoiceProposal {
             return Ok(false);
         }
 
+        // A proposal that expires without any votes fails to reach
+        // quorum. Its options all having zero votes is not a tie.
+        if self.votes.total().is_zero() && self.expiration.is_expired(block) {
+            return Ok(true);
+        }
+
         let vote_result = self.calculate_vote_result()?;
         match vote_result {
             // Proposal is rejected if there is a tie, and either the proposal is expired or
@@ -172,6 +226,18 @@ impl MultipleChoiceProposal {
                         // If the proposal is not expired and the leading choice is None and it cannot
                         // possibly be outwon by any other choices, the proposal is rejected.
                         if winning_choice.option_type == MultipleChoiceOptionType::None {
+                            // Early rejection additionally requires None
+                            // itself to have reached the configured
+                            // quorum. Rejection on expiration is unaffected.
+                            if let Some(none_rejection_quorum) = self.none_rejection_quorum {
+                                if !does_vote_count_pass(
+                                    self.votes.vote_weights[winning_choice.index as usize],
+                                    self.total_power,
+                                    none_rejection_quorum,
+                                ) {
+                                    return Ok(false);
+                                }
+                            }
                             return self.is_choice_unbeatable(&winning_choice);
                         }
                         Ok(false)
@@ -184,6 +250,9 @@ impl MultipleChoiceProposal {
     }
 
     /// Find the option with the highest vote weight, and note if there is a tie.
+    /// Abstentions are not cast for any option and so never win; if
+    /// every vote abstained all options tie at zero. Ties between
+    /// options with votes are resolved by the proposal's tie break.
     pub fn calculate_vote_result(&self) -> StdResult<VoteResult> {
         match self.voting_strategy {
             VotingStrategy::SingleChoice { quorum: _ } => {
@@ -199,7 +268,26 @@ impl MultipleChoiceProposal {
 
                     // If more than one choice has the highest number of votes, we have a tie.
                     if top_choices.len() > 1 {
-                        return Ok(VoteResult::Tie);
+                        // Options that tie without any votes are never
+                        // resolved so that a proposal can not pass with
+                        // no votes for its winning option.
+                        if max_weight.is_zero() {
+                            return Ok(VoteResult::Tie);
+                        }
+                        let winner = match self.tie_break.unwrap_or_default() {
+                            TieBreak::None => None,
+                            TieBreak::FirstIndex => top_choices.first().map(|choice| choice.0),
+                            TieBreak::Proposer => self
+                                .proposer_vote
+                                .map(|option_id| option_id as usize)
+                                .filter(|index| {
+                                    top_choices.iter().any(|choice| choice.0 == *index)
+                                }),
+                        };
+                        return Ok(match winner {
+                            Some(index) => VoteResult::SingleWinner(self.choices[index].clone()),
+                            None => VoteResult::Tie,
+                        });
                     }
 
                     match top_choices.first() {
@@ -218,8 +306,80 @@ impl MultipleChoiceProposal {
         }
     }
 
+    /// Returns the index of the option with the most votes, or None
+    /// if two or more options are tied for the most votes and the tie
+    /// is not broken.
+    pub fn leading_option(&self) -> StdResult<Option<u32>> {
+        Ok(match self.calculate_vote_result()? {
+            VoteResult::SingleWinner(choice) => Some(choice.index),
+            VoteResult::Tie => None,
+        })
+    }
+
+    /// Returns the best and worst status this proposal may end with
+    /// given the votes cast so far. Every way of casting the
+    /// remaining voting power for a single option, as well as not
+    /// casting it at all, is considered; while revoting is possible
+    /// votes already cast may also move. Bounds for a proposal that is
+    /// no longer open are both its current status.
+    pub fn outcome_bounds(&self, block: &BlockInfo) -> StdResult<(Status, Status)> {
+        let status = self.current_status(block)?;
+        if status != Status::Open {
+            return Ok((status, status));
+        }
+
+        let votes = if self.allow_revoting {
+            MultipleChoiceVotes::zero(self.choices.len())
+        } else {
+            self.votes.clone()
+        };
+        let remaining = self.total_power.saturating_sub(votes.total());
+
+        let mut outcomes = vec![self.passes_with(votes.clone())?];
+        for choice in &self.choices {
+            let mut votes = votes.clone();
+            votes.vote_weights[choice.index as usize] += remaining;
+            outcomes.push(self.passes_with(votes)?);
+        }
+
+        let bound = |passes: bool| {
+            if passes {
+                Status::Passed
+            } else {
+                Status::Rejected
+            }
+        };
+        Ok((
+            bound(outcomes.iter().any(|passes| *passes)),
+            bound(outcomes.iter().all(|passes| *passes)),
+        ))
+    }
+
+    /// Returns true if this proposal would pass on expiration were
+    /// VOTES its final tally.
+    fn passes_with(&self, votes: MultipleChoiceVotes) -> StdResult<bool> {
+        let proposal = MultipleChoiceProposal {
+            votes,
+            ..self.clone()
+        };
+        if !does_vote_count_pass(
+            proposal.votes.total(),
+            proposal.total_power,
+            proposal.voting_strategy.get_quorum(),
+        ) {
+            return Ok(false);
+        }
+        Ok(match proposal.calculate_vote_result()? {
+            VoteResult::SingleWinner(choice) => {
+                choice.option_type != MultipleChoiceOptionType::None
+            }
+            VoteResult::Tie => false,
+        })
+    }
+
     /// Ensure that with the remaining vote power, the choice with the second highest votes
-    /// cannot overtake the first choice.
+    /// cannot overtake the first choice. If the first choice won a
+    /// broken tie, the second choice has as many votes as it does.
     fn is_choice_unbeatable(
         &self,
         winning_choice: &CheckedMultipleChoiceOption,
@@ -229,7 +389,9 @@ impl MultipleChoiceProposal {
             .votes
             .vote_weights
             .iter()
-            .filter(|&x| x < &winning_choice_power)
+            .enumerate()
+            .filter(|(index, _)| *index != winning_choice.index as usize)
+            .map(|(_, power)| power)
             .max_by(|&a, &b| a.cmp(b))
         {
             // Check if the remaining vote power can be used to overtake the current winning choice.
@@ -254,6 +416,66 @@ impl MultipleChoiceProposal {
         }
         Ok(false)
     }
+
+    /// Returns this proposal's options paired with the voting power
+    /// cast for them, ordered from most to least votes. Options with
+    /// equal votes are ordered by index.
+    pub fn ranked_choices(&self) -> Vec<(&CheckedMultipleChoiceOption, Uint128)> {
+        let mut ranked: Vec<(&CheckedMultipleChoiceOption, Uint128)> = self
+            .choices
+            .iter()
+            .map(|choice| (choice, self.votes.vote_weights[choice.index as usize]))
+            .collect();
+        // Stable, so ties remain in index order.
+        ranked.sort_by(|(_, a), (_, b)| b.cmp(a));
+        ranked
+    }
+
+    /// Returns the options for a runoff between this proposal's tied
+    /// options. None if quorum was not met, there is no tie, the tied
+    /// options have no votes, or fewer than two of the tied options
+    /// are not "None of the above".
+    pub fn runoff_options(&self) -> StdResult<Option<MultipleChoiceOptions>> {
+        if !does_vote_count_pass(
+            self.votes.total(),
+            self.total_power,
+            self.voting_strategy.get_quorum(),
+        ) {
+            return Ok(None);
+        }
+        if !matches!(self.calculate_vote_result()?, VoteResult::Tie) {
+            return Ok(None);
+        }
+
+        let max_weight = self
+            .votes
+            .vote_weights
+            .iter()
+            .max()
+            .copied()
+            .unwrap_or_default();
+        if max_weight.is_zero() {
+            return Ok(None);
+        }
+        let options: Vec<MultipleChoiceOption> = self
+            .choices
+            .iter()
+            .filter(|choice| {
+                choice.option_type == MultipleChoiceOptionType::Standard
+                    && self.votes.vote_weights[choice.index as usize] == max_weight
+            })
+            .map(|choice| MultipleChoiceOption {
+                title: choice.title.clone(),
+                description: choice.description.clone(),
+                msgs: choice.msgs.clone(),
+            })
+            .collect();
+
+        if options.len() < 2 {
+            return Ok(None);
+        }
+        Ok(Some(MultipleChoiceOptions { options }))
+    }
 }
 
 #[cfg(test)]
@@ -261,7 +483,6 @@ mod tests {
     use super::*;
 
     use cosmwasm_std::testing::mock_env;
-    use dao_voting::multiple_choice::{MultipleChoiceOption, MultipleChoiceOptions};
 
     fn create_proposal(
         block: &BlockInfo,
@@ -306,6 +527,13 @@ mod tests {
             votes,
             allow_revoting,
             min_voting_period: None,
+            min_participation_for_early_execution: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            proposer_vote: None,
+            only_members_execute: None,
+            runoff_depth: 0,
+            runoff_of: None,
         }
     }
 
@@ -318,6 +546,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
 
         let prop = create_proposal(
@@ -335,6 +564,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(0), Uint128::new(1)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -351,6 +581,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -367,6 +598,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -383,6 +615,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -399,6 +632,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -425,6 +659,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
 
         let prop = create_proposal(
@@ -442,6 +677,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(0), Uint128::new(1)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -458,6 +694,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -474,6 +711,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -490,6 +728,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -506,6 +745,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -531,6 +771,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(50), Uint128::new(500)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -556,6 +797,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(10), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -579,6 +821,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(999999), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -602,6 +845,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(9888889), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -627,6 +871,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(7), Uint128::new(0), Uint128::new(6)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -664,6 +909,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(7), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
         let prop = create_proposal(
             &env.block,
@@ -702,6 +948,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(6), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
 
         let prop = create_proposal(
@@ -737,6 +984,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(5), Uint128::new(5), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
 
         let prop = create_proposal(
@@ -779,6 +1027,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(81), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
 
         let prop = create_proposal(
@@ -817,6 +1066,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(90), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
 
         let prop = create_proposal(
@@ -832,6 +1082,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
         };
 
         let prop = create_proposal(
@@ -845,4 +1096,272 @@ mod tests {
         // No quorum reached & proposal has expired => rejection
         assert!(prop.is_rejected(&env.block).unwrap());
     }
+
+    #[test]
+    fn test_min_participation_for_early_execution() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(cosmwasm_std::Decimal::percent(10)),
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(550), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes.clone(),
+            Uint128::new(1000),
+            false,
+            false,
+        );
+
+        // The leading choice can not be overtaken so the proposal
+        // passes early.
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // With a turnout floor above the current turnout the
+        // proposal waits for expiration.
+        prop.min_participation_for_early_execution = Some(PercentageThreshold::Percent(
+            cosmwasm_std::Decimal::percent(60),
+        ));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        // Once turnout reaches the floor it may pass early again.
+        prop.min_participation_for_early_execution = Some(PercentageThreshold::Percent(
+            cosmwasm_std::Decimal::percent(55),
+        ));
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // The floor does not apply to expired proposals.
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(1000),
+            true,
+            false,
+        );
+        prop.min_participation_for_early_execution = Some(PercentageThreshold::Percent(
+            cosmwasm_std::Decimal::percent(60),
+        ));
+        assert!(prop.is_passed(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_none_rejection_quorum() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(cosmwasm_std::Decimal::percent(10)),
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(0), Uint128::new(0), Uint128::new(550)],
+            abstain: Uint128::zero(),
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes,
+            Uint128::new(1000),
+            false,
+            false,
+        );
+
+        // None can not be overtaken so the proposal is rejected
+        // early.
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // With a None quorum above None's share of the vote the
+        // proposal stays open.
+        prop.none_rejection_quorum = Some(PercentageThreshold::Percent(
+            cosmwasm_std::Decimal::percent(60),
+        ));
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        // Once None reaches it the proposal is rejected early again.
+        prop.none_rejection_quorum = Some(PercentageThreshold::Percent(
+            cosmwasm_std::Decimal::percent(55),
+        ));
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // Failing the base quorum still rejects an expired proposal,
+        // regardless of the None quorum.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(50), Uint128::new(0), Uint128::new(0)],
+            abstain: Uint128::zero(),
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(1000),
+            true,
+            false,
+        );
+        prop.none_rejection_quorum = Some(PercentageThreshold::Percent(
+            cosmwasm_std::Decimal::percent(60),
+        ));
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_abstain_counts_toward_quorum() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        };
+
+        // Abstentions alone meet quorum but no option wins.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::zero(); 3],
+            abstain: Uint128::new(60),
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes,
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(does_vote_count_pass(
+            prop.votes.total(),
+            prop.total_power,
+            prop.voting_strategy.get_quorum()
+        ));
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::Tie
+        ));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // Abstentions move an option's plurality over quorum without
+        // outweighing it.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(10), Uint128::new(5), Uint128::zero()],
+            abstain: Uint128::new(40),
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes,
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // Without the abstentions quorum is not met.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(10), Uint128::new(5), Uint128::zero()],
+            abstain: Uint128::zero(),
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_no_votes_expired_is_not_a_tie() {
+        let env = mock_env();
+        // A zero quorum is met even without votes.
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(cosmwasm_std::Decimal::zero()),
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+        assert_eq!(prop.runoff_options().unwrap(), None);
+
+        // Before expiration the proposal stays open.
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(!prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_tie_break() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            abstain: Uint128::zero(),
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(100),
+            true,
+            false,
+        );
+        let winner = |prop: &MultipleChoiceProposal| match prop.calculate_vote_result().unwrap() {
+            VoteResult::SingleWinner(choice) => Some(choice.index),
+            VoteResult::Tie => None,
+        };
+
+        // Without a tie break the tie is not resolved.
+        assert_eq!(winner(&prop), None);
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        prop.tie_break = Some(TieBreak::None);
+        assert_eq!(winner(&prop), None);
+
+        prop.tie_break = Some(TieBreak::FirstIndex);
+        assert_eq!(winner(&prop), Some(0));
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // The proposer has not voted for a tied option.
+        prop.tie_break = Some(TieBreak::Proposer);
+        assert_eq!(winner(&prop), None);
+        prop.proposer_vote = Some(2);
+        assert_eq!(winner(&prop), None);
+
+        prop.proposer_vote = Some(1);
+        assert_eq!(winner(&prop), Some(1));
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // A tie resolved to "None of the above" is rejected.
+        prop.votes.vote_weights = vec![Uint128::new(50), Uint128::new(0), Uint128::new(50)];
+        prop.proposer_vote = Some(2);
+        assert_eq!(winner(&prop), Some(2));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // Options that tie without votes are never resolved.
+        prop.tie_break = Some(TieBreak::FirstIndex);
+        prop.votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::zero(); 3],
+            abstain: Uint128::new(100),
+        };
+        assert_eq!(winner(&prop), None);
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
 }
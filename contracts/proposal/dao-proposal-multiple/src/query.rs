@@ -2,7 +2,14 @@ use crate::{proposal::MultipleChoiceProposal, state::Config};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 
-use dao_voting::multiple_choice::MultipleChoiceVote;
+use dao_voting::{
+    deposit::CheckedDepositInfo,
+    multiple_choice::{
+        CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVote, VotingStrategy,
+    },
+    status::Status,
+    threshold::PercentageThreshold,
+};
 
 #[cw_serde]
 pub struct ProposalListResponse {
@@ -48,3 +55,74 @@ pub struct VoterResponse {
 pub struct ConfigResponse {
     pub config: Config,
 }
+
+/// The configuration that was in effect when a proposal was created.
+#[cw_serde]
+pub struct ProposalConfigResponse {
+    /// The voting strategy used to determine if the proposal passes.
+    pub voting_strategy: VotingStrategy,
+    /// The minimum turnout required for the proposal to pass before
+    /// it expired.
+    pub min_participation_for_early_execution: Option<PercentageThreshold>,
+    /// Whether votes on the proposal may be changed.
+    pub allow_revoting: bool,
+    /// Whether only members could execute passed proposals when the
+    /// proposal was created. None if this was not recorded.
+    pub only_members_execute: Option<bool>,
+    /// The deposit paid to create the proposal, if any, as reported
+    /// by the module's current pre-propose module.
+    pub deposit_info: Option<CheckedDepositInfo>,
+}
+
+/// The option with the second most votes on a proposal.
+#[cw_serde]
+pub struct RunnerupResponse {
+    /// The runner-up option. None if no votes have been cast or the
+    /// proposal has fewer than two options.
+    pub option: Option<CheckedMultipleChoiceOption>,
+    /// The voting power cast for the runner-up option.
+    pub votes: Uint128,
+    /// The voting power by which the runner-up trails the leading
+    /// option. Zero if they are tied.
+    pub margin: Uint128,
+}
+
+/// The votes cast on one of a proposal's options.
+#[cw_serde]
+pub struct OptionTally {
+    /// The index of the option in the proposal's choices.
+    pub index: u32,
+    pub option_type: MultipleChoiceOptionType,
+    pub title: String,
+    pub description: String,
+    /// The voting power cast for the option so far.
+    pub vote_count: Uint128,
+}
+
+/// The current tally of the votes cast on a proposal.
+#[cw_serde]
+pub struct ProposalTallyResponse {
+    /// The voting power cast for each option, indexed by option.
+    pub vote_weights: Vec<Uint128>,
+    /// The proposal's options and the votes cast for each.
+    pub options: Vec<OptionTally>,
+    /// The total voting power when the proposal was created.
+    pub total_power: Uint128,
+    /// Whether enough voting power has been cast to meet the
+    /// proposal's quorum.
+    pub quorum_met: bool,
+}
+
+/// The range of statuses a proposal may end with.
+#[cw_serde]
+pub struct OutcomeBoundsResponse {
+    /// The status the proposal ends with if the voting power that
+    /// has yet to be cast is used in its favor.
+    pub best_case: Status,
+    /// The status the proposal ends with if the voting power that
+    /// has yet to be cast is used against it.
+    pub worst_case: Status,
+    /// Whether the best and worst cases agree, in which case no
+    /// further votes can change the proposal's outcome.
+    pub decided: bool,
+}
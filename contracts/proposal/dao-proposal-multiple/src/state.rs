@@ -1,12 +1,14 @@
 use crate::proposal::MultipleChoiceProposal;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{MultipleChoiceVote, TieBreak, VotingStrategy},
     pre_propose::ProposalCreationPolicy,
+    text_limits::ProposalTextLimits,
+    threshold::PercentageThreshold,
 };
 
 /// The proposal module's configuration.
@@ -43,6 +45,50 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The minimum percentage of voting power that must have voted
+    /// before a proposal may pass early because its leading choice
+    /// can not be overtaken. Proposals below this turnout remain open
+    /// until they expire. If None, proposals may pass early regardless
+    /// of turnout.
+    pub min_participation_for_early_execution: Option<PercentageThreshold>,
+    /// If set to true, closing a proposal that was rejected because
+    /// its leading options tied creates a runoff proposal between
+    /// the tied options.
+    #[serde(default)]
+    pub tie_runoff: bool,
+    /// The maximum number of messages each of a proposal's options
+    /// may contain. If None, proposals are only limited by
+    /// `MAX_PROPOSAL_SIZE`.
+    pub max_proposal_msgs: Option<u64>,
+    /// Limits on the length of proposal titles and descriptions for
+    /// this module. If None, they are only limited by
+    /// `MAX_PROPOSAL_SIZE`.
+    pub text_limits: Option<ProposalTextLimits>,
+    /// The percentage of voting power that must have voted for "None
+    /// of the above" before a proposal may be rejected early. If
+    /// None, early rejection has no additional requirement.
+    pub none_rejection_quorum: Option<PercentageThreshold>,
+    /// How ties between the options with the most votes are
+    /// resolved.
+    #[serde(default)]
+    pub tie_break: TieBreak,
+    /// The minimum voting power an address must have to create a
+    /// proposal. If None, any address permitted by the proposal
+    /// creation policy may propose.
+    #[serde(default)]
+    pub min_proposer_power: Option<Uint128>,
+}
+
+/// An IBC channel that the outcomes of executed proposals are
+/// posted to.
+#[cw_serde]
+pub struct OutcomeChannel {
+    /// The ID of the channel on this chain. The channel must be
+    /// connected to this module's port.
+    pub channel_id: String,
+    /// The number of seconds after execution that an outcome packet
+    /// times out. Must be greater than zero.
+    pub timeout_seconds: u64,
 }
 
 // Each ballot stores a chosen vote and corresponding voting power and rationale.
@@ -60,7 +106,15 @@ pub struct Ballot {
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
+/// Maps the ID of a tied proposal to the ID of the runoff proposal
+/// created when it was closed.
+pub const RUNOFFS: Map<u64, u64> = Map::new("runoffs");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The index of the leading option of each proposal after its
+/// creation and after each vote, keyed by `(proposal_id, height)`.
+/// None while options are tied for the lead. Only the last vote of
+/// each block is kept.
+pub const LEADER_HISTORY: Map<(u64, u64), Option<u32>> = Map::new("leader_history");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -68,3 +122,14 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The channel that the outcomes of executed proposals are posted
+/// to, if any.
+pub const OUTCOME_CHANNEL: Item<OutcomeChannel> = Item::new("outcome_channel");
+/// The IBC channels opened to this module's port that are currently
+/// connected. Only these may be used as the outcome channel.
+pub const CONNECTED_CHANNELS: Map<&str, Empty> = Map::new("connected_channels");
+
+/// The maximum number of runoffs that may follow an original
+/// proposal. Closing a tied runoff at this depth does not create
+/// another one.
+pub const MAX_RUNOFF_DEPTH: u64 = 3;
@@ -280,6 +280,13 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_multiple_staked_balances_governance(
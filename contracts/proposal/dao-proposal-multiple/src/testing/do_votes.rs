@@ -130,6 +130,13 @@ where
         voting_strategy,
         close_proposal_on_execution_failure: true,
         pre_propose_info,
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let governance_addr = setup_governance(&mut app, instantiate, Some(initial_balances));
@@ -39,6 +39,7 @@ pub fn make_proposal(
                     denom,
                     amount,
                     refund_policy: _,
+                    staking_contract: _,
                 }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
@@ -38,6 +38,8 @@ fn get_pre_propose_info(
             msg: to_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                deposit_min_lock: None,
+                dao_reimburses_passed_deposits: false,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -67,6 +69,13 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
             false,
         ),
         close_proposal_on_execution_failure: true,
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     }
 }
 
@@ -83,6 +92,13 @@ fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     }
 }
 
@@ -269,6 +285,7 @@ pub fn _instantiate_with_native_staked_balances_governance(
                 manager: None,
                 denom: "ujuno".to_string(),
                 unstaking_duration: None,
+                inactivity_decay: None,
             })
             .unwrap(),
             admin: None,
@@ -463,6 +480,7 @@ pub fn instantiate_with_staked_balances_governance(
                     staking_code_id: cw20_stake_id,
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             })
             .unwrap(),
@@ -597,6 +615,7 @@ pub fn instantiate_with_multiple_staked_balances_governance(
                     staking_code_id: cw20_stake_id,
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             })
             .unwrap(),
@@ -706,6 +725,7 @@ pub fn instantiate_with_staking_active_threshold(
                     staking_code_id: cw20_staking_id,
                     unstaking_duration: None,
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
                 active_threshold,
             })
@@ -3,11 +3,11 @@ use cw_hooks::HooksResponse;
 use cw_multi_test::App;
 use dao_core::state::{ProposalModule, ProposalModuleStatus};
 use dao_pre_propose_multiple as cppm;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{pre_propose::ProposalCreationPolicy, status::Status};
 
 use crate::{
     msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse},
+    query::{ProposalConfigResponse, ProposalListResponse, ProposalResponse},
     state::Config,
 };
 
@@ -115,6 +115,42 @@ pub fn query_list_proposals_reverse(
         .unwrap()
 }
 
+pub fn query_proposals_by_status(
+    app: &App,
+    proposal_multiple: &Addr,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::ProposalsByStatus {
+                status,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub fn query_leader_changed_since(
+    app: &App,
+    proposal_multiple: &Addr,
+    proposal_id: u64,
+    height: u64,
+) -> Option<bool> {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::LeaderChangedSince {
+                proposal_id,
+                height,
+            },
+        )
+        .unwrap()
+}
+
 pub fn query_dao_token(app: &App, core_addr: &Addr) -> Addr {
     let voting_module = query_voting_module(app, core_addr);
     app.wrap()
@@ -175,3 +211,13 @@ pub fn query_proposal(app: &App, proposal_multiple: &Addr, id: u64) -> ProposalR
         .query_wasm_smart(proposal_multiple, &QueryMsg::Proposal { proposal_id: id })
         .unwrap()
 }
+
+pub fn query_proposal_config_snapshot(
+    app: &App,
+    proposal_multiple: &Addr,
+    proposal_id: u64,
+) -> ProposalConfigResponse {
+    app.wrap()
+        .query_wasm_smart(proposal_multiple, &QueryMsg::ProposalConfig { proposal_id })
+        .unwrap()
+}
@@ -10,12 +10,13 @@ use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
-        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
-        MAX_NUM_CHOICES,
+        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, TieBreak, VotingStrategy,
+        ABSTAIN_OPTION_ID, MAX_NUM_CHOICES,
     },
     pre_propose::PreProposeInfo,
+    proposal::ParticipationResponse,
     status::Status,
-    threshold::{PercentageThreshold, Threshold},
+    threshold::{NormalizedThreshold, PercentageThreshold, Threshold},
 };
 use dao_voting_cw20_staked::msg::ActiveThreshold;
 use std::panic;
@@ -23,8 +24,11 @@ use std::panic;
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::MultipleChoiceProposal,
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
-    state::Config,
+    query::{
+        OutcomeBoundsResponse, ProposalConfigResponse, ProposalListResponse, ProposalResponse,
+        ProposalTallyResponse, RunnerupResponse, VoteInfo, VoteListResponse, VoteResponse,
+    },
+    state::{Config, OutcomeChannel, MAX_RUNOFF_DEPTH},
     testing::{
         do_votes::do_test_votes_cw20_balances,
         execute::make_proposal,
@@ -34,9 +38,11 @@ use crate::{
         },
         queries::{
             query_balance_cw20, query_balance_native, query_cw20_token_staking_contracts,
-            query_dao_token, query_deposit_config_and_pre_propose_module, query_list_proposals,
-            query_list_proposals_reverse, query_multiple_proposal_module, query_proposal,
-            query_proposal_config, query_proposal_hooks, query_vote_hooks,
+            query_dao_token, query_deposit_config_and_pre_propose_module,
+            query_leader_changed_since, query_list_proposals, query_list_proposals_reverse,
+            query_multiple_proposal_module, query_proposal, query_proposal_config,
+            query_proposal_config_snapshot, query_proposal_hooks, query_proposals_by_status,
+            query_vote_hooks,
         },
     },
     ContractError,
@@ -93,6 +99,8 @@ pub fn get_pre_propose_info(
             msg: to_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                deposit_min_lock: None,
+                dao_reimburses_passed_deposits: false,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -120,6 +128,13 @@ fn test_propose() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -135,6 +150,13 @@ fn test_propose() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: TieBreak::None,
+        min_proposer_power: None,
     };
     assert_eq!(config, expected);
 
@@ -172,9 +194,17 @@ fn test_propose() {
         total_power: Uint128::new(100_000_000),
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(); 3],
+            abstain: Uint128::zero(),
         },
         allow_revoting: false,
         min_voting_period: None,
+        min_participation_for_early_execution: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        proposer_vote: None,
+        only_members_execute: Some(false),
+        runoff_depth: 0,
+        runoff_of: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -199,6 +229,13 @@ fn test_propose_wrong_num_choices() {
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -214,6 +251,13 @@ fn test_propose_wrong_num_choices() {
         allow_revoting: false,
         dao: core_addr,
         voting_strategy,
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: TieBreak::None,
+        min_proposer_power: None,
     };
     assert_eq!(config, expected);
 
@@ -261,6 +305,85 @@ fn test_propose_wrong_num_choices() {
     assert!(err.is_err());
 }
 
+#[test]
+fn test_propose_wrong_number_of_choices() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, count: u32| {
+        let options = (0..count)
+            .map(|i| MultipleChoiceOption {
+                title: format!("option {i}"),
+                description: format!("option {i}"),
+                msgs: vec![],
+            })
+            .collect();
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions { options },
+                proposer: None,
+            },
+            &[],
+        )
+    };
+
+    // No options at all, a lone option that would only compete with
+    // "None of the above", and more options than the maximum.
+    for count in [0, 1, MAX_NUM_CHOICES + 1] {
+        let err: ContractError = propose(&mut app, count).unwrap_err().downcast().unwrap();
+        assert!(matches!(err, ContractError::WrongNumberOfChoices {}));
+    }
+
+    // Options at the limits are accepted, and a single "None of the
+    // above" option is added after them.
+    for count in [2, MAX_NUM_CHOICES] {
+        propose(&mut app, count).unwrap();
+        let id: u64 = app
+            .wrap()
+            .query_wasm_smart(&govmod, &QueryMsg::NextProposalId {})
+            .unwrap();
+        let proposal = query_proposal(&app, &govmod, id - 1).proposal;
+        assert_eq!(proposal.choices.len(), count as usize + 1);
+        for (i, choice) in proposal.choices.iter().enumerate() {
+            assert_eq!(choice.index, i as u32);
+            assert_eq!(choice.vote_count, Uint128::zero());
+            let expected = if i == count as usize {
+                MultipleChoiceOptionType::None
+            } else {
+                MultipleChoiceOptionType::Standard
+            };
+            assert_eq!(choice.option_type, expected);
+        }
+        assert_eq!(
+            proposal.votes.vote_weights,
+            vec![Uint128::zero(); count as usize + 1]
+        );
+    }
+}
+
 #[test]
 fn test_proposal_count_initialized_to_zero() {
     let mut app = App::default();
@@ -275,6 +398,13 @@ fn test_proposal_count_initialized_to_zero() {
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, msg, None);
 
@@ -309,6 +439,13 @@ fn test_no_early_pass_with_min_duration() {
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -403,6 +540,13 @@ fn test_propose_with_messages() {
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -439,6 +583,13 @@ fn test_propose_with_messages() {
         only_members_execute: false,
         allow_revoting: false,
         dao: "dao".to_string(),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let wasm_msg = WasmMsg::Execute {
@@ -522,6 +673,13 @@ fn test_min_duration_units_missmatch() {
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -554,6 +712,13 @@ fn test_min_duration_larger_than_proposal_duration() {
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -585,6 +750,13 @@ fn test_min_duration_same_as_proposal_duration() {
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -705,6 +877,13 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
             }),
             false,
         ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -726,7 +905,8 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
         Some(CheckedDepositInfo {
             denom: CheckedDenom::Cw20(token),
             amount: Uint128::new(1),
-            refund_policy: DepositRefundPolicy::OnlyPassed
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            staking_contract: None,
         })
     )
 }
@@ -777,6 +957,13 @@ fn test_different_token_proposal_deposit() {
             }),
             false,
         ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -838,6 +1025,13 @@ fn test_bad_token_proposal_deposit() {
             }),
             false,
         ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -868,6 +1062,13 @@ fn test_take_proposal_deposit() {
             }),
             false,
         ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -973,6 +1174,13 @@ fn test_native_proposal_deposit() {
             }),
             false,
         ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1282,6 +1490,63 @@ fn test_invalid_quorum() {
     );
 }
 
+#[test]
+fn test_query_participation() {
+    let (app, core_addr) = do_test_votes_cw20_balances(
+        vec![
+            TestMultipleChoiceVote {
+                voter: "blue".to_string(),
+                position: MultipleChoiceVote { option_id: 0 },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestMultipleChoiceVote {
+                voter: "note".to_string(),
+                position: MultipleChoiceVote { option_id: 1 },
+                weight: Uint128::new(20),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Open,
+        Some(Uint128::new(100)),
+        None,
+        false,
+    );
+
+    let gov_state: dao_core::query::DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .unwrap();
+    let govmod = gov_state
+        .proposal_modules
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+
+    let participation: ParticipationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            govmod,
+            &QueryMsg::Participation {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        participation,
+        ParticipationResponse {
+            open_proposals: 1,
+            votes_cast: Uint128::new(30),
+            total_power: Uint128::new(100),
+        }
+    );
+}
+
 #[test]
 fn test_cant_vote_executed_or_closed() {
     // Create a proposal that will be rejected
@@ -1395,6 +1660,13 @@ fn test_cant_propose_zero_power() {
             }),
             false,
         ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -1559,6 +1831,13 @@ fn test_cant_execute_not_member() {
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1649,6 +1928,13 @@ fn test_cant_execute_not_member_when_proposal_created() {
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1766,6 +2052,13 @@ fn test_open_proposal_submission() {
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
     let govmod = query_multiple_proposal_module(&app, &core_addr);
@@ -1833,7 +2126,15 @@ fn test_open_proposal_submission() {
         ],
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(); 3],
-        },
+            abstain: Uint128::zero(),
+        },
+        min_participation_for_early_execution: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        proposer_vote: None,
+        only_members_execute: Some(false),
+        runoff_depth: 0,
+        runoff_of: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -2059,6 +2360,13 @@ fn test_execute_expired_proposal() {
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2208,23 +2516,36 @@ fn test_update_config() {
 
     // Attempt to update the config from a non-dao address. This
     // should fail as it is unauthorized.
-    app.execute_contract(
-        Addr::unchecked("wrong"),
-        govmod.clone(),
-        &ExecuteMsg::UpdateConfig {
-            voting_strategy: VotingStrategy::SingleChoice {
-                quorum: PercentageThreshold::Majority {},
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("wrong"),
+            govmod.clone(),
+            &ExecuteMsg::UpdateConfig {
+                voting_strategy: VotingStrategy::SingleChoice {
+                    quorum: PercentageThreshold::Majority {},
+                },
+                min_voting_period: None,
+                close_proposal_on_execution_failure: true,
+                max_voting_period: cw_utils::Duration::Height(10),
+                only_members_execute: false,
+                allow_revoting: false,
+                dao: dao.to_string(),
+                min_participation_for_early_execution: None,
+                tie_runoff: false,
+                max_proposal_msgs: None,
+                text_limits: None,
+                none_rejection_quorum: None,
+                tie_break: None,
+                min_proposer_power: None,
             },
-            min_voting_period: None,
-            close_proposal_on_execution_failure: true,
-            max_voting_period: cw_utils::Duration::Height(10),
-            only_members_execute: false,
-            allow_revoting: false,
-            dao: dao.to_string(),
-        },
-        &[],
-    )
-    .unwrap_err();
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let proposal = query_proposal(&app, &govmod, 1).proposal;
 
     // Update the config from the DAO address. This should succeed.
     app.execute_contract(
@@ -2240,6 +2561,13 @@ fn test_update_config() {
             only_members_execute: false,
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         &[],
     )
@@ -2257,9 +2585,20 @@ fn test_update_config() {
         only_members_execute: false,
         allow_revoting: false,
         dao: Addr::unchecked(CREATOR_ADDR),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: TieBreak::None,
+        min_proposer_power: None,
     };
     assert_eq!(govmod_config, expected);
 
+    // Existing proposals keep the voting strategy, expiration, and
+    // total power they were created with.
+    assert_eq!(query_proposal(&app, &govmod, 1).proposal, proposal);
+
     // As we have changed the DAO address updating the config using
     // the original one should now fail.
     app.execute_contract(
@@ -2275,6 +2614,13 @@ fn test_update_config() {
             only_members_execute: false,
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         &[],
     )
@@ -2350,6 +2696,13 @@ fn test_query_list_proposals() {
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
     let gov_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -2427,9 +2780,17 @@ fn test_query_list_proposals() {
             total_power: Uint128::new(100),
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
+                abstain: Uint128::zero(),
             },
             allow_revoting: false,
             min_voting_period: None,
+            min_participation_for_early_execution: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            proposer_vote: None,
+            only_members_execute: Some(false),
+            runoff_depth: 0,
+            runoff_of: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2455,9 +2816,17 @@ fn test_query_list_proposals() {
             total_power: Uint128::new(100),
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
+                abstain: Uint128::zero(),
             },
             allow_revoting: false,
             min_voting_period: None,
+            min_participation_for_early_execution: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            proposer_vote: None,
+            only_members_execute: Some(false),
+            runoff_depth: 0,
+            runoff_of: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2483,6 +2852,13 @@ fn test_hooks() {
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -2609,6 +2985,13 @@ fn test_active_threshold_absolute() {
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -2736,6 +3119,13 @@ fn test_active_threshold_percent() {
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     // 20% needed to be active, 20% of 100000000 is 20000000
@@ -2864,6 +3254,13 @@ fn test_active_threshold_none() {
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr =
@@ -2974,6 +3371,13 @@ fn test_revoting() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3106,6 +3510,13 @@ fn test_allow_revoting_config_changes() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3163,6 +3574,13 @@ fn test_allow_revoting_config_changes() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         &[],
     )
@@ -3257,6 +3675,13 @@ fn test_revoting_same_vote_twice() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3351,6 +3776,13 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3539,6 +3971,13 @@ fn test_close_failed_proposal() {
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(&mut app, instantiate, None, None);
@@ -3673,6 +4112,13 @@ fn test_close_failed_proposal() {
                                     allow_revoting: false,
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
+                                    min_participation_for_early_execution: None,
+                                    tie_runoff: false,
+                                    max_proposal_msgs: None,
+                                    text_limits: None,
+                                    none_rejection_quorum: None,
+                                    tie_break: None,
+                                    min_proposer_power: None,
                                 })
                                 .unwrap(),
                                 funds: vec![],
@@ -3787,6 +4233,13 @@ fn test_no_double_refund_on_execute_fail_and_close() {
             }),
             false,
         ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -3965,6 +4418,13 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -4056,6 +4516,13 @@ fn test_next_proposal_id() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4127,6 +4594,13 @@ fn test_vote_with_rationale() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4223,6 +4697,13 @@ fn test_revote_with_rationale() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4377,6 +4858,13 @@ fn test_update_rationale() {
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4490,3 +4978,1836 @@ fn test_update_rationale() {
         Some("This may be a good idea, but I'm not sure. YOLO".to_string())
     );
 }
+
+#[test]
+fn test_proposal_config_snapshot() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: true,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: get_pre_propose_info(
+            &mut app,
+            Some(UncheckedDepositInfo {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native("ujuno".to_string()),
+                },
+                amount: Uint128::new(1),
+                refund_policy: DepositRefundPolicy::Always,
+            }),
+            false,
+        ),
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: CREATOR_ADDR.to_string(),
+        amount: vec![Coin {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(2),
+        }],
+    }))
+    .unwrap();
+
+    let options = MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                description: "multiple choice option 1".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+            },
+            MultipleChoiceOption {
+                description: "multiple choice option 2".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+            },
+        ],
+    };
+    let proposal_id = make_proposal(&mut app, &govmod, CREATOR_ADDR, options.clone());
+
+    let expected = ProposalConfigResponse {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_participation_for_early_execution: None,
+        allow_revoting: false,
+        only_members_execute: Some(true),
+        deposit_info: Some(CheckedDepositInfo {
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(1),
+            refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
+        }),
+    };
+    assert_eq!(
+        query_proposal_config_snapshot(&app, &govmod, proposal_id),
+        expected
+    );
+
+    // Changing the module's config does not change the config
+    // reported for existing proposals.
+    app.execute_contract(
+        core_addr.clone(),
+        govmod.clone(),
+        &ExecuteMsg::UpdateConfig {
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+            },
+            min_voting_period: None,
+            close_proposal_on_execution_failure: true,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: true,
+            dao: core_addr.to_string(),
+            min_participation_for_early_execution: Some(PercentageThreshold::Percent(
+                Decimal::percent(50),
+            )),
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break: None,
+            min_proposer_power: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal_config_snapshot(&app, &govmod, proposal_id),
+        expected
+    );
+
+    // New proposals pick up the new config.
+    let proposal_id = make_proposal(&mut app, &govmod, CREATOR_ADDR, options);
+    assert_eq!(
+        query_proposal_config_snapshot(&app, &govmod, proposal_id),
+        ProposalConfigResponse {
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+            },
+            min_participation_for_early_execution: Some(PercentageThreshold::Percent(
+                Decimal::percent(50)
+            )),
+            allow_revoting: true,
+            only_members_execute: Some(false),
+            ..expected
+        }
+    );
+
+    // Querying a proposal that does not exist fails.
+    app.wrap()
+        .query_wasm_smart::<ProposalConfigResponse>(
+            &govmod,
+            &QueryMsg::ProposalConfig { proposal_id: 3 },
+        )
+        .unwrap_err();
+}
+
+#[test]
+fn test_tie_runoff() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: true,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("blue", 10), ("red", 10), ("green", 5)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |title: &str| MultipleChoiceOption {
+        title: title.to_string(),
+        description: format!("{title} description"),
+        msgs: vec![],
+    };
+    make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b"), option("c")],
+        },
+    );
+
+    let vote = |app: &mut App, voter: &str, proposal_id: u64, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // "a" and "b" tie. As all voting power has been used the proposal
+    // is rejected.
+    vote(&mut app, "blue", 1, 0);
+    vote(&mut app, "red", 1, 1);
+    vote(&mut app, "green", 1, 2);
+    let proposal = query_proposal(&app, &govmod, 1).proposal;
+    assert_eq!(proposal.status, Status::Rejected);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Close { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal(&app, &govmod, 1).proposal.status,
+        Status::Closed
+    );
+
+    let runoff_id: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::Runoff { proposal_id: 1 })
+        .unwrap();
+    assert_eq!(runoff_id, Some(2));
+
+    // The runoff is between the tied options only.
+    let runoff = query_proposal(&app, &govmod, 2).proposal;
+    assert_eq!(runoff.status, Status::Open);
+    assert_eq!(runoff.title, "Runoff: title");
+    assert_eq!(runoff.proposer, Addr::unchecked(CREATOR_ADDR));
+    assert_eq!(runoff.runoff_of, Some(1));
+    assert_eq!(runoff.runoff_depth, 1);
+    assert_eq!(
+        runoff.choices,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b")],
+        }
+        .into_checked()
+        .unwrap()
+        .options
+    );
+
+    // Runoffs that tie create further runoffs until the maximum
+    // depth is reached.
+    for proposal_id in 2..=(MAX_RUNOFF_DEPTH + 1) {
+        vote(&mut app, "blue", proposal_id, 0);
+        vote(&mut app, "red", proposal_id, 1);
+        vote(&mut app, "green", proposal_id, 2);
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Close { proposal_id },
+            &[],
+        )
+        .unwrap();
+    }
+    let runoff = query_proposal(&app, &govmod, MAX_RUNOFF_DEPTH + 1).proposal;
+    assert_eq!(runoff.status, Status::Closed);
+    assert_eq!(runoff.runoff_depth, MAX_RUNOFF_DEPTH);
+    let runoff_id: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::Runoff {
+                proposal_id: MAX_RUNOFF_DEPTH + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(runoff_id, None);
+    let count: u64 = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::ProposalCount {})
+        .unwrap();
+    assert_eq!(count, MAX_RUNOFF_DEPTH + 1);
+}
+
+#[test]
+fn test_vote_no_power_at_snapshot() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![
+                MultipleChoiceOption {
+                    description: "multiple choice option 1".to_string(),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                },
+                MultipleChoiceOption {
+                    description: "multiple choice option 2".to_string(),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                },
+            ],
+        },
+    );
+
+    // "late" stakes after the proposal is created.
+    let (token_contract, staking_contract) = query_cw20_token_staking_contracts(&app, &core_addr);
+    app.execute_contract(
+        core_addr,
+        token_contract.clone(),
+        &cw20::Cw20ExecuteMsg::Mint {
+            recipient: "late".to_string(),
+            amount: Uint128::new(10),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("late"),
+        token_contract,
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: staking_contract.to_string(),
+            amount: Uint128::new(10),
+            msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let vote = |app: &mut App, voter: &str| -> ContractError {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id: 0 },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+    let start_height = query_proposal(&app, &govmod, proposal_id)
+        .proposal
+        .start_height;
+    assert!(matches!(
+        vote(&mut app, "late"),
+        ContractError::NoVotingPowerAtSnapshot { snapshot_height } if snapshot_height == start_height
+    ));
+    // Addresses with no voting power at all are not registered.
+    assert!(matches!(
+        vote(&mut app, "ekez"),
+        ContractError::NotRegistered {}
+    ));
+}
+
+#[test]
+fn test_query_runnerup() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("blue", 10), ("red", 7), ("green", 3)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |title: &str| MultipleChoiceOption {
+        title: title.to_string(),
+        description: format!("{title} description"),
+        msgs: vec![],
+    };
+    make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b"), option("c"), option("d")],
+        },
+    );
+    let query_runnerup = |app: &App| -> RunnerupResponse {
+        app.wrap()
+            .query_wasm_smart(&govmod, &QueryMsg::Runnerup { proposal_id: 1 })
+            .unwrap()
+    };
+
+    // No votes, no runner-up.
+    assert_eq!(
+        query_runnerup(&app),
+        RunnerupResponse {
+            option: None,
+            votes: Uint128::zero(),
+            margin: Uint128::zero(),
+        }
+    );
+
+    for (voter, option_id) in [("blue", 2), ("red", 0), ("green", 1)] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let proposal = query_proposal(&app, &govmod, 1).proposal;
+    assert_eq!(
+        query_runnerup(&app),
+        RunnerupResponse {
+            option: Some(proposal.choices[0].clone()),
+            votes: Uint128::new(7),
+            margin: Uint128::new(3),
+        }
+    );
+}
+
+#[test]
+fn test_max_proposal_msgs() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: Some(2),
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |title: &str, count: usize| MultipleChoiceOption {
+        title: title.to_string(),
+        description: format!("{title} description"),
+        msgs: vec![
+            WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&dao_core::msg::ExecuteMsg::UpdateCw20List {
+                    to_add: vec![],
+                    to_remove: vec![],
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into();
+            count
+        ],
+    };
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![option("a", 1), option("b", 3)],
+                },
+                proposer: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::TooManyProposalMsgs { count: 3, max: 2 }
+    ));
+
+    // Options at the limit are accepted.
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a", 2), option("b", 2)],
+        },
+    );
+    let proposal = query_proposal(&app, &govmod, proposal_id).proposal;
+    assert_eq!(proposal.choices[0].msgs.len(), 2);
+    assert_eq!(proposal.choices[1].msgs.len(), 2);
+}
+
+#[test]
+fn test_normalized_threshold() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let normalized: NormalizedThreshold = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::NormalizedThreshold {})
+        .unwrap();
+    assert_eq!(
+        normalized,
+        NormalizedThreshold {
+            quorum: Some(PercentageThreshold::Percent(Decimal::percent(20))),
+            threshold: None,
+            absolute: None,
+            veto: None,
+        }
+    );
+
+    // The quorum is comparable with that of a single choice module
+    // using the same quorum.
+    let single = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+    }
+    .normalize();
+    assert_eq!(normalized.quorum, single.quorum);
+}
+
+#[test]
+fn test_abstain_vote() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("blue", 10), ("red", 3)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |title: &str| MultipleChoiceOption {
+        title: title.to_string(),
+        description: format!("{title} description"),
+        msgs: vec![],
+    };
+    let vote = |app: &mut App, voter: &str, proposal_id: u64, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // The abstaining majority meets quorum while the minority's
+    // option wins.
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b")],
+        },
+    );
+    vote(&mut app, "blue", proposal_id, ABSTAIN_OPTION_ID);
+    vote(&mut app, "red", proposal_id, 0);
+    let proposal = query_proposal(&app, &govmod, proposal_id).proposal;
+    assert_eq!(proposal.votes.abstain, Uint128::new(10));
+    assert_eq!(proposal.votes.total(), Uint128::new(13));
+    assert_eq!(proposal.status, Status::Passed);
+
+    // If everyone abstains no option wins.
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b")],
+        },
+    );
+    vote(&mut app, "blue", proposal_id, ABSTAIN_OPTION_ID);
+    vote(&mut app, "red", proposal_id, ABSTAIN_OPTION_ID);
+    let proposal = query_proposal(&app, &govmod, proposal_id).proposal;
+    assert_eq!(proposal.votes.vote_weights, vec![Uint128::zero(); 3]);
+    assert_eq!(proposal.status, Status::Rejected);
+}
+
+#[test]
+fn test_no_votes_expired_rejects_without_runoff() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::zero()),
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: true,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |title: &str| MultipleChoiceOption {
+        title: title.to_string(),
+        description: format!("{title} description"),
+        msgs: vec![],
+    };
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b")],
+        },
+    );
+
+    app.update_block(|b| b.height += 6);
+    let proposal = query_proposal(&app, &govmod, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Rejected);
+
+    // Closing does not treat the unvoted options as tied.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Close { proposal_id },
+        &[],
+    )
+    .unwrap();
+    let runoff_id: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::Runoff { proposal_id })
+        .unwrap();
+    assert_eq!(runoff_id, None);
+    let count: u64 = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::ProposalCount {})
+        .unwrap();
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_proposals_by_status() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: "blue".to_string(),
+            amount: Uint128::new(10),
+        }]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let choices = MultipleChoiceOptions {
+        options: ["a", "b"]
+            .into_iter()
+            .map(|title| MultipleChoiceOption {
+                title: title.to_string(),
+                description: format!("{title} description"),
+                msgs: vec![],
+            })
+            .collect(),
+    };
+    let ids: Vec<u64> = (0..4)
+        .map(|_| make_proposal(&mut app, &govmod, CREATOR_ADDR, choices.clone()))
+        .collect();
+
+    // The first proposal passes and the second is rejected by a
+    // vote for "None of the above". The others remain open.
+    for (proposal_id, option_id) in [(ids[0], 0), (ids[1], 2)] {
+        app.execute_contract(
+            Addr::unchecked("blue"),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let by_status = |app: &App, status: Status, start_after: Option<u64>, limit: Option<u32>| {
+        query_proposals_by_status(app, &govmod, status, start_after, limit)
+            .proposals
+            .into_iter()
+            .map(|response| response.id)
+            .collect::<Vec<u64>>()
+    };
+    assert_eq!(by_status(&app, Status::Passed, None, None), vec![ids[0]]);
+    assert_eq!(by_status(&app, Status::Rejected, None, None), vec![ids[1]]);
+    assert_eq!(
+        by_status(&app, Status::Open, None, None),
+        vec![ids[2], ids[3]]
+    );
+
+    // Once expired the open proposals are rejected, even though
+    // their stored status has not been updated.
+    app.update_block(|block| block.height += 6);
+    assert_eq!(by_status(&app, Status::Open, None, None), Vec::<u64>::new());
+    assert_eq!(
+        by_status(&app, Status::Rejected, None, None),
+        vec![ids[1], ids[2], ids[3]]
+    );
+    assert_eq!(
+        by_status(&app, Status::Rejected, Some(ids[1]), Some(1)),
+        vec![ids[2]]
+    );
+
+    let count: u64 = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::ProposalCount {})
+        .unwrap();
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn test_leader_changed_since() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(10),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("blue", 2), ("red", 3), ("green", 10)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let choices = MultipleChoiceOptions {
+        options: ["a", "b"]
+            .into_iter()
+            .map(|title| MultipleChoiceOption {
+                title: title.to_string(),
+                description: format!("{title} description"),
+                msgs: vec![],
+            })
+            .collect(),
+    };
+    let created = app.block_info().height;
+    let id = make_proposal(&mut app, &govmod, CREATOR_ADDR, choices);
+
+    let vote = |app: &mut App, voter: &str, option_id: u32| {
+        app.update_block(next_block);
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.block_info().height
+    };
+
+    // No leader until "a" takes the lead, then "b" overtakes it.
+    let a_leads = vote(&mut app, "blue", 0);
+    let b_leads = vote(&mut app, "red", 1);
+
+    let changed = |app: &App, height: u64| query_leader_changed_since(app, &govmod, id, height);
+    assert_eq!(changed(&app, created), Some(true));
+    assert_eq!(changed(&app, a_leads), Some(true));
+    assert_eq!(changed(&app, b_leads), Some(false));
+    // Heights between votes use the leader after the last vote.
+    app.update_block(next_block);
+    assert_eq!(changed(&app, b_leads + 1), Some(false));
+
+    // The proposal did not exist before it was created.
+    assert_eq!(changed(&app, created - 1), None);
+    assert_eq!(
+        query_leader_changed_since(&app, &govmod, id + 1, created),
+        None
+    );
+}
+
+#[test]
+fn test_height_based_expiration() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(10),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: Some(Duration::Height(2)),
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(10),
+        }]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let choices = || MultipleChoiceOptions {
+        options: ["a", "b"]
+            .into_iter()
+            .map(|title| MultipleChoiceOption {
+                title: title.to_string(),
+                description: format!("{title} description"),
+                msgs: vec![],
+            })
+            .collect(),
+    };
+    let rejected = make_proposal(&mut app, &govmod, CREATOR_ADDR, choices());
+    let passed = make_proposal(&mut app, &govmod, CREATOR_ADDR, choices());
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id: passed,
+            vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only block height moves height based voting periods forward.
+    app.update_block(|b| b.time = b.time.plus_seconds(604800 * 4));
+    let status = |app: &App, id: u64| query_proposal(app, &govmod, id).proposal.status;
+    assert_eq!(status(&app, rejected), Status::Open);
+    assert_eq!(status(&app, passed), Status::Open);
+
+    // The minimum voting period elapses.
+    app.update_block(|b| b.height += 2);
+    assert_eq!(status(&app, rejected), Status::Open);
+    assert_eq!(status(&app, passed), Status::Passed);
+
+    // The maximum voting period elapses.
+    app.update_block(|b| b.height += 8);
+    assert_eq!(status(&app, rejected), Status::Rejected);
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Close {
+            proposal_id: rejected,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(status(&app, rejected), Status::Closed);
+}
+
+#[test]
+fn test_tie_break() {
+    // Options "a" and "b" tie, with the proposer voting for "b".
+    let tied = [("blue", 0), ("red", 1), (CREATOR_ADDR, 1)];
+    // Option "a" ties with "None of the above", which the proposer
+    // votes for.
+    let tied_with_none = [("blue", 0), ("red", 2), (CREATOR_ADDR, 2)];
+
+    for (tie_break, votes, expected) in [
+        (None, tied, Status::Rejected),
+        (Some(TieBreak::None), tied, Status::Rejected),
+        (Some(TieBreak::FirstIndex), tied, Status::Passed),
+        (Some(TieBreak::Proposer), tied, Status::Passed),
+        (Some(TieBreak::FirstIndex), tied_with_none, Status::Passed),
+        (Some(TieBreak::Proposer), tied_with_none, Status::Rejected),
+    ] {
+        let mut app = App::default();
+        let instantiate = InstantiateMsg {
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            min_voting_period: None,
+            close_proposal_on_execution_failure: true,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            min_participation_for_early_execution: None,
+            tie_runoff: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            none_rejection_quorum: None,
+            tie_break,
+            min_proposer_power: None,
+        };
+        let core_addr = instantiate_with_cw20_balances_governance(
+            &mut app,
+            instantiate,
+            Some(
+                [("blue", 10), ("red", 5), (CREATOR_ADDR, 5)]
+                    .into_iter()
+                    .map(|(address, amount)| Cw20Coin {
+                        address: address.to_string(),
+                        amount: Uint128::new(amount),
+                    })
+                    .collect(),
+            ),
+        );
+        let govmod = query_multiple_proposal_module(&app, &core_addr);
+        assert_eq!(
+            query_proposal_config(&app, &govmod).tie_break,
+            tie_break.unwrap_or_default()
+        );
+
+        let choices = MultipleChoiceOptions {
+            options: ["a", "b"]
+                .into_iter()
+                .map(|title| MultipleChoiceOption {
+                    title: title.to_string(),
+                    description: format!("{title} description"),
+                    msgs: vec![],
+                })
+                .collect(),
+        };
+        let id = make_proposal(&mut app, &govmod, CREATOR_ADDR, choices);
+        for (voter, option_id) in votes {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                govmod.clone(),
+                &ExecuteMsg::Vote {
+                    proposal_id: id,
+                    vote: MultipleChoiceVote { option_id },
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        app.update_block(|b| b.height += 6);
+        assert_eq!(
+            query_proposal(&app, &govmod, id).proposal.status,
+            expected,
+            "{tie_break:?}"
+        );
+    }
+}
+
+#[test]
+fn test_min_proposer_power() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: Some(Uint128::new(2)),
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(2),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    assert_eq!(
+        query_proposal_config(&app, &govmod).min_proposer_power,
+        Some(Uint128::new(2))
+    );
+
+    let choices = MultipleChoiceOptions {
+        options: vec![MultipleChoiceOption {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+        }],
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: choices.clone(),
+                proposer: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::InsufficientProposerPower { power, min }
+            if power == Uint128::new(1) && min == Uint128::new(2)
+    ));
+
+    make_proposal(&mut app, &govmod, "whale", choices);
+}
+
+#[test]
+fn test_lifecycle_events() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("blue", 10), ("red", 5), (CREATOR_ADDR, 5)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    // Gets the attributes this module emitted in response to SENDER
+    // sending MSG.
+    let mut execute = |sender: &str, msg: ExecuteMsg| -> Vec<(String, String)> {
+        let res = app
+            .execute_contract(Addr::unchecked(sender), govmod.clone(), &msg, &[])
+            .unwrap();
+        res.events
+            .into_iter()
+            .filter(|event| {
+                event.ty == "wasm"
+                    && event
+                        .attributes
+                        .iter()
+                        .any(|attr| attr.key == "_contract_addr" && attr.value == govmod.as_str())
+            })
+            .flat_map(|event| event.attributes)
+            .map(|attr| (attr.key, attr.value))
+            .collect()
+    };
+    let has = |attributes: &[(String, String)], key: &str, value: &str| {
+        attributes.iter().any(|(k, v)| k == key && v == value)
+    };
+    let propose = || ExecuteMsg::Propose {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        choices: MultipleChoiceOptions {
+            options: ["a", "b"]
+                .into_iter()
+                .map(|title| MultipleChoiceOption {
+                    title: title.to_string(),
+                    description: format!("{title} description"),
+                    msgs: vec![],
+                })
+                .collect(),
+        },
+        proposer: None,
+    };
+    let vote = |proposal_id, option_id| ExecuteMsg::Vote {
+        proposal_id,
+        vote: MultipleChoiceVote { option_id },
+        rationale: None,
+    };
+
+    let events = execute(CREATOR_ADDR, propose());
+    assert!(has(&events, "action", "propose"));
+    assert!(has(&events, "proposal_id", "1"));
+    assert!(has(&events, "status", "open"));
+    assert!(has(&events, "sender", CREATOR_ADDR));
+
+    let events = execute("blue", vote(1, 0));
+    assert!(has(&events, "action", "vote"));
+    assert!(has(&events, "proposal_id", "1"));
+    assert!(has(&events, "status", "open"));
+    assert!(has(&events, "sender", "blue"));
+    assert!(has(&events, "vote", "0"));
+    assert!(has(&events, "power", "10"));
+
+    let events = execute("red", vote(1, 0));
+    assert!(has(&events, "status", "passed"));
+    assert!(has(&events, "power", "5"));
+
+    let events = execute("red", ExecuteMsg::Execute { proposal_id: 1 });
+    assert!(has(&events, "action", "execute"));
+    assert!(has(&events, "proposal_id", "1"));
+    assert!(has(&events, "status", "executed"));
+    assert!(has(&events, "sender", "red"));
+
+    // Option 2 is "None of the above".
+    execute(CREATOR_ADDR, propose());
+    execute("blue", vote(2, 2));
+    let events = execute("red", vote(2, 2));
+    assert!(has(&events, "status", "rejected"));
+
+    let events = execute("blue", ExecuteMsg::Close { proposal_id: 2 });
+    assert!(has(&events, "action", "close"));
+    assert!(has(&events, "proposal_id", "2"));
+    assert!(has(&events, "status", "closed"));
+    assert!(has(&events, "sender", "blue"));
+}
+
+#[test]
+fn test_proposal_tally() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: true,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("blue", 10), ("red", 7), ("green", 3)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |title: &str| MultipleChoiceOption {
+        title: title.to_string(),
+        description: format!("{title} description"),
+        msgs: vec![],
+    };
+    let id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b")],
+        },
+    );
+    let query_tally = |app: &App| -> ProposalTallyResponse {
+        app.wrap()
+            .query_wasm_smart(&govmod, &QueryMsg::ProposalTally { proposal_id: id })
+            .unwrap()
+    };
+    let vote = |app: &mut App, voter: &str, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    let tally = query_tally(&app);
+    assert_eq!(tally.vote_weights, vec![Uint128::zero(); 3]);
+    assert_eq!(tally.total_power, Uint128::new(20));
+    assert!(!tally.quorum_met);
+    assert_eq!(
+        tally
+            .options
+            .iter()
+            .map(|option| (
+                option.index,
+                option.option_type.clone(),
+                option.title.as_str()
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (0, MultipleChoiceOptionType::Standard, "a"),
+            (1, MultipleChoiceOptionType::Standard, "b"),
+            (2, MultipleChoiceOptionType::None, "None of the above"),
+        ]
+    );
+    assert_eq!(tally.options[0].description, "a description");
+
+    // Half of the voting power is not a majority.
+    vote(&mut app, "blue", 1);
+    let tally = query_tally(&app);
+    assert_eq!(
+        tally.vote_weights,
+        vec![Uint128::zero(), Uint128::new(10), Uint128::zero()]
+    );
+    assert!(!tally.quorum_met);
+
+    vote(&mut app, "red", 0);
+    vote(&mut app, "green", 2);
+    // Revotes move voting power between options.
+    vote(&mut app, "blue", 0);
+
+    let tally = query_tally(&app);
+    let expected = vec![Uint128::new(17), Uint128::zero(), Uint128::new(3)];
+    assert_eq!(tally.vote_weights, expected);
+    assert_eq!(
+        tally
+            .options
+            .iter()
+            .map(|option| option.vote_count)
+            .collect::<Vec<_>>(),
+        expected
+    );
+    assert!(tally.quorum_met);
+}
+
+#[test]
+fn test_outcome_bounds() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("blue", 10), ("red", 7), ("green", 3)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = || MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                title: "a".to_string(),
+                description: "a".to_string(),
+                msgs: vec![],
+            },
+            MultipleChoiceOption {
+                title: "b".to_string(),
+                description: "b".to_string(),
+                msgs: vec![],
+            },
+        ],
+    };
+    let query_bounds = |app: &App, proposal_id: u64| -> OutcomeBoundsResponse {
+        app.wrap()
+            .query_wasm_smart(&govmod, &QueryMsg::OutcomeBounds { proposal_id })
+            .unwrap()
+    };
+    let vote = |app: &mut App, voter: &str, proposal_id: u64, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    let undecided = OutcomeBoundsResponse {
+        best_case: Status::Passed,
+        worst_case: Status::Rejected,
+        decided: false,
+    };
+
+    // With no votes cast anything can happen.
+    let id = make_proposal(&mut app, &govmod, CREATOR_ADDR, options());
+    assert_eq!(query_bounds(&app, id), undecided);
+
+    // Half of the voting power is still uncast and could go to
+    // "None of the above".
+    vote(&mut app, "blue", id, 0);
+    assert_eq!(query_bounds(&app, id), undecided);
+
+    // The remaining power can no longer outvote "a".
+    vote(&mut app, "red", id, 0);
+    assert_eq!(
+        query_bounds(&app, id),
+        OutcomeBoundsResponse {
+            best_case: Status::Passed,
+            worst_case: Status::Passed,
+            decided: true,
+        }
+    );
+
+    // Nor can it help a proposal that "None of the above" has won.
+    let id = make_proposal(&mut app, &govmod, CREATOR_ADDR, options());
+    vote(&mut app, "blue", id, 2);
+    vote(&mut app, "red", id, 1);
+    assert_eq!(
+        query_bounds(&app, id),
+        OutcomeBoundsResponse {
+            best_case: Status::Rejected,
+            worst_case: Status::Rejected,
+            decided: true,
+        }
+    );
+}
+
+#[test]
+fn test_update_outcome_channel() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        min_participation_for_early_execution: None,
+        tie_runoff: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        min_proposer_power: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    let query_outcome_channel = |app: &App| -> Option<OutcomeChannel> {
+        app.wrap()
+            .query_wasm_smart(&govmod, &QueryMsg::OutcomeChannel {})
+            .unwrap()
+    };
+    assert_eq!(query_outcome_channel(&app), None);
+
+    let channel = OutcomeChannel {
+        channel_id: "channel-0".to_string(),
+        timeout_seconds: 600,
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::UpdateOutcomeChannel {
+                channel: Some(channel.clone()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // The multi-test app does not open IBC channels, so no channel is
+    // connected to the module.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            govmod.clone(),
+            &ExecuteMsg::UpdateOutcomeChannel {
+                channel: Some(channel),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(
+        matches!(err, ContractError::UnknownOutcomeChannel { channel_id } if channel_id == "channel-0")
+    );
+    assert_eq!(query_outcome_channel(&app), None);
+
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateOutcomeChannel { channel: None },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_outcome_channel(&app), None);
+}
+
+#[test]
+fn test_execute_posts_outcome() {
+    use crate::{
+        contract::{execute, reply},
+        ibc::{ibc_channel_close, ibc_channel_connect, ProposalOutcomePacket, OUTCOME_VERSION},
+        state::{CONFIG, CREATION_POLICY, OUTCOME_CHANNEL, PROPOSALS},
+    };
+    use cosmwasm_std::{
+        coins, from_binary,
+        testing::{
+            mock_dependencies, mock_env, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+            mock_info,
+        },
+        BankMsg, DepsMut, IbcMsg, IbcOrder, IbcTimeout, Reply, ReplyOn, Response, SubMsg,
+        SubMsgResponse, SubMsgResult,
+    };
+    use cw_utils::Expiration;
+    use dao_voting::{
+        pre_propose::ProposalCreationPolicy,
+        reply::{mask_outcome_packet_proposal_id, mask_proposal_execution_proposal_id},
+    };
+
+    // The multi-test app does not route IBC messages, so the module's
+    // state is set up directly.
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum: PercentageThreshold::Majority {},
+    };
+    CONFIG
+        .save(
+            deps.as_mut().storage,
+            &Config {
+                voting_strategy: voting_strategy.clone(),
+                min_voting_period: None,
+                max_voting_period: Duration::Height(6),
+                only_members_execute: false,
+                allow_revoting: false,
+                dao: Addr::unchecked("dao"),
+                close_proposal_on_execution_failure: true,
+                min_participation_for_early_execution: None,
+                tie_runoff: false,
+                max_proposal_msgs: None,
+                text_limits: None,
+                none_rejection_quorum: None,
+                tie_break: TieBreak::None,
+                min_proposer_power: None,
+            },
+        )
+        .unwrap();
+    CREATION_POLICY
+        .save(deps.as_mut().storage, &ProposalCreationPolicy::Anyone {})
+        .unwrap();
+    let choices = MultipleChoiceOptions {
+        options: ["a", "b"]
+            .into_iter()
+            .map(|title| MultipleChoiceOption {
+                title: title.to_string(),
+                description: format!("{title} description"),
+                msgs: vec![],
+            })
+            .collect(),
+    }
+    .into_checked()
+    .unwrap()
+    .options;
+    let proposal = MultipleChoiceProposal {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        proposer: Addr::unchecked(CREATOR_ADDR),
+        start_height: env.block.height,
+        min_voting_period: None,
+        expiration: Expiration::AtHeight(env.block.height + 6),
+        voting_strategy,
+        total_power: Uint128::new(10),
+        status: Status::Passed,
+        votes: MultipleChoiceVotes {
+            vote_weights: vec![Uint128::zero(), Uint128::new(10), Uint128::zero()],
+            abstain: Uint128::zero(),
+        },
+        allow_revoting: false,
+        choices,
+        min_participation_for_early_execution: None,
+        none_rejection_quorum: None,
+        tie_break: None,
+        proposer_vote: None,
+        only_members_execute: Some(false),
+        runoff_of: None,
+        runoff_depth: 0,
+    };
+    PROPOSALS.save(deps.as_mut().storage, 1, &proposal).unwrap();
+    PROPOSALS.save(deps.as_mut().storage, 2, &proposal).unwrap();
+    let mut with_msgs = proposal;
+    with_msgs.choices[1].msgs = vec![BankMsg::Send {
+        to_address: "recipient".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    PROPOSALS
+        .save(deps.as_mut().storage, 3, &with_msgs)
+        .unwrap();
+    PROPOSALS
+        .save(deps.as_mut().storage, 4, &with_msgs)
+        .unwrap();
+
+    // Without an outcome channel nothing is posted.
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+
+    // Only connected channels may be configured, and packets must not
+    // time out immediately.
+    let update_outcome_channel = |deps: DepsMut, channel_id: &str, timeout_seconds: u64| {
+        execute(
+            deps,
+            mock_env(),
+            mock_info("dao", &[]),
+            ExecuteMsg::UpdateOutcomeChannel {
+                channel: Some(OutcomeChannel {
+                    channel_id: channel_id.to_string(),
+                    timeout_seconds,
+                }),
+            },
+        )
+    };
+    let err = update_outcome_channel(deps.as_mut(), "channel-0", 600).unwrap_err();
+    assert!(matches!(err, ContractError::UnknownOutcomeChannel { .. }));
+    ibc_channel_connect(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_channel_connect_ack("channel-0", IbcOrder::Unordered, OUTCOME_VERSION),
+    )
+    .unwrap();
+    let err = update_outcome_channel(deps.as_mut(), "channel-0", 0).unwrap_err();
+    assert!(matches!(err, ContractError::ZeroOutcomeTimeout {}));
+    let err = update_outcome_channel(deps.as_mut(), "channel-1", 600).unwrap_err();
+    assert!(matches!(err, ContractError::UnknownOutcomeChannel { .. }));
+    update_outcome_channel(deps.as_mut(), "channel-0", 600).unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::Execute { proposal_id: 2 },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    // A packet that fails to send does not fail execution.
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Error);
+    assert_eq!(res.messages[0].id, mask_outcome_packet_proposal_id(2));
+    match &res.messages[0].msg {
+        CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id,
+            data,
+            timeout,
+        }) => {
+            assert_eq!(channel_id, "channel-0");
+            assert_eq!(
+                *timeout,
+                IbcTimeout::with_timestamp(env.block.time.plus_seconds(600))
+            );
+            let packet: ProposalOutcomePacket = from_binary(data).unwrap();
+            assert_eq!(
+                packet,
+                ProposalOutcomePacket {
+                    proposal_id: 2,
+                    status: Status::Executed,
+                    winning_option: 1,
+                }
+            );
+        }
+        msg => panic!("expected an IBC packet, got {msg:?}"),
+    }
+    let res = reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: mask_outcome_packet_proposal_id(2),
+            result: SubMsgResult::Err("channel not found".to_string()),
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+
+    // The outcome of an execution that may fail is posted once, when
+    // the execution is replied to.
+    let outcome_status = |res: &Response| match &res.messages[..] {
+        [SubMsg {
+            msg: CosmosMsg::Ibc(IbcMsg::SendPacket { data, .. }),
+            ..
+        }] => from_binary::<ProposalOutcomePacket>(data).unwrap().status,
+        msgs => panic!("expected one IBC packet, got {msgs:?}"),
+    };
+    for (proposal_id, result, status) in [
+        (
+            3,
+            SubMsgResult::Err("insufficient funds".to_string()),
+            Status::ExecutionFailed,
+        ),
+        (
+            4,
+            SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+            Status::Executed,
+        ),
+    ] {
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::Execute { proposal_id },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].reply_on, ReplyOn::Always);
+        assert_eq!(
+            res.messages[0].id,
+            mask_proposal_execution_proposal_id(proposal_id)
+        );
+        let res = reply(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: mask_proposal_execution_proposal_id(proposal_id),
+                result,
+            },
+        )
+        .unwrap();
+        assert_eq!(outcome_status(&res), status);
+        let proposal = PROPOSALS.load(&deps.storage, proposal_id).unwrap();
+        assert_eq!(proposal.status, status);
+    }
+
+    // Closing the channel stops outcomes from being posted to it.
+    ibc_channel_close(
+        deps.as_mut(),
+        env,
+        mock_ibc_channel_close_init("channel-0", IbcOrder::Unordered, OUTCOME_VERSION),
+    )
+    .unwrap();
+    assert_eq!(OUTCOME_CHANNEL.may_load(&deps.storage).unwrap(), None);
+    let err = update_outcome_channel(deps.as_mut(), "channel-0", 600).unwrap_err();
+    assert!(matches!(err, ContractError::UnknownOutcomeChannel { .. }));
+}
@@ -1,31 +1,48 @@
+use cosmwasm_schema::serde::Serialize;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    from_binary, to_binary, to_vec, Addr, Attribute, BankMsg, Binary, BlockInfo, Coin, CosmosMsg,
+    Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response, StdError, StdResult,
+    Storage, SubMsg, SubMsgResult, Timestamp, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_hooks::Hooks;
 use cw_proposal_single_v1 as v1;
-use cw_storage_plus::Bound;
-use cw_utils::{parse_reply_instantiate_data, Duration};
-use dao_interface::voting::IsActiveResponse;
+use cw_storage_plus::{Bound, Map};
+use cw_utils::{parse_reply_instantiate_data, Duration, Expiration};
+use dao_interface::voting::{self, IsActiveResponse, LastUnstakeResponse, StakingQuery};
+use dao_pre_propose_base::msg::DepositInfoResponse;
 use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
 use dao_vote_hooks::new_vote_hooks;
+use dao_voting::deposit::CheckedDepositInfo;
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+use dao_voting::precondition::Precondition;
 use dao_voting::proposal::{
-    SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
+    proposal_attributes, ParticipationResponse, SingleChoiceProposeMsg as ProposeMsg,
+    DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
 };
 use dao_voting::reply::{
-    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
+    mask_proposal_execution_state_hash_id, mask_proposal_message_execution_id, TaggedReplyId,
 };
 use dao_voting::status::Status;
-use dao_voting::threshold::Threshold;
-use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
-
-use crate::msg::MigrateMsg;
-use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use dao_voting::text_limits::ProposalTextLimits;
+use dao_voting::threshold::{PercentageThreshold, Threshold};
+use dao_voting::voting::{
+    get_total_power, get_voting_power, total_power_at_height, validate_voting_period,
+    voting_power_at_height, Vote, Votes,
+};
+use sha2::{Digest, Sha256};
+
+use crate::msg::{MigrateMsg, UncheckedProposerNftGate};
+use crate::proposal::{next_proposal_id, ProposalPhase, SingleChoiceProposal};
+use crate::state::{
+    Blackout, Config, ConfirmationPhase, MessageExecution, ProposerNftGate, SingleChoiceTiePolicy,
+    StatusTransition, AUDITED_CONTRACTS, CONFIRMATION_BALLOTS, CREATION_POLICY, EXECUTING_CHUNKS,
+    EXECUTION_REPORTS, LINKED_MODULE, MAX_STATUS_HISTORY, MESSAGE_ORIGINS, PROPOSALS_BY_PROPOSER,
+    PROPOSER_STATS, STATUS_HISTORY, VOTER_PARTICIPATION, VOTE_TIMELINE,
+};
 
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
@@ -35,13 +52,37 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        CanRevoteResponse, Cw20Amount, ExecutionHistoryResponse, ExecutionInfo, ExecutionOutcome,
+        Parameter, ProposalConfigResponse, ProposalResponse, ProposalSends,
+        ProposalSummariesResponse, ProposalSummary, ProposalsSendingToResponse,
+        ProposerStatsResponse, TierTally, TierVotes, TurnoutBreakdownResponse, VoteInfo,
+        VoteListResponse, VoteResponse, VoteStatusResponse, VoterListResponse, VotesByTierResponse,
+        VotingModuleCompatibilityResponse,
+    },
     state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The number of NFTs requested per query when counting the NFTs a
+/// proposer holds of a gating collection.
+const NFT_PAGE_LIMIT: u32 = 30;
+
+/// The maximum number of tiers that votes may be grouped into by a
+/// `VotesByTier` query.
+const MAX_VOTE_TIERS: usize = 10;
+
+/// The maximum number of proposals that may be closed by a single
+/// `CloseExpired` call.
+const MAX_LIMIT: u64 = 100;
+
+/// The maximum number of proposals examined by a single call or
+/// query that filters proposals by their status, so that its gas
+/// cost does not grow with the number of proposals in the module.
+const MAX_PROPOSALS_SCANNED: usize = 300;
+
 /// Message type used for firing hooks to this module's pre-propose
 /// module, if one is installed.
 type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
@@ -49,18 +90,39 @@ type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.threshold.validate()?;
+    validate_max_execution_attempts(msg.max_execution_attempts)?;
+    validate_max_msgs_per_execution(msg.max_msgs_per_execution)?;
+    validate_max_proposal_msgs(msg.max_proposal_msgs)?;
+    validate_abstain_quorum_weight(msg.abstain_quorum_weight)?;
+    if let Some(text_limits) = &msg.text_limits {
+        text_limits.validate()?;
+    }
 
     let dao = info.sender;
+    validate_dao(&env, &dao, msg.allow_self_dao)?;
+    validate_blackout(&msg.blackout)?;
+    validate_unstake_cooldown(deps.as_ref(), &dao, msg.unstake_cooldown)?;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
+    validate_proposer_execution_grace_period(
+        msg.proposer_execution_grace_period,
+        max_voting_period,
+    )?;
+    validate_confirmation_phase(&msg.confirmation_phase, max_voting_period)?;
+    validate_execution_delay(msg.execution_delay, max_voting_period)?;
+    let proposer_nft_gate = validate_proposer_nft_gate(deps.as_ref(), msg.proposer_nft_gate)?;
+    let fallback_voting_module = msg
+        .fallback_voting_module
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
 
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
@@ -74,6 +136,25 @@ pub fn instantiate(
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        max_execution_attempts: msg.max_execution_attempts,
+        max_msgs_per_execution: msg.max_msgs_per_execution,
+        audited_only: msg.audited_only,
+        max_proposal_msgs: msg.max_proposal_msgs,
+        text_limits: msg.text_limits,
+        min_proposer_power: msg.min_proposer_power,
+        tie_policy: msg.tie_policy.unwrap_or_default(),
+        allow_self_dao: msg.allow_self_dao,
+        blackout: msg.blackout,
+        best_effort_execution: msg.best_effort_execution,
+        unstake_cooldown: msg.unstake_cooldown,
+        proposer_must_execute: msg.proposer_must_execute,
+        proposer_execution_grace_period: msg.proposer_execution_grace_period,
+        proposer_nft_gate,
+        fallback_voting_module,
+        require_discussion_url: msg.require_discussion_url,
+        abstain_quorum_weight: msg.abstain_quorum_weight,
+        confirmation_phase: msg.confirmation_phase,
+        execution_delay: msg.execution_delay,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -101,7 +182,21 @@ pub fn execute(
             description,
             msgs,
             proposer,
-        }) => execute_propose(deps, env, info.sender, title, description, msgs, proposer),
+            precondition,
+            proposal_duration,
+            discussion_url,
+        }) => execute_propose(
+            deps,
+            env,
+            info.sender,
+            title,
+            description,
+            msgs,
+            proposer,
+            precondition,
+            proposal_duration,
+            discussion_url,
+        ),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
@@ -111,8 +206,14 @@ pub fn execute(
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
-        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id, false),
+        ExecuteMsg::ContinueExecution { proposal_id } => {
+            execute_execute(deps, env, info, proposal_id, true)
+        }
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::CloseExpired { start_after, limit } => {
+            execute_close_expired(deps, env, info, start_after, limit)
+        }
         ExecuteMsg::UpdateConfig {
             threshold,
             max_voting_period,
@@ -121,8 +222,28 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_execution_attempts,
+            max_msgs_per_execution,
+            audited_only,
+            max_proposal_msgs,
+            text_limits,
+            min_proposer_power,
+            tie_policy,
+            allow_self_dao,
+            blackout,
+            best_effort_execution,
+            unstake_cooldown,
+            proposer_must_execute,
+            proposer_execution_grace_period,
+            proposer_nft_gate,
+            fallback_voting_module,
+            require_discussion_url,
+            abstain_quorum_weight,
+            confirmation_phase,
+            execution_delay,
         } => execute_update_config(
             deps,
+            env,
             info,
             threshold,
             max_voting_period,
@@ -131,10 +252,38 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_execution_attempts,
+            max_msgs_per_execution,
+            audited_only,
+            max_proposal_msgs,
+            text_limits,
+            min_proposer_power,
+            tie_policy,
+            allow_self_dao,
+            blackout,
+            best_effort_execution,
+            unstake_cooldown,
+            proposer_must_execute,
+            proposer_execution_grace_period,
+            proposer_nft_gate,
+            fallback_voting_module,
+            require_discussion_url,
+            abstain_quorum_weight,
+            confirmation_phase,
+            execution_delay,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::UpdateLinkedModule { address } => {
+            execute_update_linked_module(deps, info, address)
+        }
+        ExecuteMsg::AddAuditedContract { address } => {
+            execute_add_audited_contract(deps, info, address)
+        }
+        ExecuteMsg::RemoveAuditedContract { address } => {
+            execute_remove_audited_contract(deps, info, address)
+        }
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
@@ -148,6 +297,7 @@ pub fn execute(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
     deps: DepsMut,
     env: Env,
@@ -156,6 +306,9 @@ pub fn execute_propose(
     description: String,
     msgs: Vec<CosmosMsg<Empty>>,
     proposer: Option<String>,
+    precondition: Option<Precondition>,
+    proposal_duration: Option<Duration>,
+    discussion_url: Option<String>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
@@ -164,6 +317,7 @@ pub fn execute_propose(
     if !proposal_creation_policy.is_permitted(&sender) {
         return Err(ContractError::Unauthorized {});
     }
+    check_blackout(&config, &env.block, false)?;
 
     // Determine the appropriate proposer. If this is coming from our
     // pre-propose module, it must be specified. Otherwise, the
@@ -194,9 +348,62 @@ pub fn execute_propose(
         return Err(ContractError::InactiveDao {});
     }
 
-    let expiration = config.max_voting_period.after(&env.block);
+    if let Some(min) = config.min_proposer_power {
+        // Voting power at the current height is the power held at
+        // the end of the previous block, so power acquired in the
+        // block the proposal is created in does not count.
+        let power = voting_power(deps.as_ref(), &config, &proposer, Some(env.block.height))?;
+        if power < min {
+            return Err(ContractError::InsufficientProposerPower { power, min });
+        }
+    }
+
+    if let Some(gate) = &config.proposer_nft_gate {
+        let count = count_nfts(deps.as_ref(), gate, &proposer)?;
+        if count < gate.min_count {
+            return Err(ContractError::InsufficientProposerNfts {
+                count,
+                min: gate.min_count,
+            });
+        }
+    }
+
+    check_unstake_cooldown(deps.as_ref(), &env, &config, &proposer)?;
 
-    let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
+    if let Some(text_limits) = &config.text_limits {
+        text_limits.check(&title, &description)?;
+    }
+
+    if config.require_discussion_url
+        && discussion_url
+            .as_ref()
+            .map_or(true, |url| url.trim().is_empty())
+    {
+        return Err(ContractError::MissingDiscussionUrl {});
+    }
+
+    if let Some(max) = config.max_proposal_msgs {
+        let count = msgs.len() as u64;
+        if count > max {
+            return Err(ContractError::TooManyProposalMsgs { count, max });
+        }
+    }
+
+    if config.audited_only {
+        check_audited(deps.as_ref(), &config.dao, &msgs)?;
+    }
+
+    let precondition = precondition
+        .map(|precondition| precondition.into_checked(deps.as_ref()))
+        .transpose()?;
+
+    let voting_period = match proposal_duration {
+        Some(duration) => check_proposal_duration(&config, duration)?,
+        None => config.max_voting_period,
+    };
+    let expiration = voting_period.after(&env.block);
+
+    let total_power = total_power(deps.as_ref(), &config, Some(env.block.height))?;
 
     let proposal = {
         // Limit mutability to this block.
@@ -213,6 +420,19 @@ pub fn execute_propose(
             status: Status::Open,
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
+            execution_attempts: 0,
+            execution_height: None,
+            executed_msgs: 0,
+            only_members_execute: Some(config.only_members_execute),
+            created_at: env.block.time,
+            precondition,
+            tie_policy: Some(config.tie_policy),
+            discussion_url,
+            abstain_quorum_weight: config.abstain_quorum_weight,
+            phase: config
+                .confirmation_phase
+                .map(|confirmation| ProposalPhase::Intent { confirmation }),
+            passed_at: None,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -244,15 +464,27 @@ pub fn execute_propose(
     }
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
+    PROPOSALS_BY_PROPOSER.save(deps.storage, (&proposer, id), &Empty {})?;
+    record_status_transition(deps.storage, id, Status::Open, env.block.height, "propose")?;
+    record_status_transition(
+        deps.storage,
+        id,
+        proposal.status,
+        env.block.height,
+        "propose",
+    )?;
+    PROPOSER_STATS.update(deps.storage, &proposer, |stats| -> StdResult<_> {
+        let mut stats = stats.unwrap_or_default();
+        stats.proposals += 1;
+        Ok(stats)
+    })?;
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
     Ok(Response::default()
         .add_submessages(hooks)
         .add_attribute("action", "propose")
-        .add_attribute("sender", sender)
-        .add_attribute("proposal_id", id.to_string())
-        .add_attribute("status", proposal.status.to_string()))
+        .add_attributes(proposal_attributes(id, proposal.status, &sender)))
 }
 
 pub fn execute_execute(
@@ -260,6 +492,7 @@ pub fn execute_execute(
     env: Env,
     info: MessageInfo,
     proposal_id: u64,
+    continuation: bool,
 ) -> Result<Response, ContractError> {
     let mut prop = PROPOSALS
         .may_load(deps.storage, proposal_id)?
@@ -267,16 +500,29 @@ pub fn execute_execute(
 
     let config = CONFIG.load(deps.storage)?;
     if config.only_members_execute {
-        let power = get_voting_power(
+        let power = voting_power(
             deps.as_ref(),
-            info.sender.clone(),
-            &config.dao,
+            &config,
+            &info.sender,
             Some(prop.start_height),
         )?;
         if power.is_zero() {
             return Err(ContractError::Unauthorized {});
         }
     }
+    if config.proposer_must_execute && info.sender != prop.proposer {
+        let grace_ends = match config.proposer_execution_grace_period {
+            // A grace period can not be added to the expiration of a
+            // proposal created before the voting period's units
+            // (height or time) changed. Its grace period ends when it
+            // expires.
+            Some(grace_period) => (prop.expiration + grace_period).unwrap_or(prop.expiration),
+            None => Expiration::Never {},
+        };
+        if !grace_ends.is_expired(&env.block) {
+            return Err(ContractError::ProposerMustExecute { grace_ends });
+        }
+    }
 
     // Check here that the proposal is passed. Allow it to be executed
     // even if it is expired so long as it passed during its voting
@@ -286,68 +532,144 @@ pub fn execute_execute(
     if prop.status != Status::Passed {
         return Err(ContractError::NotPassed {});
     }
+    if let (Some(delay), Some(passed_at)) = (config.execution_delay, prop.passed_at) {
+        // As with grace periods, a delay can not be added to when a
+        // proposal created before the voting period's units changed
+        // passed, in which case it may be executed once passed.
+        let executable_at = (passed_at + delay).unwrap_or(passed_at);
+        if !executable_at.is_expired(&env.block) {
+            return Err(ContractError::TimelockNotExpired { executable_at });
+        }
+    }
+    check_blackout(&config, &env.block, true)?;
+    record_status_transition(
+        deps.storage,
+        proposal_id,
+        prop.status,
+        env.block.height,
+        "execute",
+    )?;
+
+    // Chunked execution is started with `Execute` and continued
+    // with `ContinueExecution`.
+    match (continuation, prop.executed_msgs) {
+        (false, 0) => {
+            if !prop.is_precondition_met(deps.as_ref())? {
+                return Err(ContractError::PreconditionNotMet { id: proposal_id });
+            }
+        }
+        (false, _) => return Err(ContractError::ExecutionInProgress {}),
+        (true, 0) => return Err(ContractError::ExecutionNotStarted {}),
+        (true, _) => (),
+    }
 
-    prop.status = Status::Executed;
+    let chunk = prop.next_chunk(config.max_msgs_per_execution).to_vec();
+    // Messages are indexed as they are sent. Those that fail are
+    // removed from the index when the failure is replied to.
+    index_message_origins(deps.storage, &config.dao, proposal_id, &chunk)?;
+    let hashes_state = !state_hash_targets(&env.contract.address, &config.dao, &chunk).is_empty();
+    // Failed messages do not fail best-effort executions, so there
+    // are no failed attempts to track.
+    let tracks_attempts = config.max_execution_attempts.is_some() && !config.best_effort_execution;
+    // The chunk is only remembered while its failure may be replied
+    // to. Otherwise a failure reverts the execution entirely.
+    if !config.best_effort_execution
+        && (tracks_attempts || config.close_proposal_on_execution_failure)
+    {
+        let start = prop.executed_msgs;
+        EXECUTING_CHUNKS.save(
+            deps.storage,
+            proposal_id,
+            &(start, start + chunk.len() as u64),
+        )?;
+    }
+    let completes = prop.executed_msgs as usize + chunk.len() == prop.msgs.len();
+    if completes {
+        prop.status = Status::Executed;
+        prop.execution_height = Some(env.block.height);
+    }
+    // When execution attempts are tracked progress is recorded once
+    // the chunk is known to have succeeded.
+    if !tracks_attempts {
+        prop.executed_msgs += chunk.len() as u64;
+    }
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    record_status_transition(
+        deps.storage,
+        proposal_id,
+        prop.status,
+        env.block.height,
+        "execute",
+    )?;
 
     let response = {
-        if !prop.msgs.is_empty() {
+        if !chunk.is_empty() && config.best_effort_execution {
+            // Each message is executed on its own so that one failing
+            // does not revert the others. The outcome of each is
+            // recorded in the proposal's execution report.
+            let masked_proposal_id = mask_proposal_message_execution_id(proposal_id);
+            let execute_messages = chunk
+                .into_iter()
+                .map(|msg| -> StdResult<SubMsg> {
+                    let execute_message = WasmMsg::Execute {
+                        contract_addr: config.dao.to_string(),
+                        msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook {
+                            msgs: vec![msg],
+                        })?,
+                        funds: vec![],
+                    };
+                    Ok(SubMsg::reply_always(execute_message, masked_proposal_id))
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            Response::default().add_submessages(execute_messages)
+        } else if !chunk.is_empty() {
             let execute_message = WasmMsg::Execute {
                 contract_addr: config.dao.to_string(),
-                msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook {
-                    msgs: prop.msgs,
-                })?,
+                msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook { msgs: chunk })?,
                 funds: vec![],
             };
-            match config.close_proposal_on_execution_failure {
-                true => {
-                    let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                    Response::default()
-                        .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
-                }
-                false => Response::default().add_message(execute_message),
+            let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
+            if tracks_attempts {
+                // Whether or not this attempt succeeds is not known
+                // until the reply is received, so hooks are fired
+                // from there.
+                return Ok(Response::default()
+                    .add_submessage(SubMsg::reply_always(execute_message, masked_proposal_id))
+                    .add_attribute("action", "execute")
+                    .add_attributes(proposal_attributes(proposal_id, prop.status, &info.sender))
+                    .add_attribute("dao", config.dao));
+            }
+            // Executions whose failures are replied to are also
+            // replied to on success so that the executing chunk is
+            // forgotten. Proposals that change the config of a known
+            // contract are replied to on success so that the
+            // resulting config may be hashed.
+            let state_hash_id = mask_proposal_execution_state_hash_id(proposal_id);
+            match (config.close_proposal_on_execution_failure, hashes_state) {
+                (true, _) => Response::default()
+                    .add_submessage(SubMsg::reply_always(execute_message, state_hash_id)),
+                (false, true) => Response::default()
+                    .add_submessage(SubMsg::reply_on_success(execute_message, state_hash_id)),
+                (false, false) => Response::default().add_message(execute_message),
             }
         } else {
             Response::default()
         }
     };
 
-    let hooks = proposal_status_changed_hooks(
-        PROPOSAL_HOOKS,
-        deps.storage,
-        proposal_id,
-        old_status.to_string(),
-        prop.status.to_string(),
-    )?;
-
-    // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let hooks = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => hooks,
-        ProposalCreationPolicy::Module { addr } => {
-            let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
-                proposal_id,
-                new_status: prop.status,
-            })?;
-            let mut hooks = hooks;
-            hooks.push(SubMsg::reply_on_error(
-                WasmMsg::Execute {
-                    contract_addr: addr.into_string(),
-                    msg,
-                    funds: vec![],
-                },
-                failed_pre_propose_module_hook_id(),
-            ));
-            hooks
-        }
+    let hooks = if completes {
+        record_proposal_outcome(deps.storage, &prop, None)?;
+        proposal_completed_hooks(deps.storage, proposal_id, old_status, prop.status)?
+    } else {
+        vec![]
     };
 
     Ok(response
         .add_submessages(hooks)
         .add_attribute("action", "execute")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attributes(proposal_attributes(proposal_id, prop.status, &info.sender))
+        .add_attribute("executed_msgs", prop.executed_msgs.to_string())
         .add_attribute("dao", config.dao))
 }
 
@@ -371,21 +693,43 @@ pub fn execute_vote(
     // cause a different one. This then serves to allow
     // for better tallies of opinions in the event that a
     // proposal passes or is rejected early.
+    //
+    // Proposals whose intent phase has passed are moved into their
+    // confirmation phase first so that it may be voted on.
+    let old_status = prop.status;
+    prop.update_status(&env.block);
     if prop.expiration.is_expired(&env.block) {
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    check_unstake_cooldown(deps.as_ref(), &env, &config, &info.sender)?;
+
+    let vote_power = voting_power(
         deps.as_ref(),
-        info.sender.clone(),
-        &config.dao,
+        &config,
+        &info.sender,
         Some(prop.start_height),
     )?;
     if vote_power.is_zero() {
+        // Distinguish voters who gained voting power after the
+        // proposal was created from those who have none at all so
+        // that frontends may explain why the vote failed.
+        let current_power = voting_power(deps.as_ref(), &config, &info.sender, None)?;
+        if !current_power.is_zero() {
+            return Err(ContractError::NoVotingPowerAtSnapshot {
+                snapshot_height: prop.start_height,
+            });
+        }
         return Err(ContractError::NotRegistered {});
     }
 
-    BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
+    // Re-votes replace an existing ballot and so do not count towards
+    // the voter's participation, nor do votes cast in a proposal's
+    // confirmation phase by voters who voted in its intent phase.
+    let first_vote = !BALLOTS.has(deps.storage, (proposal_id, &info.sender))
+        && !CONFIRMATION_BALLOTS.has(deps.storage, (proposal_id, &info.sender));
+    let ballots = phase_ballots(prop.phase.as_ref());
+    ballots.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
                 if current_ballot.vote == vote {
@@ -416,13 +760,33 @@ pub fn execute_vote(
             rationale: rationale.clone(),
         }),
     })?;
+    if first_vote {
+        VOTER_PARTICIPATION.update(deps.storage, &info.sender, |count| -> StdResult<_> {
+            Ok(count.unwrap_or_default() + 1)
+        })?;
+    }
 
-    let old_status = prop.status;
-
+    let voted_status = prop.status;
     prop.votes.add_vote(vote, vote_power);
     prop.update_status(&env.block);
+    if voted_status == Status::Open && prop.status == Status::Passed {
+        // This vote passed the proposal.
+        prop.passed_at = Some(prop.at_block(&env.block));
+    }
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    VOTE_TIMELINE.save(
+        deps.storage,
+        (proposal_id, env.block.time.seconds()),
+        &prop.votes.total(),
+    )?;
+    record_status_transition(
+        deps.storage,
+        proposal_id,
+        prop.status,
+        env.block.height,
+        "vote",
+    )?;
 
     let new_status = prop.status;
     let change_hooks = proposal_status_changed_hooks(
@@ -445,11 +809,13 @@ pub fn execute_vote(
         .add_submessages(change_hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "vote")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attributes(proposal_attributes(proposal_id, prop.status, &info.sender))
+        // `position` predates `vote` and is kept for existing
+        // consumers.
         .add_attribute("position", vote.to_string())
-        .add_attribute("rationale", rationale.as_deref().unwrap_or("_none"))
-        .add_attribute("status", prop.status.to_string()))
+        .add_attribute("vote", vote.to_string())
+        .add_attribute("power", vote_power.to_string())
+        .add_attribute("rationale", rationale.as_deref().unwrap_or("_none")))
 }
 
 pub fn execute_update_rationale(
@@ -458,7 +824,10 @@ pub fn execute_update_rationale(
     proposal_id: u64,
     rationale: Option<String>,
 ) -> Result<Response, ContractError> {
-    BALLOTS.update(
+    let phase = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .and_then(|prop| prop.phase);
+    phase_ballots(phase.as_ref()).update(
         deps.storage,
         // info.sender can't be forged so we implicitly access control
         // with the key.
@@ -493,55 +862,101 @@ pub fn execute_close(
     // Update status to ensure that proposals which were open and have
     // expired are moved to "rejected."
     prop.update_status(&env.block);
-    if prop.status != Status::Rejected {
+    if !is_closeable(deps.as_ref(), &prop)? {
         return Err(ContractError::WrongCloseStatus {});
     }
 
-    let old_status = prop.status;
-
-    prop.status = Status::Closed;
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    let hooks = close_proposal(deps.storage, &env, proposal_id, &mut prop)?;
 
-    let hooks = proposal_status_changed_hooks(
-        PROPOSAL_HOOKS,
-        deps.storage,
-        proposal_id,
-        old_status.to_string(),
-        prop.status.to_string(),
-    )?;
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "close")
+        .add_attributes(proposal_attributes(proposal_id, prop.status, &info.sender)))
+}
 
-    // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let hooks = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => hooks,
-        ProposalCreationPolicy::Module { addr } => {
-            let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
-                proposal_id,
-                new_status: prop.status,
-            })?;
-            let mut hooks = hooks;
-            hooks.push(SubMsg::reply_on_error(
-                WasmMsg::Execute {
-                    contract_addr: addr.into_string(),
-                    msg,
-                    funds: vec![],
-                },
-                failed_pre_propose_module_hook_id(),
-            ));
-            hooks
+pub fn execute_close_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.map_or(DEFAULT_LIMIT, u64::from).min(MAX_LIMIT) as usize;
+    let mut rejected: Vec<(u64, SingleChoiceProposal)> = vec![];
+    let mut last_scanned = None;
+    for item in PROPOSALS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(MAX_PROPOSALS_SCANNED)
+    {
+        let (id, mut prop) = item?;
+        last_scanned = Some(id);
+        prop.update_status(&env.block);
+        if prop.status == Status::Rejected {
+            rejected.push((id, prop));
+            if rejected.len() == limit {
+                break;
+            }
         }
-    };
+    }
+
+    let mut hooks = vec![];
+    let mut closed = Vec::with_capacity(rejected.len());
+    for (id, mut prop) in rejected {
+        hooks.extend(close_proposal(deps.storage, &env, id, &mut prop)?);
+        closed.push(id.to_string());
+    }
 
     Ok(Response::default()
         .add_submessages(hooks)
-        .add_attribute("action", "close")
+        .add_attribute("action", "close_expired")
         .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_attribute("proposal_ids", closed.join(","))
+        .add_attribute(
+            "last_scanned",
+            last_scanned.map_or_else(|| "_none".to_string(), |id| id.to_string()),
+        ))
+}
+
+/// Closes PROP, whose status is up to date and which may be closed,
+/// and returns the hooks to fire for its completion.
+fn close_proposal(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: u64,
+    prop: &mut SingleChoiceProposal,
+) -> StdResult<Vec<SubMsg>> {
+    let old_status = prop.status;
+    record_status_transition(storage, proposal_id, old_status, env.block.height, "close")?;
+
+    prop.status = Status::Closed;
+    PROPOSALS.save(storage, proposal_id, prop)?;
+    record_status_transition(storage, proposal_id, prop.status, env.block.height, "close")?;
+    record_proposal_outcome(storage, prop, None)?;
+
+    proposal_completed_hooks(storage, proposal_id, old_status, prop.status)
+}
+
+/// Returns true if PROP, whose status is up to date, may be closed.
+fn is_closeable(deps: Deps, prop: &SingleChoiceProposal) -> StdResult<bool> {
+    Ok(match prop.status {
+        Status::Rejected => true,
+        // Passed proposals whose precondition does not hold may not
+        // be executed, so allow them to be closed so long as their
+        // execution has not started.
+        Status::Passed => prop.executed_msgs == 0 && !prop.is_precondition_met(deps)?,
+        _ => false,
+    })
 }
 
 #[allow(clippy::too_many_arguments)]
 pub fn execute_update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     threshold: Threshold,
     max_voting_period: Duration,
@@ -550,6 +965,25 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    max_execution_attempts: Option<u64>,
+    max_msgs_per_execution: Option<u64>,
+    audited_only: bool,
+    max_proposal_msgs: Option<u64>,
+    text_limits: Option<ProposalTextLimits>,
+    min_proposer_power: Option<Uint128>,
+    tie_policy: Option<SingleChoiceTiePolicy>,
+    allow_self_dao: bool,
+    blackout: Option<Blackout>,
+    best_effort_execution: bool,
+    unstake_cooldown: Option<Duration>,
+    proposer_must_execute: bool,
+    proposer_execution_grace_period: Option<Duration>,
+    proposer_nft_gate: Option<UncheckedProposerNftGate>,
+    fallback_voting_module: Option<String>,
+    require_discussion_url: bool,
+    abstain_quorum_weight: Option<PercentageThreshold>,
+    confirmation_phase: Option<ConfirmationPhase>,
+    execution_delay: Option<Duration>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -558,10 +992,27 @@ pub fn execute_update_config(
         return Err(ContractError::Unauthorized {});
     }
     threshold.validate()?;
+    validate_max_execution_attempts(max_execution_attempts)?;
+    validate_max_msgs_per_execution(max_msgs_per_execution)?;
+    validate_max_proposal_msgs(max_proposal_msgs)?;
+    validate_abstain_quorum_weight(abstain_quorum_weight)?;
+    if let Some(text_limits) = &text_limits {
+        text_limits.validate()?;
+    }
     let dao = deps.api.addr_validate(&dao)?;
+    validate_dao(&env, &dao, allow_self_dao)?;
+    validate_blackout(&blackout)?;
+    validate_unstake_cooldown(deps.as_ref(), &dao, unstake_cooldown)?;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    validate_proposer_execution_grace_period(proposer_execution_grace_period, max_voting_period)?;
+    validate_confirmation_phase(&confirmation_phase, max_voting_period)?;
+    validate_execution_delay(execution_delay, max_voting_period)?;
+    let proposer_nft_gate = validate_proposer_nft_gate(deps.as_ref(), proposer_nft_gate)?;
+    let fallback_voting_module = fallback_voting_module
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
 
     CONFIG.save(
         deps.storage,
@@ -573,6 +1024,25 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            max_execution_attempts,
+            max_msgs_per_execution,
+            audited_only,
+            max_proposal_msgs,
+            text_limits,
+            min_proposer_power,
+            tie_policy: tie_policy.unwrap_or_default(),
+            allow_self_dao,
+            blackout,
+            best_effort_execution,
+            unstake_cooldown,
+            proposer_must_execute,
+            proposer_execution_grace_period,
+            proposer_nft_gate,
+            fallback_voting_module,
+            require_discussion_url,
+            abstain_quorum_weight,
+            confirmation_phase,
+            execution_delay,
         },
     )?;
 
@@ -581,67 +1051,635 @@ pub fn execute_update_config(
         .add_attribute("sender", info.sender))
 }
 
-pub fn execute_update_proposal_creation_policy(
-    deps: DepsMut,
-    info: MessageInfo,
-    new_info: PreProposeInfo,
-) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if config.dao != info.sender {
-        return Err(ContractError::Unauthorized {});
+/// Errors if DAO is this module's own address and ALLOW_SELF_DAO is
+/// not set. A module that is its own DAO governs itself, which is
+/// almost always a misconfiguration.
+fn validate_dao(env: &Env, dao: &Addr, allow_self_dao: bool) -> Result<(), ContractError> {
+    if !allow_self_dao && *dao == env.contract.address {
+        return Err(ContractError::SelfDao {});
     }
+    Ok(())
+}
 
-    let (initial_policy, messages) = new_info.into_initial_policy_and_messages(config.dao)?;
-    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+/// Errors if BLACKOUT's window is empty.
+fn validate_blackout(blackout: &Option<Blackout>) -> Result<(), ContractError> {
+    match blackout {
+        Some(blackout) if blackout.start_height >= blackout.end_height => {
+            Err(ContractError::InvalidBlackout {})
+        }
+        _ => Ok(()),
+    }
+}
 
-    Ok(Response::default()
-        .add_submessages(messages)
-        .add_attribute("action", "update_proposal_creation_policy")
-        .add_attribute("sender", info.sender)
-        .add_attribute("new_policy", format!("{initial_policy:?}")))
+/// Errors if BLOCK falls within the config's blackout window. If
+/// EXECUTING, only errors if the blackout also blocks execution.
+fn check_blackout(
+    config: &Config,
+    block: &BlockInfo,
+    executing: bool,
+) -> Result<(), ContractError> {
+    match &config.blackout {
+        Some(blackout)
+            if blackout.contains(block.height) && (!executing || blackout.block_execution) =>
+        {
+            Err(ContractError::InBlackout {
+                start_height: blackout.start_height,
+                end_height: blackout.end_height,
+            })
+        }
+        _ => Ok(()),
+    }
 }
 
-pub fn add_hook(
-    hooks: Hooks,
-    storage: &mut dyn Storage,
-    validated_address: Addr,
+/// Gets when ADDRESS last unstaked from the staking contract of
+/// DAO's voting module. Errors if the voting module or its staking
+/// contract do not support the `StakingQuery` queries.
+fn query_last_unstake(
+    deps: Deps,
+    dao: &Addr,
+    address: &Addr,
+) -> StdResult<Option<LastUnstakeResponse>> {
+    let voting_module: Addr = deps
+        .querier
+        .query_wasm_smart(dao, &dao_core::msg::QueryMsg::VotingModule {})?;
+    let staking_contract: Addr = deps
+        .querier
+        .query_wasm_smart(voting_module, &StakingQuery::StakingContract {})?;
+    deps.querier.query_wasm_smart(
+        staking_contract,
+        &StakingQuery::LastUnstake {
+            address: address.to_string(),
+        },
+    )
+}
+
+/// Errors if an unstake cooldown is configured and DAO's voting
+/// module does not support the queries it is read with.
+fn validate_unstake_cooldown(
+    deps: Deps,
+    dao: &Addr,
+    unstake_cooldown: Option<Duration>,
 ) -> Result<(), ContractError> {
-    hooks
-        .add_hook(storage, validated_address)
-        .map_err(ContractError::HookError)?;
+    if unstake_cooldown.is_some() && query_last_unstake(deps, dao, dao).is_err() {
+        return Err(ContractError::UnstakeCooldownUnsupported {});
+    }
     Ok(())
 }
 
-pub fn remove_hook(
-    hooks: Hooks,
-    storage: &mut dyn Storage,
-    validate_address: Addr,
+/// Errors if ADDRESS unstaked less than the configured unstake
+/// cooldown ago. If the DAO's voting module no longer supports the
+/// queries the cooldown is read with, for example because it has
+/// been replaced, the cooldown is not enforced.
+fn check_unstake_cooldown(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    address: &Addr,
 ) -> Result<(), ContractError> {
-    hooks
-        .remove_hook(storage, validate_address)
-        .map_err(ContractError::HookError)?;
+    let cooldown = match config.unstake_cooldown {
+        Some(cooldown) => cooldown,
+        None => return Ok(()),
+    };
+    if let Ok(Some(last_unstake)) = query_last_unstake(deps, &config.dao, address) {
+        let ends = match cooldown {
+            Duration::Height(blocks) => Expiration::AtHeight(last_unstake.height + blocks),
+            Duration::Time(seconds) => Expiration::AtTime(last_unstake.time.plus_seconds(seconds)),
+        };
+        if !ends.is_expired(&env.block) {
+            return Err(ContractError::UnstakeCooldown { ends });
+        }
+    }
     Ok(())
 }
 
-pub fn execute_add_proposal_hook(
-    deps: DepsMut,
-    _env: Env,
-    info: MessageInfo,
-    address: String,
-) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if config.dao != info.sender {
-        // Only DAO can add hooks
-        return Err(ContractError::Unauthorized {});
+/// Errors if GRACE_PERIOD and MAX_VOTING_PERIOD have different
+/// units, as the grace period is added to proposal expirations.
+fn validate_proposer_execution_grace_period(
+    grace_period: Option<Duration>,
+    max_voting_period: Duration,
+) -> Result<(), ContractError> {
+    match (grace_period, max_voting_period) {
+        (None, _) | (Some(Duration::Height(_)), Duration::Height(_)) => Ok(()),
+        (Some(Duration::Time(_)), Duration::Time(_)) => Ok(()),
+        _ => Err(ContractError::GracePeriodUnitsConflict {}),
     }
+}
 
-    let validated_address = deps.api.addr_validate(&address)?;
+/// The ballots cast during a proposal's current voting PHASE.
+fn phase_ballots<'a>(phase: Option<&ProposalPhase>) -> Map<'static, (u64, &'a Addr), Ballot> {
+    match phase {
+        Some(ProposalPhase::Confirmation { .. }) => CONFIRMATION_BALLOTS,
+        _ => BALLOTS,
+    }
+}
 
-    add_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+/// Errors if CONFIRMATION's threshold is invalid or its voting period
+/// has different units than MAX_VOTING_PERIOD, as the confirmation
+/// phase begins when a proposal's voting period ends.
+fn validate_confirmation_phase(
+    confirmation: &Option<ConfirmationPhase>,
+    max_voting_period: Duration,
+) -> Result<(), ContractError> {
+    let Some(confirmation) = confirmation else {
+        return Ok(());
+    };
+    confirmation.threshold.validate()?;
+    match (confirmation.voting_period, max_voting_period) {
+        (Duration::Height(_), Duration::Height(_)) | (Duration::Time(_), Duration::Time(_)) => {
+            Ok(())
+        }
+        _ => Err(ContractError::ConfirmationPhaseUnitsConflict {}),
+    }
+}
 
-    Ok(Response::default()
-        .add_attribute("action", "add_proposal_hook")
-        .add_attribute("address", address))
+/// Errors if DELAY and MAX_VOTING_PERIOD have different units, as
+/// the delay is added to when proposals pass.
+fn validate_execution_delay(
+    delay: Option<Duration>,
+    max_voting_period: Duration,
+) -> Result<(), ContractError> {
+    match (delay, max_voting_period) {
+        (None, _) | (Some(Duration::Height(_)), Duration::Height(_)) => Ok(()),
+        (Some(Duration::Time(_)), Duration::Time(_)) => Ok(()),
+        _ => Err(ContractError::ExecutionDelayUnitsConflict {}),
+    }
+}
+
+/// Errors if DURATION, a proposer's override of the voting period, is
+/// longer than or has different units than the max voting period.
+fn check_proposal_duration(config: &Config, duration: Duration) -> Result<Duration, ContractError> {
+    let valid = match (duration, config.max_voting_period) {
+        (Duration::Height(duration), Duration::Height(max)) => duration <= max,
+        (Duration::Time(duration), Duration::Time(max)) => duration <= max,
+        _ => return Err(ContractError::ProposalDurationUnitsConflict {}),
+    };
+    if valid {
+        Ok(duration)
+    } else {
+        Err(ContractError::ProposalDurationTooLong {})
+    }
+}
+
+/// Validates GATE's collection address and errors if it requires
+/// zero NFTs.
+fn validate_proposer_nft_gate(
+    deps: Deps,
+    gate: Option<UncheckedProposerNftGate>,
+) -> Result<Option<ProposerNftGate>, ContractError> {
+    gate.map(|gate| {
+        if gate.min_count == 0 {
+            return Err(ContractError::ZeroNftGateCount {});
+        }
+        Ok(ProposerNftGate {
+            collection: deps.api.addr_validate(&gate.collection)?,
+            min_count: gate.min_count,
+        })
+    })
+    .transpose()
+}
+
+/// Counts the NFTs of GATE's collection that OWNER holds, stopping
+/// once the gate's minimum has been reached.
+fn count_nfts(deps: Deps, gate: &ProposerNftGate, owner: &Addr) -> StdResult<u64> {
+    let mut count = 0;
+    let mut start_after = None;
+    while count < gate.min_count {
+        let page: cw721::TokensResponse = deps.querier.query_wasm_smart(
+            &gate.collection,
+            &cw721::Cw721QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after,
+                limit: Some(NFT_PAGE_LIMIT),
+            },
+        )?;
+        count += page.tokens.len() as u64;
+        start_after = match page.tokens.into_iter().last() {
+            Some(token_id) => Some(token_id),
+            None => break,
+        };
+    }
+    Ok(count)
+}
+
+/// Gets the voting power of ADDRESS at HEIGHT from the DAO, or from
+/// the module's fallback voting module if querying the DAO fails.
+fn voting_power(
+    deps: Deps,
+    config: &Config,
+    address: &Addr,
+    height: Option<u64>,
+) -> StdResult<Uint128> {
+    get_voting_power(deps, address.clone(), &config.dao, height).or_else(|err| {
+        match &config.fallback_voting_module {
+            Some(fallback) => voting_power_at_height(&deps.querier, fallback, address, height),
+            None => Err(err),
+        }
+    })
+}
+
+/// Gets the total voting power at HEIGHT from the DAO, or from the
+/// module's fallback voting module if querying the DAO fails.
+fn total_power(deps: Deps, config: &Config, height: Option<u64>) -> StdResult<Uint128> {
+    get_total_power(deps, &config.dao, height).or_else(|err| match &config.fallback_voting_module {
+        Some(fallback) => total_power_at_height(&deps.querier, fallback, height),
+        None => Err(err),
+    })
+}
+
+fn validate_max_execution_attempts(
+    max_execution_attempts: Option<u64>,
+) -> Result<(), ContractError> {
+    if max_execution_attempts == Some(0) {
+        return Err(ContractError::ZeroMaxExecutionAttempts {});
+    }
+    Ok(())
+}
+
+fn validate_max_msgs_per_execution(
+    max_msgs_per_execution: Option<u64>,
+) -> Result<(), ContractError> {
+    if max_msgs_per_execution == Some(0) {
+        return Err(ContractError::ZeroMaxMsgsPerExecution {});
+    }
+    Ok(())
+}
+
+fn validate_max_proposal_msgs(max_proposal_msgs: Option<u64>) -> Result<(), ContractError> {
+    if max_proposal_msgs == Some(0) {
+        return Err(ContractError::ZeroMaxProposalMsgs {});
+    }
+    Ok(())
+}
+
+/// Errors unless WEIGHT is a percentage no greater than 100%. A
+/// weight of zero is allowed, in which case abstentions do not count
+/// toward quorum at all.
+fn validate_abstain_quorum_weight(
+    weight: Option<PercentageThreshold>,
+) -> Result<(), ContractError> {
+    match weight {
+        Some(PercentageThreshold::Percent(percent)) if percent > Decimal::one() => {
+            Err(ContractError::InvalidAbstainQuorumWeight {})
+        }
+        Some(PercentageThreshold::Majority {}) => Err(ContractError::InvalidAbstainQuorumWeight {}),
+        _ => Ok(()),
+    }
+}
+
+/// Errors if any of MSGS target a contract other than the DAO that is
+/// not in the audited contracts allowlist.
+fn check_audited(deps: Deps, dao: &Addr, msgs: &[CosmosMsg<Empty>]) -> Result<(), ContractError> {
+    for msg in msgs {
+        let target = match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. })
+            | CosmosMsg::Wasm(WasmMsg::Migrate { contract_addr, .. })
+            | CosmosMsg::Wasm(WasmMsg::UpdateAdmin { contract_addr, .. })
+            | CosmosMsg::Wasm(WasmMsg::ClearAdmin { contract_addr }) => contract_addr,
+            _ => continue,
+        };
+        let audited = deps
+            .api
+            .addr_validate(target)
+            .map(|addr| addr == dao || AUDITED_CONTRACTS.has(deps.storage, &addr))
+            .unwrap_or(false);
+        if !audited {
+            return Err(ContractError::UnauditedContract {
+                address: target.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the contracts whose config is updated by MSGS and whose
+/// config this module knows how to query: the DAO and this module.
+fn state_hash_targets(module: &Addr, dao: &Addr, msgs: &[CosmosMsg<Empty>]) -> Vec<Addr> {
+    let mut targets = vec![];
+    for msg in msgs {
+        let CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) = msg
+        else {
+            continue;
+        };
+        let updates_config = if contract_addr == dao {
+            matches!(
+                from_binary(msg),
+                Ok(dao_core::msg::ExecuteMsg::UpdateConfig { .. })
+            )
+        } else if contract_addr == module {
+            matches!(from_binary(msg), Ok(ExecuteMsg::UpdateConfig { .. }))
+        } else {
+            false
+        };
+        if updates_config && !targets.iter().any(|t: &Addr| t == contract_addr) {
+            targets.push(Addr::unchecked(contract_addr));
+        }
+    }
+    targets
+}
+
+/// Returns the key of MSG in the message origin index: the address
+/// MSG is sent to and the hex encoded SHA-256 hash of MSG's JSON
+/// encoding. Messages that are not sent to a contract or account are
+/// keyed under DAO.
+fn message_origin_key(dao: &Addr, msg: &CosmosMsg<Empty>) -> StdResult<(String, String)> {
+    let target = match msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. })
+        | CosmosMsg::Wasm(WasmMsg::Migrate { contract_addr, .. })
+        | CosmosMsg::Wasm(WasmMsg::UpdateAdmin { contract_addr, .. })
+        | CosmosMsg::Wasm(WasmMsg::ClearAdmin { contract_addr }) => contract_addr.clone(),
+        CosmosMsg::Bank(BankMsg::Send { to_address, .. }) => to_address.clone(),
+        _ => dao.to_string(),
+    };
+    let hash = Sha256::digest(to_vec(msg)?)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    Ok((target, hash))
+}
+
+/// Records that MSGS were executed by the proposal with ID
+/// PROPOSAL_ID.
+fn index_message_origins(
+    storage: &mut dyn Storage,
+    dao: &Addr,
+    proposal_id: u64,
+    msgs: &[CosmosMsg<Empty>],
+) -> StdResult<()> {
+    for msg in msgs {
+        let (target, hash) = message_origin_key(dao, msg)?;
+        MESSAGE_ORIGINS.save(
+            storage,
+            (target.as_str(), hash.as_str(), proposal_id),
+            &Empty {},
+        )?;
+    }
+    Ok(())
+}
+
+/// Removes MSGS, messages of the proposal with ID PROPOSAL_ID that
+/// failed to execute, from the message origin index.
+fn unindex_message_origins(
+    storage: &mut dyn Storage,
+    dao: &Addr,
+    proposal_id: u64,
+    msgs: &[CosmosMsg<Empty>],
+) -> StdResult<()> {
+    for msg in msgs {
+        let (target, hash) = message_origin_key(dao, msg)?;
+        MESSAGE_ORIGINS.remove(storage, (target.as_str(), hash.as_str(), proposal_id));
+    }
+    Ok(())
+}
+
+/// Returns a `state_hash` attribute for each contract whose config
+/// has been updated by PROP's executed messages. The value of the
+/// attribute is `{address}:{hash}` where hash is the hex encoded
+/// SHA-256 hash of the contract's `Config {}` query response.
+fn state_hash_attributes(
+    deps: Deps,
+    env: &Env,
+    prop: &SingleChoiceProposal,
+) -> StdResult<Vec<Attribute>> {
+    let config = CONFIG.load(deps.storage)?;
+    let executed = &prop.msgs[..prop.executed_msgs as usize];
+    state_hash_targets(&env.contract.address, &config.dao, executed)
+        .into_iter()
+        .map(|target| {
+            let state = if target == env.contract.address {
+                to_vec(&config)?
+            } else {
+                to_vec(&deps.querier.query_wasm_smart::<dao_core::state::Config>(
+                    &target,
+                    &dao_core::msg::QueryMsg::Config {},
+                )?)?
+            };
+            let hash: String = Sha256::digest(state)
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+            Ok(Attribute::new("state_hash", format!("{target}:{hash}")))
+        })
+        .collect()
+}
+
+/// Appends a transition to STATUS in proposal ID's status history
+/// unless it is already the proposal's most recently recorded
+/// status.
+fn record_status_transition(
+    storage: &mut dyn Storage,
+    id: u64,
+    status: Status,
+    height: u64,
+    cause: &str,
+) -> StdResult<()> {
+    let mut history = STATUS_HISTORY.may_load(storage, id)?.unwrap_or_default();
+    if history.last().map(|last| last.status) == Some(status) {
+        return Ok(());
+    }
+    if history.len() == MAX_STATUS_HISTORY {
+        history.remove(0);
+    }
+    history.push(StatusTransition {
+        status,
+        height,
+        cause: cause.to_string(),
+    });
+    STATUS_HISTORY.save(storage, id, &history)
+}
+
+/// Records the final status of PROP in its proposer's stats. If
+/// REPLACES is set, PROP was previously recorded as completing with
+/// that status.
+fn record_proposal_outcome(
+    storage: &mut dyn Storage,
+    prop: &SingleChoiceProposal,
+    replaces: Option<Status>,
+) -> StdResult<()> {
+    PROPOSER_STATS.update(storage, &prop.proposer, |stats| -> StdResult<_> {
+        let mut stats = stats.unwrap_or_default();
+        stats.record_completion(prop.status, prop.turnout(), replaces)?;
+        Ok(stats)
+    })?;
+    Ok(())
+}
+
+/// Builds the hooks fired when a proposal completes. This includes
+/// proposal status changed hooks and, if a pre-propose module is
+/// installed, its proposal completed hook which handles deposit
+/// refunds.
+fn proposal_completed_hooks(
+    storage: &dyn Storage,
+    proposal_id: u64,
+    old_status: Status,
+    new_status: Status,
+) -> StdResult<Vec<SubMsg>> {
+    let hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        storage,
+        proposal_id,
+        old_status.to_string(),
+        new_status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(storage)?;
+    let hooks = match proposal_creation_policy {
+        ProposalCreationPolicy::Anyone {} => hooks,
+        ProposalCreationPolicy::Module { addr } => {
+            // Pre-propose modules only handle closed and executed
+            // proposals. Proposals whose execution failed had passed,
+            // so their deposits are handled as if they had executed.
+            let new_status = match new_status {
+                Status::ExecutionFailed => Status::Executed,
+                new_status => new_status,
+            };
+            let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
+                proposal_id,
+                new_status,
+            })?;
+            let mut hooks = hooks;
+            hooks.push(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: addr.into_string(),
+                    msg,
+                    funds: vec![],
+                },
+                failed_pre_propose_module_hook_id(),
+            ));
+            hooks
+        }
+    };
+
+    Ok(hooks)
+}
+
+pub fn execute_update_proposal_creation_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_info: PreProposeInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (initial_policy, messages) = new_info.into_initial_policy_and_messages(config.dao)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("action", "update_proposal_creation_policy")
+        .add_attribute("sender", info.sender)
+        .add_attribute("new_policy", format!("{initial_policy:?}")))
+}
+
+pub fn execute_update_linked_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let linked_module = match address {
+        Some(address) => {
+            let addr = deps.api.addr_validate(&address)?;
+            LINKED_MODULE.save(deps.storage, &addr)?;
+            addr.into_string()
+        }
+        None => {
+            LINKED_MODULE.remove(deps.storage);
+            "none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_linked_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute("linked_module", linked_module))
+}
+
+pub fn execute_add_audited_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    AUDITED_CONTRACTS.save(deps.storage, &addr, &Empty {})?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_audited_contract")
+        .add_attribute("address", addr))
+}
+
+pub fn execute_remove_audited_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    AUDITED_CONTRACTS.remove(deps.storage, &addr);
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_audited_contract")
+        .add_attribute("address", addr))
+}
+
+pub fn add_hook(
+    hooks: Hooks,
+    storage: &mut dyn Storage,
+    validated_address: Addr,
+) -> Result<(), ContractError> {
+    hooks
+        .add_hook(storage, validated_address)
+        .map_err(ContractError::HookError)?;
+    Ok(())
+}
+
+pub fn remove_hook(
+    hooks: Hooks,
+    storage: &mut dyn Storage,
+    validate_address: Addr,
+) -> Result<(), ContractError> {
+    hooks
+        .remove_hook(storage, validate_address)
+        .map_err(ContractError::HookError)?;
+    Ok(())
+}
+
+pub fn execute_add_proposal_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can add hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    add_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_proposal_hook")
+        .add_attribute("address", address))
 }
 
 pub fn execute_remove_proposal_hook(
@@ -702,78 +1740,654 @@ pub fn execute_remove_vote_hook(
 
     remove_hook(VOTE_HOOKS, deps.storage, validated_address)?;
 
-    Ok(Response::default()
-        .add_attribute("action", "remove_vote_hook")
-        .add_attribute("address", address))
+    Ok(Response::default()
+        .add_attribute("action", "remove_vote_hook")
+        .add_attribute("address", address))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => query_config(deps),
+        QueryMsg::NormalizedThreshold {} => query_normalized_threshold(deps),
+        QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::Proposal { proposal_id } => query_proposal(deps, env, proposal_id),
+        QueryMsg::ProposalConfig { proposal_id } => query_proposal_config(deps, proposal_id),
+        QueryMsg::TurnoutBreakdown { proposal_id } => query_turnout_breakdown(deps, proposal_id),
+        QueryMsg::QuorumGap { proposal_id } => query_quorum_gap(deps, proposal_id),
+        QueryMsg::WinMarginPercent { proposal_id } => query_win_margin_percent(deps, proposal_id),
+        QueryMsg::TimeWeightedTurnout { proposal_id } => {
+            query_time_weighted_turnout(deps, env, proposal_id)
+        }
+        QueryMsg::StatusHistory { proposal_id } => query_status_history(deps, proposal_id),
+        QueryMsg::ExecutionReport { proposal_id } => query_execution_report(deps, proposal_id),
+        QueryMsg::MessageOrigin { contract, msg_hash } => {
+            query_message_origin(deps, contract, msg_hash)
+        }
+        QueryMsg::MinProposerPower {} => query_min_proposer_power(deps),
+        QueryMsg::IsVotingOpen { proposal_id } => query_is_voting_open(deps, env, proposal_id),
+        QueryMsg::ListProposals { start_after, limit } => {
+            query_list_proposals(deps, env, start_after, limit)
+        }
+        QueryMsg::ProposalSummaries { start_after, limit } => {
+            query_proposal_summaries(deps, env, start_after, limit)
+        }
+        QueryMsg::ProposalsInRange {
+            start_time,
+            end_time,
+            start_after,
+            limit,
+        } => query_proposals_in_range(deps, env, start_time, end_time, start_after, limit),
+        QueryMsg::CloseableProposals { start_after, limit } => {
+            query_closeable_proposals(deps, env, start_after, limit)
+        }
+        QueryMsg::ExpiringProposals {
+            within,
+            start_after,
+            limit,
+        } => query_expiring_proposals(deps, env, within, start_after, limit),
+        QueryMsg::ProposalsSendingTo {
+            recipient,
+            start_after,
+            limit,
+        } => query_proposals_sending_to(deps, recipient, start_after, limit),
+        QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
+        QueryMsg::ProposalCount {} => query_proposal_count(deps),
+        QueryMsg::ExecutionHistory { start_after, limit } => {
+            query_execution_history(deps, start_after, limit)
+        }
+        QueryMsg::ProposalsByExecutionOutcome {
+            outcome,
+            start_after,
+            limit,
+        } => query_proposals_by_execution_outcome(deps, env, outcome, start_after, limit),
+        QueryMsg::CanRevote { proposal_id, voter } => {
+            query_can_revote(deps, env, proposal_id, voter)
+        }
+        QueryMsg::VoteStatus {
+            proposal_id,
+            address,
+        } => query_vote_status(deps, env, proposal_id, address),
+        QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
+        QueryMsg::ListVotes {
+            proposal_id,
+            start_after,
+            limit,
+            reverse,
+        } => query_list_votes(deps, proposal_id, start_after, limit, reverse),
+        QueryMsg::Voters {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_voters(deps, proposal_id, start_after, limit),
+        QueryMsg::VotesByTier { proposal_id, tiers } => {
+            query_votes_by_tier(deps, proposal_id, tiers)
+        }
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::Participation { start_after, limit } => {
+            to_binary(&participation(deps, &env, start_after, limit)?)
+        }
+        QueryMsg::ProposerStats { proposer } => query_proposer_stats(deps, proposer),
+        QueryMsg::VoterParticipationCount { voter } => query_voter_participation_count(deps, voter),
+        QueryMsg::ProposalsByProposer {
+            proposer,
+            start_after,
+            limit,
+        } => query_proposals_by_proposer(deps, env, proposer, start_after, limit),
+        QueryMsg::AuditedContracts { start_after, limit } => {
+            query_audited_contracts(deps, start_after, limit)
+        }
+        QueryMsg::LinkedModule {} => to_binary(&LINKED_MODULE.may_load(deps.storage)?),
+        QueryMsg::CombinedParticipation { start_after, limit } => {
+            query_combined_participation(deps, env, start_after, limit)
+        }
+        QueryMsg::VotingModuleCompatible { voting_module } => {
+            query_voting_module_compatible(deps, env, voting_module)
+        }
+        QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
+        QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::Parameters {} => query_parameters(deps),
+    }
+}
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_binary(&config)
+}
+
+pub fn query_normalized_threshold(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_binary(&config.threshold.normalize())
+}
+
+pub fn query_min_proposer_power(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_binary(&config.min_proposer_power)
+}
+
+pub fn query_dao(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_binary(&config.dao)
+}
+
+pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_binary(&proposal.into_response(&env.block, id))
+}
+
+pub fn query_proposal_config(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_binary(&ProposalConfigResponse {
+        threshold: proposal.threshold,
+        allow_revoting: proposal.allow_revoting,
+        only_members_execute: proposal.only_members_execute,
+        deposit_info: query_proposal_deposit(deps, id)?,
+    })
+}
+
+pub fn query_turnout_breakdown(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let voted_power = proposal.votes.yes.checked_add(proposal.votes.no)?;
+    let abstained_power = proposal.votes.abstain;
+    // Saturate so that a proposal with no total power reports zero
+    // rather than failing.
+    let uncast_power = proposal
+        .total_power
+        .saturating_sub(voted_power.checked_add(abstained_power)?);
+    to_binary(&TurnoutBreakdownResponse {
+        voted_power,
+        abstained_power,
+        uncast_power,
+        total_power: proposal.total_power,
+    })
+}
+
+pub fn query_quorum_gap(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_binary(&proposal.quorum_gap()?)
+}
+
+pub fn query_win_margin_percent(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_binary(&proposal.win_margin_percent())
+}
+
+/// Computes `sum(votes_i * duration_i) / (total_power * period)`
+/// where `votes_i` is the total votes cast on the proposal during the
+/// i-th interval of the period between votes and `duration_i` is the
+/// length of that interval in seconds. Votes cast before the vote
+/// timeline was recorded are not counted.
+pub fn query_time_weighted_turnout(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    if proposal.total_power.is_zero() {
+        return to_binary(&None::<Decimal>);
+    }
+
+    let start = proposal.created_at.seconds();
+    let end = match proposal.expiration {
+        Expiration::AtTime(time) => time.seconds().min(env.block.time.seconds()),
+        _ => env.block.time.seconds(),
+    };
+    if end <= start {
+        // No time has passed, so the turnout is the current one.
+        let turnout = Decimal::from_ratio(proposal.votes.total(), proposal.total_power);
+        return to_binary(&Some(turnout));
+    }
+
+    let mut weighted_votes = Uint128::zero();
+    let (mut last_time, mut last_votes) = (start, Uint128::zero());
+    for item in VOTE_TIMELINE.prefix(id).range(
+        deps.storage,
+        None,
+        Some(Bound::inclusive(end)),
+        Order::Ascending,
+    ) {
+        let (time, votes) = item?;
+        let time = time.max(start);
+        weighted_votes =
+            weighted_votes.checked_add(last_votes.checked_mul((time - last_time).into())?)?;
+        (last_time, last_votes) = (time, votes);
+    }
+    weighted_votes =
+        weighted_votes.checked_add(last_votes.checked_mul((end - last_time).into())?)?;
+
+    let turnout = Decimal::from_ratio(
+        weighted_votes,
+        proposal.total_power.checked_mul((end - start).into())?,
+    );
+    to_binary(&Some(turnout))
+}
+
+pub fn query_execution_report(deps: Deps, id: u64) -> StdResult<Binary> {
+    // Proposals that have not been executed in best-effort mode
+    // have no report.
+    let report = EXECUTION_REPORTS.may_load(deps.storage, id)?;
+    to_binary(&report.unwrap_or_default())
+}
+
+pub fn query_message_origin(deps: Deps, contract: String, msg_hash: String) -> StdResult<Binary> {
+    // Identical messages may be executed by several proposals, in
+    // which case the most recent is returned.
+    let msg_hash = msg_hash.to_lowercase();
+    let origin = MESSAGE_ORIGINS
+        .prefix((contract.as_str(), msg_hash.as_str()))
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    to_binary(&origin)
+}
+
+pub fn query_status_history(deps: Deps, id: u64) -> StdResult<Binary> {
+    // Proposals created before status history was recorded have
+    // none.
+    let history = STATUS_HISTORY.may_load(deps.storage, id)?;
+    to_binary(&history.unwrap_or_default())
+}
+
+/// Queries the module's pre-propose module, if any, for the deposit
+/// paid to create proposal ID.
+fn query_proposal_deposit(deps: Deps, id: u64) -> StdResult<Option<CheckedDepositInfo>> {
+    match CREATION_POLICY.load(deps.storage)? {
+        ProposalCreationPolicy::Anyone {} => Ok(None),
+        ProposalCreationPolicy::Module { addr } => {
+            let deposit: StdResult<DepositInfoResponse> = deps.querier.query_wasm_smart(
+                addr,
+                &dao_pre_propose_base::msg::QueryMsg::<Empty>::DepositInfo { proposal_id: id },
+            );
+            // The proposal may predate the current pre-propose module.
+            Ok(deposit.ok().and_then(|deposit| deposit.deposit_info))
+        }
+    }
+}
+
+pub fn query_is_voting_open(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let mut proposal = PROPOSALS.load(deps.storage, id)?;
+    proposal.update_status(&env.block);
+    let open = proposal.status == Status::Open && !proposal.expiration.is_expired(&env.block);
+    to_binary(&open)
+}
+
+pub fn query_can_revote(
+    deps: Deps,
+    env: Env,
+    proposal_id: u64,
+    voter: String,
+) -> StdResult<Binary> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    proposal.update_status(&env.block);
+    let voter = deps.api.addr_validate(&voter)?;
+
+    // Mirrors the checks performed by `execute_vote` when a voter
+    // changes their vote.
+    let reason = if !proposal.allow_revoting {
+        Some("revoting is not allowed on this proposal")
+    } else if proposal.expiration.is_expired(&env.block) {
+        Some("proposal has expired")
+    } else if !phase_ballots(proposal.phase.as_ref()).has(deps.storage, (proposal_id, &voter)) {
+        Some("voter has not voted on this proposal")
+    } else {
+        None
+    };
+    to_binary(&CanRevoteResponse {
+        can_revote: reason.is_none(),
+        reason: reason.map(str::to_string),
+    })
+}
+
+pub fn query_vote_status(
+    deps: Deps,
+    env: Env,
+    proposal_id: u64,
+    address: String,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    proposal.update_status(&env.block);
+    let address = deps.api.addr_validate(&address)?;
+
+    // Mirrors the checks performed by `execute_vote`.
+    let power = voting_power(deps, &config, &address, Some(proposal.start_height))?;
+    let has_voted =
+        phase_ballots(proposal.phase.as_ref()).has(deps.storage, (proposal_id, &address));
+    let in_cooldown = match check_unstake_cooldown(deps, &env, &config, &address) {
+        Ok(()) => false,
+        Err(ContractError::UnstakeCooldown { .. }) => true,
+        Err(err) => return Err(StdError::generic_err(err.to_string())),
+    };
+    let can_vote = !proposal.expiration.is_expired(&env.block)
+        && !power.is_zero()
+        && !in_cooldown
+        && (!has_voted || proposal.allow_revoting);
+    to_binary(&VoteStatusResponse {
+        can_vote,
+        has_voted,
+        power,
+    })
+}
+
+pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
+    let policy = CREATION_POLICY.load(deps.storage)?;
+    to_binary(&policy)
+}
+
+pub fn query_parameters(deps: Deps) -> StdResult<Binary> {
+    // Every config field is set by `UpdateConfig`.
+    fn parameter(key: &str, value: &impl Serialize) -> StdResult<Parameter> {
+        Ok(Parameter {
+            key: key.to_string(),
+            value: String::from_utf8(to_vec(value)?).map_err(StdError::invalid_utf8)?,
+            mutable: true,
+        })
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let parameters = vec![
+        parameter("threshold", &config.threshold)?,
+        parameter("max_voting_period", &config.max_voting_period)?,
+        parameter("min_voting_period", &config.min_voting_period)?,
+        parameter("only_members_execute", &config.only_members_execute)?,
+        parameter("allow_revoting", &config.allow_revoting)?,
+        parameter("dao", &config.dao)?,
+        parameter(
+            "close_proposal_on_execution_failure",
+            &config.close_proposal_on_execution_failure,
+        )?,
+        parameter("max_execution_attempts", &config.max_execution_attempts)?,
+        parameter("max_msgs_per_execution", &config.max_msgs_per_execution)?,
+        parameter("audited_only", &config.audited_only)?,
+        parameter("max_proposal_msgs", &config.max_proposal_msgs)?,
+        parameter("text_limits", &config.text_limits)?,
+        parameter("min_proposer_power", &config.min_proposer_power)?,
+        parameter("tie_policy", &config.tie_policy)?,
+        parameter("allow_self_dao", &config.allow_self_dao)?,
+        parameter("blackout", &config.blackout)?,
+        parameter("best_effort_execution", &config.best_effort_execution)?,
+        parameter("unstake_cooldown", &config.unstake_cooldown)?,
+        parameter("proposer_must_execute", &config.proposer_must_execute)?,
+        parameter(
+            "proposer_execution_grace_period",
+            &config.proposer_execution_grace_period,
+        )?,
+        parameter("proposer_nft_gate", &config.proposer_nft_gate)?,
+        parameter("fallback_voting_module", &config.fallback_voting_module)?,
+        parameter("require_discussion_url", &config.require_discussion_url)?,
+        parameter("abstain_quorum_weight", &config.abstain_quorum_weight)?,
+        parameter("confirmation_phase", &config.confirmation_phase)?,
+        parameter("execution_delay", &config.execution_delay)?,
+    ];
+    to_binary(&parameters)
+}
+
+pub fn query_list_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let props: Vec<ProposalResponse> = PROPOSALS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
+        .into_iter()
+        .map(|(id, proposal)| proposal.into_response(&env.block, id))
+        .collect();
+
+    to_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_proposal_summaries(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let proposals = PROPOSALS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, mut proposal) = item?;
+            proposal.update_status(&env.block);
+            Ok(ProposalSummary {
+                id,
+                title: proposal.title,
+                status: proposal.status,
+                proposer: proposal.proposer,
+                expiration: proposal.expiration,
+            })
+        })
+        .collect::<StdResult<Vec<ProposalSummary>>>()?;
+
+    to_binary(&ProposalSummariesResponse { proposals })
+}
+
+pub fn query_proposals_in_range(
+    deps: Deps,
+    env: Env,
+    start_time: Timestamp,
+    end_time: Timestamp,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let first = match first_proposal_created_since(deps.storage, start_time)? {
+        Some(first) => first,
+        None => return to_binary(&ProposalListResponse { proposals: vec![] }),
+    };
+    let min = start_after.map_or(first, |id| first.max(id.saturating_add(1)));
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let props: Vec<ProposalResponse> = PROPOSALS
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(min)),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take_while(|item| !matches!(item, Ok((_, proposal)) if proposal.created_at >= end_time))
+        .take(limit as usize)
+        .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
+        .into_iter()
+        .map(|(id, proposal)| proposal.into_response(&env.block, id))
+        .collect();
+
+    to_binary(&ProposalListResponse { proposals: props })
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::Config {} => query_config(deps),
-        QueryMsg::Dao {} => query_dao(deps),
-        QueryMsg::Proposal { proposal_id } => query_proposal(deps, env, proposal_id),
-        QueryMsg::ListProposals { start_after, limit } => {
-            query_list_proposals(deps, env, start_after, limit)
+/// Gets the ID of the first proposal created at or after TIME, if
+/// any. Proposal IDs are sequential and creation times ascend with
+/// them, so it is found by binary search rather than by scanning
+/// every proposal. Proposals created before creation times were
+/// recorded have a zero timestamp and are never found.
+fn first_proposal_created_since(storage: &dyn Storage, time: Timestamp) -> StdResult<Option<u64>> {
+    let time = time.max(Timestamp::from_nanos(1));
+    let first = PROPOSALS
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .transpose()?;
+    let last = PROPOSALS
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    let (mut low, last) = match (first, last) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Ok(None),
+    };
+    let mut high = last + 1;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if PROPOSALS.load(storage, mid)?.created_at < time {
+            low = mid + 1;
+        } else {
+            high = mid;
         }
-        QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
-        QueryMsg::ProposalCount {} => query_proposal_count(deps),
-        QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
-        QueryMsg::ListVotes {
-            proposal_id,
-            start_after,
-            limit,
-        } => query_list_votes(deps, proposal_id, start_after, limit),
-        QueryMsg::Info {} => query_info(deps),
-        QueryMsg::ReverseProposals {
-            start_before,
-            limit,
-        } => query_reverse_proposals(deps, env, start_before, limit),
-        QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
-        QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
-        QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
     }
+    Ok(Some(low).filter(|id| *id <= last))
 }
 
-pub fn query_config(deps: Deps) -> StdResult<Binary> {
-    let config = CONFIG.load(deps.storage)?;
-    to_binary(&config)
-}
+pub fn query_proposals_by_proposer(
+    deps: Deps,
+    env: Env,
+    proposer: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let proposer = deps.api.addr_validate(&proposer)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let props = PROPOSALS_BY_PROPOSER
+        .prefix(&proposer)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|id| {
+            let id = id?;
+            let proposal = PROPOSALS.load(deps.storage, id)?;
+            Ok(proposal.into_response(&env.block, id))
+        })
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
 
-pub fn query_dao(deps: Deps) -> StdResult<Binary> {
-    let config = CONFIG.load(deps.storage)?;
-    to_binary(&config.dao)
+    to_binary(&ProposalListResponse { proposals: props })
 }
 
-pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
-    let proposal = PROPOSALS.load(deps.storage, id)?;
-    to_binary(&proposal.into_response(&env.block, id))
-}
+pub fn query_closeable_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_PROPOSALS_SCANNED as u64);
+    let props: Vec<ProposalResponse> = PROPOSALS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .map(|item| -> StdResult<(bool, ProposalResponse)> {
+            let (id, proposal) = item?;
+            let response = proposal.into_response(&env.block, id);
+            Ok((is_closeable(deps, &response.proposal)?, response))
+        })
+        .filter(|item| !matches!(item, Ok((false, _))))
+        .map(|item| item.map(|(_, response)| response))
+        .collect::<StdResult<_>>()?;
 
-pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
-    let policy = CREATION_POLICY.load(deps.storage)?;
-    to_binary(&policy)
+    to_binary(&ProposalListResponse { proposals: props })
 }
 
-pub fn query_list_proposals(
+pub fn query_expiring_proposals(
     deps: Deps,
     env: Env,
+    within: Duration,
     start_after: Option<u64>,
     limit: Option<u64>,
 ) -> StdResult<Binary> {
     let min = start_after.map(Bound::exclusive);
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_PROPOSALS_SCANNED as u64);
+    let deadline = within.after(&env.block);
     let props: Vec<ProposalResponse> = PROPOSALS
         .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
         .take(limit as usize)
-        .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
-        .into_iter()
-        .map(|(id, proposal)| proposal.into_response(&env.block, id))
-        .collect();
+        .map(|item| item.map(|(id, proposal)| proposal.into_response(&env.block, id)))
+        .filter(|item| match item {
+            // Expirations in different units than the deadline do not
+            // compare, so those proposals are excluded.
+            Ok(response) => {
+                response.proposal.status == Status::Open && response.proposal.expiration <= deadline
+            }
+            Err(_) => true,
+        })
+        .collect::<StdResult<_>>()?;
 
     to_binary(&ProposalListResponse { proposals: props })
 }
 
+pub fn query_proposals_sending_to(
+    deps: Deps,
+    recipient: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_PROPOSALS_SCANNED as u64);
+    let proposals = PROPOSALS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, proposal) = item?;
+            sends_to(id, &recipient, &proposal.msgs)
+        })
+        .filter(|item| match item {
+            Ok(sends) => !sends.native.is_empty() || !sends.cw20.is_empty(),
+            Err(_) => true,
+        })
+        .collect::<StdResult<Vec<ProposalSends>>>()?;
+
+    to_binary(&ProposalsSendingToResponse { proposals })
+}
+
+/// Totals the native and cw20 tokens that MSGS, the messages of the
+/// proposal with ID PROPOSAL_ID, send to RECIPIENT.
+fn sends_to(
+    proposal_id: u64,
+    recipient: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+) -> StdResult<ProposalSends> {
+    let mut native: Vec<Coin> = vec![];
+    let mut cw20: Vec<Cw20Amount> = vec![];
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) if to_address == recipient => {
+                for coin in amount {
+                    match native.iter_mut().find(|c| c.denom == coin.denom) {
+                        Some(total) => total.amount = total.amount.checked_add(coin.amount)?,
+                        None => native.push(coin.clone()),
+                    }
+                }
+            }
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                let amount = match from_binary(msg) {
+                    Ok(cw20::Cw20ExecuteMsg::Transfer {
+                        recipient: to,
+                        amount,
+                    })
+                    | Ok(cw20::Cw20ExecuteMsg::Send {
+                        contract: to,
+                        amount,
+                        ..
+                    }) if to == *recipient => amount,
+                    _ => continue,
+                };
+                match cw20.iter_mut().find(|c| c.token == *contract_addr) {
+                    Some(total) => total.amount = total.amount.checked_add(amount)?,
+                    None => cw20.push(Cw20Amount {
+                        token: contract_addr.clone(),
+                        amount,
+                    }),
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(ProposalSends {
+        proposal_id,
+        native,
+        cw20,
+    })
+}
+
 pub fn query_reverse_proposals(
     deps: Deps,
     env: Env,
@@ -793,6 +2407,190 @@ pub fn query_reverse_proposals(
     to_binary(&ProposalListResponse { proposals: props })
 }
 
+pub fn query_execution_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_PROPOSALS_SCANNED as u64);
+    let executions = PROPOSALS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .filter_map(|item| match item {
+            // Proposals whose messages failed to execute keep the
+            // height at which their execution was attempted, so only
+            // executed proposals are listed.
+            Ok((_, proposal)) if proposal.status != Status::Executed => None,
+            Ok((id, proposal)) => proposal.execution_height.map(|execution_height| {
+                Ok(ExecutionInfo {
+                    id,
+                    title: proposal.title,
+                    execution_height,
+                })
+            }),
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<StdResult<Vec<ExecutionInfo>>>()?;
+
+    to_binary(&ExecutionHistoryResponse { executions })
+}
+
+pub fn query_proposals_by_execution_outcome(
+    deps: Deps,
+    env: Env,
+    outcome: ExecutionOutcome,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_PROPOSALS_SCANNED as u64);
+    let props = PROPOSALS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .filter(|item| match item {
+            Ok((_, proposal)) => proposal.execution_outcome(&env.block) == Some(outcome),
+            Err(_) => true,
+        })
+        .map(|item| item.map(|(id, proposal)| proposal.into_response(&env.block, id)))
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_binary(&ProposalListResponse { proposals: props })
+}
+
+/// Sums the votes cast and total voting power of the open proposals
+/// among the LIMIT proposals with IDs after START_AFTER.
+fn participation(
+    deps: Deps,
+    env: &Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<ParticipationResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_PROPOSALS_SCANNED as u64);
+    PROPOSALS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .try_fold(ParticipationResponse::default(), |acc, item| {
+            let (_, proposal) = item?;
+            if proposal.current_status(&env.block) != Status::Open {
+                return Ok(acc);
+            }
+            acc.checked_add(ParticipationResponse {
+                open_proposals: 1,
+                votes_cast: proposal.votes.total(),
+                total_power: proposal.total_power,
+            })
+        })
+}
+
+pub fn query_voter_participation_count(deps: Deps, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    to_binary(
+        &VOTER_PARTICIPATION
+            .may_load(deps.storage, &voter)?
+            .unwrap_or_default(),
+    )
+}
+
+pub fn query_proposer_stats(deps: Deps, proposer: String) -> StdResult<Binary> {
+    let proposer = deps.api.addr_validate(&proposer)?;
+    let stats = PROPOSER_STATS
+        .may_load(deps.storage, &proposer)?
+        .unwrap_or_default();
+    let average_turnout = match stats.completed() {
+        0 => Decimal::zero(),
+        completed => stats.total_turnout / Decimal::from_ratio(completed, 1u64),
+    };
+    to_binary(&ProposerStatsResponse {
+        proposals: stats.proposals,
+        executed: stats.executed,
+        execution_failed: stats.execution_failed,
+        closed: stats.closed,
+        average_turnout,
+    })
+}
+
+pub fn query_audited_contracts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::<&Addr>::exclusive);
+
+    let contracts = AUDITED_CONTRACTS
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    to_binary(&contracts)
+}
+
+pub fn query_combined_participation(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let own = participation(deps, &env, start_after, limit)?;
+    let combined = match LINKED_MODULE.may_load(deps.storage)? {
+        Some(linked_module) => {
+            let linked: ParticipationResponse = deps.querier.query_wasm_smart(
+                linked_module,
+                &QueryMsg::Participation { start_after, limit },
+            )?;
+            own.checked_add(linked)?
+        }
+        None => own,
+    };
+    to_binary(&combined)
+}
+
+pub fn query_voting_module_compatible(
+    deps: Deps,
+    env: Env,
+    voting_module: String,
+) -> StdResult<Binary> {
+    let voting_module = deps.api.addr_validate(&voting_module)?;
+
+    // Responses are only checked to have the expected shape, so any
+    // error is treated as the query being unsupported.
+    let info = deps
+        .querier
+        .query_wasm_smart::<voting::InfoResponse>(&voting_module, &voting::Query::Info {})
+        .ok()
+        .map(|res| res.info);
+    let voting_power_at_height =
+        voting_power_at_height(&deps.querier, &voting_module, env.contract.address, None).is_ok();
+    let total_power_at_height = total_power_at_height(&deps.querier, &voting_module, None).is_ok();
+    let dao = deps
+        .querier
+        .query_wasm_smart::<Addr>(&voting_module, &voting::Query::Dao {})
+        .is_ok();
+
+    to_binary(&VotingModuleCompatibilityResponse {
+        compatible: info.is_some() && voting_power_at_height && total_power_at_height && dao,
+        info,
+        voting_power_at_height,
+        total_power_at_height,
+        dao,
+    })
+}
+
 pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
     let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
     to_binary(&proposal_count)
@@ -804,7 +2602,10 @@ pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
 
 pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
     let voter = deps.api.addr_validate(&voter)?;
-    let ballot = BALLOTS.may_load(deps.storage, (proposal_id, &voter))?;
+    let phase = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .and_then(|prop| prop.phase);
+    let ballot = phase_ballots(phase.as_ref()).may_load(deps.storage, (proposal_id, &voter))?;
     let vote = ballot.map(|ballot| VoteInfo {
         voter,
         vote: ballot.vote,
@@ -819,16 +2620,25 @@ pub fn query_list_votes(
     proposal_id: u64,
     start_after: Option<String>,
     limit: Option<u64>,
+    reverse: Option<bool>,
 ) -> StdResult<Binary> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
     let start_after = start_after
         .map(|addr| deps.api.addr_validate(&addr))
         .transpose()?;
-    let min = start_after.as_ref().map(Bound::<&Addr>::exclusive);
+    let bound = start_after.as_ref().map(Bound::<&Addr>::exclusive);
+    let (min, max, order) = if reverse.unwrap_or(false) {
+        (None, bound, cosmwasm_std::Order::Descending)
+    } else {
+        (bound, None, cosmwasm_std::Order::Ascending)
+    };
 
-    let votes = BALLOTS
+    let phase = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .and_then(|prop| prop.phase);
+    let votes = phase_ballots(phase.as_ref())
         .prefix(proposal_id)
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .range(deps.storage, min, max, order)
         .take(limit as usize)
         .map(|item| {
             let (voter, ballot) = item?;
@@ -844,6 +2654,77 @@ pub fn query_list_votes(
     to_binary(&VoteListResponse { votes })
 }
 
+pub fn query_voters(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<String>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::<&Addr>::exclusive);
+
+    let phase = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .and_then(|prop| prop.phase);
+    let voters = phase_ballots(phase.as_ref())
+        .prefix(proposal_id)
+        .keys(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    to_binary(&VoterListResponse { voters })
+}
+
+pub fn query_votes_by_tier(deps: Deps, proposal_id: u64, tiers: Vec<Uint128>) -> StdResult<Binary> {
+    if tiers.len() > MAX_VOTE_TIERS {
+        return Err(StdError::generic_err(format!(
+            "at most {} tiers may be queried",
+            MAX_VOTE_TIERS
+        )));
+    }
+    if tiers.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(StdError::generic_err(
+            "tiers must be in strictly ascending order",
+        ));
+    }
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+
+    let mut tiers: Vec<TierVotes> = tiers
+        .into_iter()
+        .map(|min_power| TierVotes {
+            min_power,
+            yes: TierTally::default(),
+            no: TierTally::default(),
+            abstain: TierTally::default(),
+        })
+        .collect();
+    let ballots = phase_ballots(proposal.phase.as_ref())
+        .prefix(proposal_id)
+        .range(deps.storage, None, None, Order::Ascending);
+    for ballot in ballots {
+        let (_, ballot) = ballot?;
+        let Some(tier) = tiers
+            .iter_mut()
+            .rev()
+            .find(|tier| tier.min_power <= ballot.power)
+        else {
+            continue;
+        };
+        let tally = match ballot.vote {
+            Vote::Yes => &mut tier.yes,
+            Vote::No => &mut tier.no,
+            Vote::Abstain => &mut tier.abstain,
+        };
+        tally.count += 1;
+        tally.power += ballot.power;
+    }
+
+    to_binary(&VotesByTierResponse { tiers })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_binary(&dao_interface::voting::InfoResponse { info })
@@ -880,6 +2761,25 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     allow_revoting: current_config.allow_revoting,
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
+                    max_execution_attempts: None,
+                    max_msgs_per_execution: None,
+                    audited_only: false,
+                    max_proposal_msgs: None,
+                    text_limits: None,
+                    min_proposer_power: None,
+                    tie_policy: SingleChoiceTiePolicy::default(),
+                    allow_self_dao: false,
+                    blackout: None,
+                    best_effort_execution: false,
+                    unstake_cooldown: None,
+                    proposer_must_execute: false,
+                    proposer_execution_grace_period: None,
+                    proposer_nft_gate: None,
+                    fallback_voting_module: None,
+                    require_discussion_url: false,
+                    abstain_quorum_weight: None,
+                    confirmation_phase: None,
+                    execution_delay: None,
                 },
             )?;
 
@@ -924,8 +2824,24 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         status: v1_status_to_v2(prop.status),
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
+                        execution_attempts: 0,
+                        execution_height: None,
+                        executed_msgs: 0,
+                        only_members_execute: None,
+                        created_at: Timestamp::default(),
+                        precondition: None,
+                        tie_policy: None,
+                        discussion_url: None,
+                        abstain_quorum_weight: None,
+                        phase: None,
+                        passed_at: None,
                     };
 
+                    PROPOSALS_BY_PROPOSER.save(
+                        deps.storage,
+                        (&migrated_proposal.proposer, id),
+                        &Empty {},
+                    )?;
                     PROPOSALS
                         .save(deps.storage, id, &migrated_proposal)
                         .map_err(|e| e.into())
@@ -944,20 +2860,104 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
-                Some(mut prop) => {
-                    prop.status = Status::ExecutionFailed;
-
-                    Ok(prop)
+            let mut prop = PROPOSALS
+                .may_load(deps.storage, proposal_id)?
+                .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+            let config = CONFIG.load(deps.storage)?;
+
+            // Successful executions are only replied to when execution
+            // attempts are being tracked, in which case progress and
+            // completion hooks were deferred until now.
+            if let SubMsgResult::Ok(_) = msg.result {
+                EXECUTING_CHUNKS.remove(deps.storage, proposal_id);
+                prop.executed_msgs += prop.next_chunk(config.max_msgs_per_execution).len() as u64;
+                PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+                let state_hashes = state_hash_attributes(deps.as_ref(), &env, &prop)?;
+
+                if prop.status != Status::Executed {
+                    return Ok(Response::new()
+                        .add_attribute("proposal_id", proposal_id.to_string())
+                        .add_attribute("executed_msgs", prop.executed_msgs.to_string())
+                        .add_attributes(state_hashes));
                 }
-                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
-            })?;
+                record_proposal_outcome(deps.storage, &prop, None)?;
+                let hooks = proposal_completed_hooks(
+                    deps.storage,
+                    proposal_id,
+                    Status::Passed,
+                    prop.status,
+                )?;
+                return Ok(Response::new()
+                    .add_submessages(hooks)
+                    .add_attribute("proposal_executed", proposal_id.to_string())
+                    .add_attributes(state_hashes));
+            }
 
-            Ok(Response::new().add_attribute("proposal_execution_failed", proposal_id.to_string()))
+            proposal_execution_failed(deps, &env, proposal_id, prop, config)
+        }
+        TaggedReplyId::ProposalExecutionStateHash(proposal_id) => {
+            let prop = PROPOSALS
+                .may_load(deps.storage, proposal_id)?
+                .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+            match msg.result {
+                SubMsgResult::Ok(_) => {
+                    EXECUTING_CHUNKS.remove(deps.storage, proposal_id);
+                    Ok(Response::new()
+                        .add_attribute("proposal_id", proposal_id.to_string())
+                        .add_attributes(state_hash_attributes(deps.as_ref(), &env, &prop)?))
+                }
+                // Failures are only replied to when proposals are
+                // closed on execution failure.
+                SubMsgResult::Err(_) => {
+                    let config = CONFIG.load(deps.storage)?;
+                    proposal_execution_failed(deps, &env, proposal_id, prop, config)
+                }
+            }
+        }
+        TaggedReplyId::ProposalMessageExecution(proposal_id) => {
+            let execution = match msg.result {
+                SubMsgResult::Ok(res) => MessageExecution {
+                    success: true,
+                    error: None,
+                    events: res.events,
+                },
+                SubMsgResult::Err(error) => MessageExecution {
+                    success: false,
+                    error: Some(error),
+                    events: vec![],
+                },
+            };
+            // Messages are executed and replied to in order, so the
+            // index of this message is the number of messages
+            // already in the report.
+            let mut report = EXECUTION_REPORTS
+                .may_load(deps.storage, proposal_id)?
+                .unwrap_or_default();
+            let index = report.len();
+            let success = execution.success;
+            report.push(execution);
+            EXECUTION_REPORTS.save(deps.storage, proposal_id, &report)?;
+            if !success {
+                let prop = PROPOSALS
+                    .may_load(deps.storage, proposal_id)?
+                    .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+                let config = CONFIG.load(deps.storage)?;
+                unindex_message_origins(
+                    deps.storage,
+                    &config.dao,
+                    proposal_id,
+                    &prop.msgs[index..index + 1],
+                )?;
+            }
+            Ok(Response::new()
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("message_index", index.to_string())
+                .add_attribute("success", success.to_string()))
         }
         TaggedReplyId::FailedProposalHook(idx) => {
             let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
@@ -989,6 +2989,8 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                     .add_attribute("update_pre_propose_module", res.contract_address)),
             }
         }
+        // This module does not post proposal outcomes over IBC.
+        TaggedReplyId::FailedOutcomePacket(_) => Err(ContractError::InvalidReplyID { id: msg.id }),
         TaggedReplyId::FailedPreProposeModuleHook => {
             let addr = match CREATION_POLICY.load(deps.storage)? {
                 ProposalCreationPolicy::Anyone {} => {
@@ -1012,3 +3014,94 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
         }
     }
 }
+
+/// Handles a failed execution of proposal ID, either leaving it
+/// passed so that it may be executed again or marking its execution
+/// as failed.
+fn proposal_execution_failed(
+    deps: DepsMut,
+    env: &Env,
+    proposal_id: u64,
+    mut prop: SingleChoiceProposal,
+    config: Config,
+) -> Result<Response, ContractError> {
+    // None of the failed chunk's messages were executed.
+    if let Some((start, end)) = EXECUTING_CHUNKS.may_load(deps.storage, proposal_id)? {
+        unindex_message_origins(
+            deps.storage,
+            &config.dao,
+            proposal_id,
+            &prop.msgs[start as usize..end as usize],
+        )?;
+        EXECUTING_CHUNKS.remove(deps.storage, proposal_id);
+    }
+    prop.execution_attempts += 1;
+
+    match config.max_execution_attempts {
+        Some(max) if prop.execution_attempts < max => {
+            // Attempts remain, leave the proposal passed so
+            // that it may be executed again.
+            prop.status = Status::Passed;
+            prop.execution_height = None;
+            PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+            record_status_transition(
+                deps.storage,
+                proposal_id,
+                prop.status,
+                env.block.height,
+                "execution_attempt_failed",
+            )?;
+
+            Ok(Response::new()
+                .add_attribute("proposal_execution_attempt_failed", proposal_id.to_string())
+                .add_attribute("execution_attempts", prop.execution_attempts.to_string()))
+        }
+        Some(_) => {
+            prop.status = Status::ExecutionFailed;
+            PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+            record_status_transition(
+                deps.storage,
+                proposal_id,
+                prop.status,
+                env.block.height,
+                "execution_failed",
+            )?;
+            record_proposal_outcome(deps.storage, &prop, None)?;
+
+            let hooks =
+                proposal_completed_hooks(deps.storage, proposal_id, Status::Passed, prop.status)?;
+            Ok(Response::new()
+                .add_submessages(hooks)
+                .add_attribute("proposal_execution_failed", proposal_id.to_string()))
+        }
+        None => {
+            // If the failed chunk was the final one the proposal was
+            // recorded as executed, and completion hooks fired, when
+            // it was sent. Otherwise the proposal completes here.
+            let replaces = (prop.status == Status::Executed).then_some(Status::Executed);
+            prop.status = Status::ExecutionFailed;
+            PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+            record_status_transition(
+                deps.storage,
+                proposal_id,
+                prop.status,
+                env.block.height,
+                "execution_failed",
+            )?;
+            record_proposal_outcome(deps.storage, &prop, replaces)?;
+
+            let hooks = match replaces {
+                Some(_) => vec![],
+                None => proposal_completed_hooks(
+                    deps.storage,
+                    proposal_id,
+                    Status::Passed,
+                    prop.status,
+                )?,
+            };
+            Ok(Response::new()
+                .add_submessages(hooks)
+                .add_attribute("proposal_execution_failed", proposal_id.to_string()))
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use std::u64;
 
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use cw_hooks::HookError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
 use dao_voting::reply::error::TagError;
 use thiserror::Error;
 
@@ -26,6 +26,12 @@ pub enum ContractError {
     #[error(transparent)]
     VotingError(#[from] dao_voting::error::VotingError),
 
+    #[error(transparent)]
+    PreconditionError(#[from] dao_voting::precondition::PreconditionError),
+
+    #[error(transparent)]
+    TextLimitError(#[from] dao_voting::text_limits::TextLimitError),
+
     #[error("no such proposal ({id})")]
     NoSuchProposal { id: u64 },
 
@@ -41,6 +47,11 @@ pub enum ContractError {
     #[error("not registered to vote (no voting power) at time of proposal creation")]
     NotRegistered {},
 
+    #[error(
+        "no voting power at the proposal's snapshot height ({snapshot_height}). voting power gained after a proposal is created may not be used to vote on it"
+    )]
+    NoVotingPowerAtSnapshot { snapshot_height: u64 },
+
     #[error("already voted. this proposal does not support revoting")]
     AlreadyVoted {},
 
@@ -50,13 +61,18 @@ pub enum ContractError {
     #[error("proposal is not in 'passed' state")]
     NotPassed {},
 
+    #[error("the precondition of proposal ({id}) does not hold. it may be closed")]
+    PreconditionNotMet { id: u64 },
+
     #[error("proposal has already been executed")]
     AlreadyExecuted {},
 
     #[error("proposal is closed")]
     Closed {},
 
-    #[error("only rejected proposals may be closed")]
+    #[error(
+        "only rejected proposals and passed proposals whose precondition does not hold may be closed"
+    )]
     WrongCloseStatus {},
 
     #[error("the DAO is currently inactive, you cannot create proposals")]
@@ -68,6 +84,15 @@ pub enum ContractError {
     #[error("min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
 
+    #[error("proposal_duration and max_voting_period must have the same units (height or time)")]
+    ProposalDurationUnitsConflict {},
+
+    #[error("proposal_duration may not be longer than the max voting period")]
+    ProposalDurationTooLong {},
+
+    #[error("proposals must reference the thread they were discussed in with a discussion_url")]
+    MissingDiscussionUrl {},
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
@@ -89,4 +114,81 @@ pub enum ContractError {
 
     #[error("can not migrate. current version is up to date")]
     AlreadyMigrated {},
+
+    #[error("max_execution_attempts must be greater than zero")]
+    ZeroMaxExecutionAttempts {},
+
+    #[error("max_msgs_per_execution must be greater than zero")]
+    ZeroMaxMsgsPerExecution {},
+
+    #[error("max_proposal_msgs must be greater than zero")]
+    ZeroMaxProposalMsgs {},
+
+    #[error("proposal has too many messages ({count}). the maximum is ({max})")]
+    TooManyProposalMsgs { count: u64, max: u64 },
+
+    #[error(
+        "proposer has ({power}) voting power. at least ({min}) is needed to create a proposal"
+    )]
+    InsufficientProposerPower { power: Uint128, min: Uint128 },
+
+    #[error("the DAO may not be the proposal module itself unless allow_self_dao is set")]
+    SelfDao {},
+
+    #[error(
+        "address unstaked recently and may not propose or vote until its cooldown ends ({ends})"
+    )]
+    UnstakeCooldown { ends: Expiration },
+
+    #[error("unstake cooldowns require a voting module that responds to StakingContract {{}} with a staking contract that responds to LastUnstake {{}}")]
+    UnstakeCooldownUnsupported {},
+
+    #[error(
+        "only the proposer may execute this proposal until its grace period ends ({grace_ends})"
+    )]
+    ProposerMustExecute { grace_ends: Expiration },
+
+    #[error(
+        "proposer_execution_grace_period and max_voting_period must have the same units (height or time)"
+    )]
+    GracePeriodUnitsConflict {},
+
+    #[error("proposer_nft_gate min_count must be non-zero")]
+    ZeroNftGateCount {},
+
+    #[error(
+        "proposer holds ({count}) NFTs of the gating collection. at least ({min}) are needed to create a proposal"
+    )]
+    InsufficientProposerNfts { count: u64, min: u64 },
+
+    #[error(
+        "proposal execution has already started. use `continue_execution` to execute its remaining messages"
+    )]
+    ExecutionInProgress {},
+
+    #[error("proposal execution has not started")]
+    ExecutionNotStarted {},
+
+    #[error("blackout start_height must be less than its end_height")]
+    InvalidBlackout {},
+
+    #[error(
+        "governance is paused for a blackout from height {start_height} until height {end_height}"
+    )]
+    InBlackout { start_height: u64, end_height: u64 },
+
+    #[error("proposal message targets a contract that has not been audited ({address})")]
+    UnauditedContract { address: String },
+
+    #[error("abstain_quorum_weight must be a percentage no greater than 100%")]
+    InvalidAbstainQuorumWeight {},
+
+    #[error("confirmation_phase voting_period and max_voting_period must have the same units (height or time)")]
+    ConfirmationPhaseUnitsConflict {},
+
+    #[error("proposal may not be executed until its timelock expires ({executable_at})")]
+    TimelockNotExpired { executable_at: Expiration },
+
+    #[error("execution_delay and max_voting_period must have the same units (height or time)")]
+    ExecutionDelayUnitsConflict {},
 }
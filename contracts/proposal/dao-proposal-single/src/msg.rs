@@ -1,11 +1,17 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Timestamp, Uint128};
 use cw_utils::Duration;
 use dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
+    pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg,
+    text_limits::ProposalTextLimits,
+    threshold::{PercentageThreshold, Threshold},
     voting::Vote,
 };
 
+use crate::state::{Blackout, ConfirmationPhase, SingleChoiceTiePolicy};
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The threshold a proposal must reach to complete.
@@ -38,6 +44,132 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The number of times execution of a passed proposal may fail
+    /// before the proposal is moved to the `ExecutionFailed` status
+    /// and its deposit is returned. Failed attempts before this limit
+    /// is reached leave the proposal passed so that it may be
+    /// executed again. If None, failures are handled according to
+    /// `close_proposal_on_execution_failure`.
+    pub max_execution_attempts: Option<u64>,
+    /// The maximum number of a proposal's messages that are executed
+    /// at once. Proposals with more messages than this are executed
+    /// in chunks over multiple `Execute` and `ContinueExecution`
+    /// calls and remain passed until all of their messages have
+    /// executed. If None, all messages are executed at once.
+    pub max_msgs_per_execution: Option<u64>,
+    /// If true, proposals may only contain messages that target the
+    /// DAO or contracts that have been added to this module's audited
+    /// contracts allowlist. Proposals containing messages that
+    /// execute, migrate, or change the admin of any other contract
+    /// will be rejected.
+    #[serde(default)]
+    pub audited_only: bool,
+    /// The maximum number of messages a proposal may contain.
+    /// Proposals with more messages are rejected at creation. If
+    /// None, proposals are only limited by `MAX_PROPOSAL_SIZE`.
+    pub max_proposal_msgs: Option<u64>,
+    /// Limits on the length of proposal titles and descriptions.
+    /// Proposals that exceed them are rejected at creation. If None,
+    /// they are only limited by `MAX_PROPOSAL_SIZE`.
+    pub text_limits: Option<ProposalTextLimits>,
+    /// The minimum voting power an address must have to create a
+    /// proposal. If None, any address permitted by the proposal
+    /// creation policy may propose.
+    pub min_proposer_power: Option<Uint128>,
+    /// How proposals with exactly tied yes and no votes are
+    /// resolved. If None, tied proposals are rejected.
+    pub tie_policy: Option<SingleChoiceTiePolicy>,
+    /// If true, the DAO may be this proposal module's own
+    /// address. Otherwise, instantiating or updating the config
+    /// with such a DAO fails.
+    #[serde(default)]
+    pub allow_self_dao: bool,
+    /// A window of block heights, for example around a chain upgrade,
+    /// during which new proposals are rejected and, optionally,
+    /// passed proposals may not be executed. `start_height` must be
+    /// less than `end_height`. If None, there is no blackout.
+    #[serde(default)]
+    pub blackout: Option<Blackout>,
+    /// If true, each message of a passed proposal is executed on its
+    /// own and messages that fail do not fail the proposal's
+    /// execution. The outcome of each message may be queried with
+    /// `ExecutionReport`.
+    #[serde(default)]
+    pub best_effort_execution: bool,
+    /// How long after unstaking an address may not create or vote
+    /// on proposals, which prevents voting power from being cycled
+    /// between proposals by staking, voting, and unstaking. Requires
+    /// the DAO's voting module to respond to `StakingContract {}`
+    /// with a contract that responds to `LastUnstake {}`, as
+    /// `dao-voting-cw20-staked` does. If the voting module is later
+    /// replaced by one that does not, the cooldown is not
+    /// enforced. If None, there is no cooldown.
+    pub unstake_cooldown: Option<Duration>,
+    /// If true, only the proposer of a passed proposal may execute
+    /// it until `proposer_execution_grace_period` after the
+    /// proposal's expiration, after which anyone allowed by
+    /// `only_members_execute` may. Ties a proposal's follow-through
+    /// to its proposer.
+    #[serde(default)]
+    pub proposer_must_execute: bool,
+    /// How long after a proposal expires only its proposer may
+    /// execute it when `proposer_must_execute` is set. Must use the
+    /// same units as `max_voting_period`. If None, only the proposer
+    /// may ever execute it.
+    pub proposer_execution_grace_period: Option<Duration>,
+    /// If set, proposers must hold at least `min_count` NFTs of the
+    /// cw721 `collection` to create proposals. This layers NFT
+    /// membership, for example founding member NFTs, on top of the
+    /// DAO's voting power.
+    pub proposer_nft_gate: Option<UncheckedProposerNftGate>,
+    /// A voting module queried for voting power only when querying
+    /// the DAO fails, for example because its voting module was
+    /// migrated to broken code. The fallback is a separate source of
+    /// voting power, so it should mirror the voting module's weights:
+    /// proposals and votes counted while it is in use may otherwise
+    /// be inconsistent with those counted against the DAO.
+    pub fallback_voting_module: Option<String>,
+    /// If true, proposals must be created with a non-empty
+    /// `discussion_url` referencing the thread where they were
+    /// discussed, so that deliberation happens before voting.
+    #[serde(default)]
+    pub require_discussion_url: bool,
+    /// The portion of abstaining voting power that counts toward
+    /// quorum, for example 50% to count abstentions as half a vote.
+    /// Abstaining lets a member be counted as present without taking
+    /// a side, so at full weight members who do not care about a
+    /// proposal can carry it past quorum for those who do. Must be a
+    /// percentage no greater than 100%. If None, abstentions count
+    /// in full.
+    #[serde(default)]
+    pub abstain_quorum_weight: Option<PercentageThreshold>,
+    /// If set, proposals are voted on in two phases. An intent phase,
+    /// voted on with `threshold` for the proposal's voting period,
+    /// must pass for the proposal to advance to a confirmation phase
+    /// with its own voting period and threshold. Only proposals that
+    /// pass both phases may be executed, which gives members time to
+    /// reconsider high-stakes decisions. If None, proposals have a
+    /// single voting phase.
+    #[serde(default)]
+    pub confirmation_phase: Option<ConfirmationPhase>,
+    /// A timelock between proposals passing and being executable,
+    /// which gives members time to react to passed proposals before
+    /// they take effect. Must have the same units (height or time) as
+    /// `max_voting_period`. Closing rejected proposals is not delayed.
+    /// If None, proposals may be executed as soon as they pass.
+    #[serde(default)]
+    pub execution_delay: Option<Duration>,
+}
+
+/// An unvalidated `ProposerNftGate`.
+#[cw_serde]
+pub struct UncheckedProposerNftGate {
+    /// The address of the cw721 collection proposers must hold NFTs
+    /// of.
+    pub collection: String,
+    /// The minimum number of the collection's NFTs a proposer must
+    /// hold. Must be non-zero.
+    pub min_count: u64,
 }
 
 #[cw_serde]
@@ -68,6 +200,13 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to execute.
         proposal_id: u64,
     },
+    /// Executes the next chunk of messages of a proposal whose
+    /// execution has been started but not completed. See
+    /// `max_msgs_per_execution`.
+    ContinueExecution {
+        /// The ID of the proposal to continue executing.
+        proposal_id: u64,
+    },
     /// Closes a proposal that has failed (either not passed or timed
     /// out). If applicable this will cause the proposal deposit
     /// associated wth said proposal to be returned.
@@ -75,6 +214,21 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to close.
         proposal_id: u64,
     },
+    /// Closes the rejected proposals of this module, in ascending
+    /// order of proposal ID, as `Close` would. Proposals that are
+    /// still open or have already completed are skipped. At most 300
+    /// proposals are examined per call. The IDs of the closed
+    /// proposals are returned in the `proposal_ids` attribute and
+    /// the ID of the last proposal examined in the `last_scanned`
+    /// attribute, which may be passed as `start_after` to continue.
+    CloseExpired {
+        /// Only proposals with IDs greater than this are examined.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to close. If no limit is
+        /// set a max of 30 proposals will be closed. Limits greater
+        /// than 100 are treated as 100.
+        limit: Option<u32>,
+    },
     /// Updates the governance module's config.
     UpdateConfig {
         /// The new proposal passing threshold. This will only apply
@@ -110,10 +264,105 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// The number of times execution of a passed proposal may
+        /// fail before the proposal is moved to the `ExecutionFailed`
+        /// status. Applies to all outstanding and future proposals.
+        max_execution_attempts: Option<u64>,
+        /// The maximum number of a proposal's messages that are
+        /// executed at once. Applies to all outstanding and future
+        /// proposals.
+        max_msgs_per_execution: Option<u64>,
+        /// If true, proposals may only contain messages that target
+        /// the DAO or contracts in the audited contracts
+        /// allowlist. Applies to proposals created after the config
+        /// update.
+        #[serde(default)]
+        audited_only: bool,
+        /// The maximum number of messages a proposal may
+        /// contain. Applies to proposals created after the config
+        /// update.
+        max_proposal_msgs: Option<u64>,
+        /// Limits on the length of proposal titles and
+        /// descriptions. Applies to proposals created after the
+        /// config update.
+        text_limits: Option<ProposalTextLimits>,
+        /// The minimum voting power an address must have to create
+        /// a proposal. Applies to proposals created after the config
+        /// update.
+        min_proposer_power: Option<Uint128>,
+        /// How proposals with exactly tied yes and no votes are
+        /// resolved. If None, tied proposals are rejected. Applies to
+        /// proposals created after the config update.
+        tie_policy: Option<SingleChoiceTiePolicy>,
+        /// If true, `dao` may be this proposal module's own
+        /// address.
+        #[serde(default)]
+        allow_self_dao: bool,
+        /// The window during which governance is frozen. See
+        /// `InstantiateMsg::blackout`.
+        blackout: Option<Blackout>,
+        /// If true, each message of a passed proposal is executed
+        /// on its own and messages that fail do not fail the
+        /// proposal's execution.
+        #[serde(default)]
+        best_effort_execution: bool,
+        /// How long after unstaking an address may not create or
+        /// vote on proposals. Has the same requirements as
+        /// `InstantiateMsg::unstake_cooldown`. If None, there is no
+        /// cooldown.
+        unstake_cooldown: Option<Duration>,
+        /// If true, only the proposer of a passed proposal may
+        /// execute it until its grace period has ended. Applies to
+        /// all outstanding and future proposals.
+        #[serde(default)]
+        proposer_must_execute: bool,
+        /// How long after a proposal expires only its proposer may
+        /// execute it when `proposer_must_execute` is set. If None,
+        /// only the proposer may ever execute it.
+        proposer_execution_grace_period: Option<Duration>,
+        /// If set, proposers must hold at least `min_count` NFTs of
+        /// the cw721 `collection` to create proposals. Applies to
+        /// proposals created after the config update.
+        proposer_nft_gate: Option<UncheckedProposerNftGate>,
+        /// A voting module queried for voting power only when
+        /// querying the DAO fails. See
+        /// `InstantiateMsg::fallback_voting_module` for the risks of
+        /// using one.
+        fallback_voting_module: Option<String>,
+        /// If true, proposals must be created with a non-empty
+        /// `discussion_url`. Applies to proposals created after the
+        /// config update.
+        #[serde(default)]
+        require_discussion_url: bool,
+        /// The portion of abstaining voting power that counts toward
+        /// quorum. See `InstantiateMsg::abstain_quorum_weight`.
+        /// Applies to proposals created after the config update.
+        abstain_quorum_weight: Option<PercentageThreshold>,
+        /// The second voting phase of proposals. See
+        /// `InstantiateMsg::confirmation_phase`. Applies to proposals
+        /// created after the config update.
+        confirmation_phase: Option<ConfirmationPhase>,
+        /// The timelock between proposals passing and being
+        /// executable. See `InstantiateMsg::execution_delay`. Applies
+        /// to all passed proposals, including those that passed before
+        /// the config update.
+        execution_delay: Option<Duration>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
     UpdatePreProposeInfo { info: PreProposeInfo },
+    /// Links this module to a sibling proposal module of the same
+    /// DAO, whose open proposal participation will be included in
+    /// the `CombinedParticipation {}` query. Setting this to None
+    /// removes the link. This does not affect how proposals are
+    /// tallied. Only the DAO may call this method.
+    UpdateLinkedModule { address: Option<String> },
+    /// Adds a contract to the audited contracts allowlist. Only the
+    /// DAO may call this method.
+    AddAuditedContract { address: String },
+    /// Removes a contract from the audited contracts allowlist. Only
+    /// the DAO may call this method.
+    RemoveAuditedContract { address: String },
     /// Adds an address as a consumer of proposal hooks. Consumers of
     /// proposal hooks have hook messages executed on them whenever
     /// the status of a proposal changes or a proposal is created. If
@@ -138,9 +387,64 @@ pub enum QueryMsg {
     /// Gets the proposal module's config.
     #[returns(crate::state::Config)]
     Config {},
+    /// Gets the module's current passing requirements in a form
+    /// shared by all proposal modules.
+    #[returns(::dao_voting::threshold::NormalizedThreshold)]
+    NormalizedThreshold {},
     /// Gets information about a proposal.
     #[returns(crate::query::ProposalResponse)]
     Proposal { proposal_id: u64 },
+    /// Gets the configuration that was in effect when a proposal was
+    /// created.
+    #[returns(crate::query::ProposalConfigResponse)]
+    ProposalConfig { proposal_id: u64 },
+    /// Gets how a proposal's total voting power is split between
+    /// power that voted yes or no, power that abstained, and power
+    /// that has not been cast.
+    #[returns(crate::query::TurnoutBreakdownResponse)]
+    TurnoutBreakdown { proposal_id: u64 },
+    /// Gets the additional voting power that must vote on a proposal
+    /// for it to reach its quorum. Zero if the quorum has been
+    /// reached or the proposal's threshold has no quorum.
+    #[returns(cosmwasm_std::Uint128)]
+    QuorumGap { proposal_id: u64 },
+    /// Gets the lead of a proposal's winning option over the other
+    /// as a percentage of its total voting power, allowing mandate
+    /// strength to be compared across proposals. Zero for ties and
+    /// None if the proposal's total voting power is zero.
+    #[returns(Option<cosmwasm_std::Decimal>)]
+    WinMarginPercent { proposal_id: u64 },
+    /// Gets a proposal's turnout averaged over its voting period,
+    /// weighting each level of turnout by how long it persisted. The
+    /// period runs from the proposal's creation until the earlier of
+    /// its expiration and the current block time; proposals that
+    /// expire at a height are measured until the current block
+    /// time. None if the proposal's total voting power is zero.
+    #[returns(Option<cosmwasm_std::Decimal>)]
+    TimeWeightedTurnout { proposal_id: u64 },
+    /// Lists the status transitions of a proposal in the order they
+    /// happened, along with the height and cause of each.
+    #[returns(Vec<crate::state::StatusTransition>)]
+    StatusHistory { proposal_id: u64 },
+    /// Gets whether each message of a proposal executed in
+    /// best-effort mode succeeded, along with the error or events it
+    /// produced, in execution order. Empty if the proposal has not
+    /// been executed in best-effort mode.
+    #[returns(Vec<crate::state::MessageExecution>)]
+    ExecutionReport { proposal_id: u64 },
+    /// Gets the ID of the proposal that executed the message sent to
+    /// CONTRACT whose JSON encoding has the hex encoded SHA-256 hash
+    /// MSG_HASH, or None if no proposal has. CONTRACT is the
+    /// recipient of bank sends, and the DAO for messages that are not
+    /// sent to a contract or account. If several proposals executed
+    /// the message the most recent is returned. Messages executed
+    /// before this index was added are not included.
+    #[returns(Option<u64>)]
+    MessageOrigin { contract: String, msg_hash: String },
+    /// Gets the minimum voting power an address must have to create
+    /// a proposal, or None if there is no minimum.
+    #[returns(Option<cosmwasm_std::Uint128>)]
+    MinProposerPower {},
     /// Lists all the proposals that have been cast in this
     /// module.
     #[returns(crate::query::ProposalListResponse)]
@@ -154,6 +458,92 @@ pub enum QueryMsg {
         /// returned.
         limit: Option<u64>,
     },
+    /// Lists summaries of all the proposals in this module in
+    /// ascending order of proposal ID. Summaries omit proposal
+    /// messages and tallies, making this cheaper than
+    /// `ListProposals` for list views.
+    #[returns(crate::query::ProposalSummariesResponse)]
+    ProposalSummaries {
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists the proposals created at or after `start_time` and
+    /// before `end_time` in ascending order of proposal ID. Proposals
+    /// created before creation times were recorded are never
+    /// included.
+    #[returns(crate::query::ProposalListResponse)]
+    ProposalsInRange {
+        /// The earliest creation time to include.
+        start_time: Timestamp,
+        /// The creation time to stop listing proposals before.
+        end_time: Timestamp,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists the proposals that may currently be closed among the
+    /// `limit` proposals with IDs after `start_after`, in ascending
+    /// order of proposal ID: rejected proposals and passed proposals
+    /// whose precondition does not hold. Lets keepers find proposals
+    /// to close without scanning every proposal client-side. Proposal
+    /// IDs are sequential, so every proposal is examined by advancing
+    /// `start_after` by `limit` up to `ProposalCount {}`.
+    #[returns(crate::query::ProposalListResponse)]
+    CloseableProposals {
+        /// The proposal ID to start examining proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to examine. If no limit
+        /// is set a max of 30 proposals will be examined. Limits
+        /// greater than 300 are treated as 300.
+        limit: Option<u64>,
+    },
+    /// Lists the open proposals that expire within WITHIN of the
+    /// current block among the `limit` proposals with IDs after
+    /// `start_after`, in ascending order of proposal ID. Lets bots
+    /// remind voters of proposals that are closing soon. Proposals
+    /// whose expiration is not in the same units as WITHIN are not
+    /// listed. Proposal IDs are sequential, so every proposal is
+    /// examined by advancing `start_after` by `limit` up to
+    /// `ProposalCount {}`.
+    #[returns(crate::query::ProposalListResponse)]
+    ExpiringProposals {
+        /// How far past the current block to look for expirations.
+        within: Duration,
+        /// The proposal ID to start examining proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to examine. If no limit
+        /// is set a max of 30 proposals will be examined. Limits
+        /// greater than 300 are treated as 300.
+        limit: Option<u64>,
+    },
+    /// Lists the proposals whose messages send native tokens (via
+    /// `BankMsg::Send`) or cw20 tokens (via cw20 `Transfer` or
+    /// `Send`) to RECIPIENT, along with the amounts sent, among the
+    /// `limit` proposals with IDs after `start_after`, in ascending
+    /// order of proposal ID. Proposal IDs are sequential, so every
+    /// proposal is scanned by advancing `start_after` by `limit` up
+    /// to `ProposalCount {}`.
+    #[returns(crate::query::ProposalsSendingToResponse)]
+    ProposalsSendingTo {
+        recipient: String,
+        /// The proposal ID to start scanning proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to scan. If no limit is
+        /// set a max of 30 proposals will be scanned. Limits greater
+        /// than 300 are treated as 300.
+        limit: Option<u64>,
+    },
+    /// Returns true if the proposal is open and its voting period has
+    /// not yet expired at the current block, false otherwise.
+    #[returns(bool)]
+    IsVotingOpen { proposal_id: u64 },
     /// Lists all of the proposals that have been cast in this module
     /// in decending order of proposal ID.
     #[returns(crate::query::ProposalListResponse)]
@@ -167,6 +557,15 @@ pub enum QueryMsg {
         /// returned.
         limit: Option<u64>,
     },
+    /// Returns whether VOTER may currently change their vote on a
+    /// proposal and, if they may not, why.
+    #[returns(crate::query::CanRevoteResponse)]
+    CanRevote { proposal_id: u64, voter: String },
+    /// Returns whether ADDRESS may currently vote on a proposal,
+    /// whether they have already voted on it, and their voting power
+    /// at the proposal's start height.
+    #[returns(crate::query::VoteStatusResponse)]
+    VoteStatus { proposal_id: u64, address: String },
     /// Returns a voters position on a propsal.
     #[returns(crate::query::VoteResponse)]
     GetVote { proposal_id: u64, voter: String },
@@ -182,10 +581,127 @@ pub enum QueryMsg {
         /// The maximum number of votes to return in response to this
         /// query. If no limit is specified a max of 30 are returned.
         limit: Option<u64>,
+        /// If true, votes are listed in reverse alphabetical order
+        /// and START_AFTER is the voter to start listing votes
+        /// before. Defaults to false.
+        reverse: Option<bool>,
+    },
+    /// Lists the addresses that have voted on a proposal without
+    /// their positions.
+    #[returns(crate::query::VoterListResponse)]
+    Voters {
+        /// The proposal to list the voters of.
+        proposal_id: u64,
+        /// The voter to start listing voters after. Ordering is done
+        /// alphabetically.
+        start_after: Option<String>,
+        /// The maximum number of voters to return in response to
+        /// this query. If no limit is specified a max of 30 are
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Groups the voters on a proposal into tiers by voting power and
+    /// returns the number of voters and their combined power for each
+    /// position in each tier. TIERS are the minimum voting power of
+    /// each tier in strictly ascending order, and at most 10 may be
+    /// given. Voters are counted in the highest tier whose minimum
+    /// their power reaches, and voters with less power than the
+    /// lowest tier's minimum are not counted.
+    #[returns(crate::query::VotesByTierResponse)]
+    VotesByTier {
+        proposal_id: u64,
+        tiers: Vec<Uint128>,
     },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
+    /// Lists the proposals that have been executed by this module
+    /// among the `limit` proposals with IDs after `start_after`, in
+    /// ascending order of proposal ID. Proposal IDs are sequential,
+    /// so every execution is listed by advancing `start_after` by
+    /// `limit` up to `ProposalCount {}`.
+    #[returns(crate::query::ExecutionHistoryResponse)]
+    ExecutionHistory {
+        /// The proposal ID to start examining proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to examine. If no limit
+        /// is set a max of 30 proposals will be examined. Limits
+        /// greater than 300 are treated as 300.
+        limit: Option<u64>,
+    },
+    /// Lists the proposals whose execution outcome is OUTCOME among
+    /// the `limit` proposals with IDs after `start_after`, in
+    /// ascending order of proposal ID. Proposal IDs are sequential,
+    /// so every proposal is examined by advancing `start_after` by
+    /// `limit` up to `ProposalCount {}`.
+    #[returns(crate::query::ProposalListResponse)]
+    ProposalsByExecutionOutcome {
+        /// The execution outcome to list proposals with.
+        outcome: crate::query::ExecutionOutcome,
+        /// The proposal ID to start examining proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to examine. If no limit
+        /// is set a max of 30 proposals will be examined. Limits
+        /// greater than 300 are treated as 300.
+        limit: Option<u64>,
+    },
+    /// Gets the voting participation across the open proposals among
+    /// the `limit` proposals of this module with IDs after
+    /// `start_after`. Proposal IDs are sequential, so participation
+    /// across all open proposals is the sum of consecutive pages up
+    /// to `ProposalCount {}`.
+    #[returns(::dao_voting::proposal::ParticipationResponse)]
+    Participation {
+        /// The proposal ID to start examining proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to examine. If no limit
+        /// is set a max of 30 proposals will be examined. Limits
+        /// greater than 300 are treated as 300.
+        limit: Option<u64>,
+    },
+    /// Gets statistics about the proposals created by PROPOSER.
+    #[returns(crate::query::ProposerStatsResponse)]
+    ProposerStats { proposer: String },
+    /// Gets the number of proposals VOTER has cast a ballot on. A
+    /// changed vote is only counted once. Returns `u64`.
+    #[returns(u64)]
+    VoterParticipationCount { voter: String },
+    /// Lists the proposals created by PROPOSER in ascending order of
+    /// proposal ID. Proposals created before this index was added
+    /// are not included, except for those migrated from v1.
+    #[returns(crate::query::ProposalListResponse)]
+    ProposalsByProposer {
+        proposer: String,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists the contracts in the audited contracts allowlist.
+    #[returns(Vec<::cosmwasm_std::Addr>)]
+    AuditedContracts {
+        start_after: Option<String>,
+        limit: Option<u64>,
+    },
+    /// Gets the sibling proposal module linked to this one, if any.
+    #[returns(Option<::cosmwasm_std::Addr>)]
+    LinkedModule {},
+    /// Gets the voting participation across the open proposals of
+    /// this module and its linked module combined, examining the
+    /// same page of proposal IDs in each as `Participation`
+    /// does. The linked module must support the `Participation`
+    /// query.
+    #[returns(::dao_voting::proposal::ParticipationResponse)]
+    CombinedParticipation {
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
+    /// Checks if VOTING_MODULE implements the voting module queries
+    /// this module relies on.
+    #[returns(crate::query::VotingModuleCompatibilityResponse)]
+    VotingModuleCompatible { voting_module: String },
     /// Gets the current proposal creation policy for this module.
     #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
     ProposalCreationPolicy {},
@@ -195,6 +711,11 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the module's config as a list of parameters, one per
+    /// config field, so that it may be displayed and edited without
+    /// knowledge of each field.
+    #[returns(Vec<crate::query::Parameter>)]
+    Parameters {},
 }
 
 #[cw_serde]
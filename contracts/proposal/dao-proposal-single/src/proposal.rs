@@ -1,8 +1,12 @@
-use crate::query::ProposalResponse;
-use crate::state::PROPOSAL_COUNT;
+use crate::query::{ExecutionOutcome, ProposalResponse};
+use crate::state::{ConfirmationPhase, SingleChoiceTiePolicy, PROPOSAL_COUNT};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, StdResult, Storage, Uint128};
-use cw_utils::Expiration;
+use cosmwasm_std::{
+    Addr, BlockInfo, CosmosMsg, Decimal, Deps, Empty, StdResult, Storage, Timestamp, Uint128,
+    Uint256,
+};
+use cw_utils::{Duration, Expiration};
+use dao_voting::precondition::CheckedPrecondition;
 use dao_voting::status::Status;
 use dao_voting::threshold::{PercentageThreshold, Threshold};
 use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, Votes};
@@ -34,6 +38,75 @@ pub struct SingleChoiceProposal {
     pub status: Status,
     pub votes: Votes,
     pub allow_revoting: bool,
+    /// The number of times execution of this proposal has been
+    /// attempted and failed.
+    #[serde(default)]
+    pub execution_attempts: u64,
+    /// The block height at which this proposal was executed. None if
+    /// the proposal has not been executed.
+    #[serde(default)]
+    pub execution_height: Option<u64>,
+    /// The number of this proposal's messages that have been
+    /// executed. Proposals executed in chunks remain passed until
+    /// all of their messages have been executed.
+    #[serde(default)]
+    pub executed_msgs: u64,
+    /// Whether only members could execute passed proposals when this
+    /// proposal was created. None for proposals created before this
+    /// was recorded. Execution permissions are always determined by
+    /// the module's current config.
+    #[serde(default)]
+    pub only_members_execute: Option<bool>,
+    /// The time at which this proposal was created. Zero for
+    /// proposals created before this was recorded, for example those
+    /// migrated from v1.
+    #[serde(default)]
+    pub created_at: Timestamp,
+    /// A condition that must hold for this proposal to be executed.
+    #[serde(default)]
+    pub precondition: Option<CheckedPrecondition>,
+    /// How this proposal is resolved if its yes and no votes are
+    /// exactly tied. None for proposals created before this was
+    /// recorded, whose ties are resolved by their threshold.
+    #[serde(default)]
+    pub tie_policy: Option<SingleChoiceTiePolicy>,
+    /// A reference to the thread where this proposal was discussed,
+    /// for example a forum post URL.
+    #[serde(default)]
+    pub discussion_url: Option<String>,
+    /// The portion of abstaining voting power that counts toward this
+    /// proposal's quorum. None if abstentions count in full, and for
+    /// proposals created before this was recorded.
+    #[serde(default)]
+    pub abstain_quorum_weight: Option<PercentageThreshold>,
+    /// The voting phase of proposals created with a confirmation
+    /// phase. None for proposals with a single voting phase.
+    #[serde(default)]
+    pub phase: Option<ProposalPhase>,
+    /// When this proposal passed, in the units (height or time) of its
+    /// expiration. None if it has not passed, and for proposals that
+    /// passed before this was recorded.
+    #[serde(default)]
+    pub passed_at: Option<Expiration>,
+}
+
+/// The voting phase of a proposal that must pass two rounds of
+/// voting. The proposal's votes, threshold, and expiration are always
+/// those of its current phase.
+#[cw_serde]
+pub enum ProposalPhase {
+    /// The initial round of voting. If it passes once its voting
+    /// period ends, the proposal advances to its CONFIRMATION phase.
+    /// Otherwise, it is rejected.
+    Intent { confirmation: ConfirmationPhase },
+    /// The second round of voting, which began when the intent phase
+    /// ended.
+    Confirmation {
+        /// The votes cast during the intent phase.
+        intent_votes: Votes,
+        /// The threshold the intent phase passed.
+        intent_threshold: Threshold,
+    },
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -59,9 +132,93 @@ impl SingleChoiceProposal {
         ProposalResponse { id, proposal: self }
     }
 
+    /// The percentage of the total voting power that has voted on
+    /// this proposal.
+    pub fn turnout(&self) -> Decimal {
+        if self.total_power.is_zero() {
+            Decimal::zero()
+        } else {
+            Decimal::from_ratio(self.votes.total(), self.total_power)
+        }
+    }
+
+    /// The voting power that counts toward this proposal's quorum:
+    /// the votes cast, with abstentions weighted by the proposal's
+    /// `abstain_quorum_weight`.
+    pub fn quorum_votes(&self) -> Uint128 {
+        match self.abstain_quorum_weight {
+            Some(PercentageThreshold::Percent(weight)) => {
+                self.votes.total() - self.votes.abstain + self.votes.abstain * weight
+            }
+            _ => self.votes.total(),
+        }
+    }
+
+    /// The additional voting power that must vote on this proposal
+    /// for it to reach its quorum. Zero if the quorum has been
+    /// reached or this proposal's threshold has no quorum.
+    pub fn quorum_gap(&self) -> StdResult<Uint128> {
+        let needed = match self.threshold {
+            Threshold::ThresholdQuorum { quorum, .. }
+            | Threshold::ThresholdQuorumVeto { quorum, .. } => match quorum {
+                PercentageThreshold::Majority {} => {
+                    self.total_power.multiply_ratio(1u128, 2u128) + Uint128::one()
+                }
+                PercentageThreshold::Percent(percent) => {
+                    // Round up, as the quorum is reached once
+                    // `votes >= total_power * percent`.
+                    let denominator = Uint256::from(10u64).pow(percent.decimal_places());
+                    let needed = Uint256::from(self.total_power) * Uint256::from(percent.atomics());
+                    ((needed + denominator - Uint256::one()) / denominator).try_into()?
+                }
+            },
+            _ => return Ok(Uint128::zero()),
+        };
+        Ok(needed.saturating_sub(self.quorum_votes()))
+    }
+
+    /// The lead of the winning option (yes or no) over the other as
+    /// a percentage of this proposal's total voting power. Zero if
+    /// yes and no are tied. None if the total voting power is zero.
+    /// Abstain votes do not compete for the win and are ignored.
+    pub fn win_margin_percent(&self) -> Option<Decimal> {
+        if self.total_power.is_zero() {
+            return None;
+        }
+        let Votes { yes, no, .. } = self.votes;
+        let margin = yes.max(no) - yes.min(no);
+        Some(Decimal::from_ratio(margin, self.total_power))
+    }
+
+    /// Returns the messages that will be executed by the next
+    /// execution of this proposal.
+    pub fn next_chunk(&self, max_msgs_per_execution: Option<u64>) -> &[CosmosMsg<Empty>] {
+        let remaining = &self.msgs[self.executed_msgs as usize..];
+        match max_msgs_per_execution {
+            Some(max) => &remaining[..remaining.len().min(max as usize)],
+            None => remaining,
+        }
+    }
+
+    /// Returns true if this proposal has no precondition or its
+    /// precondition holds at the current block.
+    pub fn is_precondition_met(&self, deps: Deps) -> StdResult<bool> {
+        match &self.precondition {
+            Some(precondition) => precondition.is_met(deps),
+            None => Ok(true),
+        }
+    }
+
     /// Gets the current status of the proposal.
     pub fn current_status(&self, block: &BlockInfo) -> Status {
-        if self.status == Status::Open && self.is_passed(block) {
+        if self.is_intent_phase_passed(block) {
+            let mut confirmation = self.clone();
+            confirmation.advance_phase();
+            confirmation.current_status(block)
+        } else if self.status == Status::Open
+            && self.is_passed(block)
+            && !matches!(self.phase, Some(ProposalPhase::Intent { .. }))
+        {
             Status::Passed
         } else if self.status == Status::Open
             && (self.expiration.is_expired(block) || self.is_rejected(block))
@@ -72,12 +229,93 @@ impl SingleChoiceProposal {
         }
     }
 
+    /// Gets the outcome of executing this proposal at the current
+    /// block. None if the proposal has not passed, or was closed
+    /// without being executed.
+    pub fn execution_outcome(&self, block: &BlockInfo) -> Option<ExecutionOutcome> {
+        match self.current_status(block) {
+            Status::Executed => Some(ExecutionOutcome::Succeeded),
+            Status::ExecutionFailed => Some(ExecutionOutcome::Failed),
+            Status::Passed => Some(ExecutionOutcome::Pending),
+            _ => None,
+        }
+    }
+
     /// Sets a proposals status to its current status.
     pub fn update_status(&mut self, block: &BlockInfo) {
+        if self.is_intent_phase_passed(block) {
+            self.advance_phase();
+        }
         let new_status = self.current_status(block);
+        if self.status == Status::Open && new_status == Status::Passed {
+            self.passed_at = Some(self.observed_pass(block));
+        }
         self.status = new_status
     }
 
+    /// Returns when this proposal, observed to have passed at BLOCK,
+    /// passed. Votes are always followed by a status update that
+    /// records when they pass a proposal, so a proposal observed to
+    /// have passed otherwise passed when its voting period or min
+    /// voting period ended.
+    fn observed_pass(&self, block: &BlockInfo) -> Expiration {
+        if self.expiration.is_expired(block) {
+            self.expiration
+        } else {
+            match self.min_voting_period {
+                Some(min) if min.is_expired(block) => min,
+                _ => self.at_block(block),
+            }
+        }
+    }
+
+    /// Returns BLOCK as an expiration in the units of this proposal's
+    /// expiration.
+    pub fn at_block(&self, block: &BlockInfo) -> Expiration {
+        match self.expiration {
+            Expiration::AtHeight(_) => Expiration::AtHeight(block.height),
+            _ => Expiration::AtTime(block.time),
+        }
+    }
+
+    /// Returns true if this proposal is in its intent phase, the
+    /// intent phase has ended, and it passed. Intent phases may not
+    /// pass early so that the confirmation phase always begins when
+    /// the intent phase's voting period ends.
+    fn is_intent_phase_passed(&self, block: &BlockInfo) -> bool {
+        matches!(self.phase, Some(ProposalPhase::Intent { .. }))
+            && self.status == Status::Open
+            && self.expiration.is_expired(block)
+            && self.is_passed(block)
+    }
+
+    /// Moves a proposal in its intent phase into its confirmation
+    /// phase. The votes of the intent phase are set aside and voting
+    /// restarts with the confirmation phase's threshold until its
+    /// voting period, which begins when the intent phase's ended,
+    /// expires.
+    fn advance_phase(&mut self) {
+        let Some(ProposalPhase::Intent { confirmation }) = self.phase.take() else {
+            return;
+        };
+        self.expiration = match (self.expiration, confirmation.voting_period) {
+            (Expiration::AtHeight(height), Duration::Height(blocks)) => {
+                Expiration::AtHeight(height + blocks)
+            }
+            (Expiration::AtTime(time), Duration::Time(seconds)) => {
+                Expiration::AtTime(time.plus_seconds(seconds))
+            }
+            // Confirmation phases must have the same units as the
+            // module's voting period, so this is unreachable.
+            (expiration, _) => expiration,
+        };
+        self.phase = Some(ProposalPhase::Confirmation {
+            intent_votes: std::mem::replace(&mut self.votes, Votes::zero()),
+            intent_threshold: std::mem::replace(&mut self.threshold, confirmation.threshold),
+        });
+        self.min_voting_period = None;
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail).
@@ -101,26 +339,47 @@ impl SingleChoiceProposal {
         match self.threshold {
             Threshold::AbsolutePercentage { percentage } => {
                 let options = self.total_power - self.votes.abstain;
-                does_vote_count_pass(self.votes.yes, options, percentage)
+                self.does_vote_count_pass(options, percentage)
             }
             Threshold::ThresholdQuorum { threshold, quorum } => {
-                if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
-                    return false;
-                }
-
+                self.is_threshold_quorum_passed(block, threshold, quorum)
+            }
+            Threshold::ThresholdQuorumVeto {
+                threshold,
+                quorum,
+                veto,
+            } => {
+                // Until voting has closed all outstanding voting
+                // power may still vote no and veto the proposal.
+                let possible_no = if self.expiration.is_expired(block) {
+                    self.votes.no
+                } else {
+                    self.votes.no + self.total_power - self.votes.total()
+                };
+                !does_vote_count_pass(possible_no, self.total_power, veto)
+                    && self.is_threshold_quorum_passed(block, threshold, quorum)
+            }
+            Threshold::AbsoluteCount { threshold } => self.votes.yes >= threshold,
+            Threshold::ThresholdAndAbsolute {
+                percentage,
+                absolute,
+            } => {
+                let options = self.total_power - self.votes.abstain;
+                self.votes.yes >= absolute && self.does_vote_count_pass(options, percentage)
+            }
+            Threshold::NonAbstainPercentage { percentage } => {
                 if self.expiration.is_expired(block) {
-                    // If the quorum is met and the proposal is
-                    // expired the number of votes needed to pass a
-                    // proposal is compared to the number of votes on
-                    // the proposal.
-                    let options = self.votes.total() - self.votes.abstain;
-                    does_vote_count_pass(self.votes.yes, options, threshold)
+                    // Once voting has closed only yes and no votes
+                    // are counted.
+                    let options = self.votes.yes + self.votes.no;
+                    self.does_vote_count_pass(options, percentage)
                 } else {
+                    // Until then all outstanding voting power may
+                    // still vote no.
                     let options = self.total_power - self.votes.abstain;
-                    does_vote_count_pass(self.votes.yes, options, threshold)
+                    self.does_vote_count_pass(options, percentage)
                 }
             }
-            Threshold::AbsoluteCount { threshold } => self.votes.yes >= threshold,
         }
     }
 
@@ -136,11 +395,99 @@ impl SingleChoiceProposal {
         match self.threshold {
             Threshold::AbsolutePercentage {
                 percentage: percentage_needed,
+            } => self.is_absolute_percentage_rejected(percentage_needed),
+            Threshold::ThresholdQuorum { threshold, quorum } => {
+                self.is_threshold_quorum_rejected(block, threshold, quorum)
+            }
+            Threshold::ThresholdQuorumVeto {
+                threshold,
+                quorum,
+                veto,
             } => {
-                let options = self.total_power - self.votes.abstain;
+                does_vote_count_pass(self.votes.no, self.total_power, veto)
+                    || self.is_threshold_quorum_rejected(block, threshold, quorum)
+            }
+            Threshold::AbsoluteCount { threshold } => {
+                // If all the outstanding votes voting yes would not
+                // cause this proposal to pass then it is rejected.
+                let outstanding_votes = self.total_power - self.votes.total();
+                self.votes.yes + outstanding_votes < threshold
+            }
+            Threshold::ThresholdAndAbsolute {
+                percentage,
+                absolute,
+            } => {
+                // Rejected if either requirement can no longer be
+                // met.
+                let outstanding_votes = self.total_power - self.votes.total();
+                self.votes.yes + outstanding_votes < absolute
+                    || self.is_absolute_percentage_rejected(percentage)
+            }
+            Threshold::NonAbstainPercentage { percentage } => {
+                if self.expiration.is_expired(block) {
+                    // Rejected if the yes and no votes cast do not
+                    // meet the threshold, including when none were
+                    // cast.
+                    let options = self.votes.yes + self.votes.no;
+                    !self.does_vote_count_pass(options, percentage)
+                } else {
+                    // Rejected if all outstanding voting power voting
+                    // yes would not meet the threshold.
+                    self.is_absolute_percentage_rejected(percentage)
+                }
+            }
+        }
+    }
+
+    /// Returns true if a proposal with a `ThresholdQuorum` threshold
+    /// of THRESHOLD and QUORUM has passed.
+    fn is_threshold_quorum_passed(
+        &self,
+        block: &BlockInfo,
+        threshold: PercentageThreshold,
+        quorum: PercentageThreshold,
+    ) -> bool {
+        if !does_vote_count_pass(self.quorum_votes(), self.total_power, quorum) {
+            return false;
+        }
+
+        if self.expiration.is_expired(block) {
+            // If the quorum is met and the proposal is
+            // expired the number of votes needed to pass a
+            // proposal is compared to the number of votes on
+            // the proposal.
+            let options = self.votes.total() - self.votes.abstain;
+            self.does_vote_count_pass(options, threshold)
+        } else {
+            let options = self.total_power - self.votes.abstain;
+            self.does_vote_count_pass(options, threshold)
+        }
+    }
+
+    /// Returns true if a proposal with a `ThresholdQuorum` threshold
+    /// of THRESHOLD and QUORUM has been rejected.
+    fn is_threshold_quorum_rejected(
+        &self,
+        block: &BlockInfo,
+        threshold: PercentageThreshold,
+        quorum: PercentageThreshold,
+    ) -> bool {
+        match (
+            does_vote_count_pass(self.quorum_votes(), self.total_power, quorum),
+            self.expiration.is_expired(block),
+        ) {
+            // Has met quorum and is expired.
+            (true, true) => {
+                // => consider only votes cast and see if no
+                //    votes meet threshold.
+                let options = self.votes.total() - self.votes.abstain;
+
+                if let Some(passes) = self.tie_outcome(options, threshold) {
+                    return !passes;
+                }
 
                 // If there is a 100% passing threshold..
-                if percentage_needed == PercentageThreshold::Percent(Decimal::percent(100)) {
+                if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
                     if options == Uint128::zero() {
                         // and there are no possible votes (zero
                         // voting power or all abstain), then this
@@ -150,91 +497,117 @@ impl SingleChoiceProposal {
                         // and there are possible votes, then this is
                         // rejected if there is a single no vote.
                         //
-                        // We need this check becuase otherwise when
-                        // we invert the threshold (`Decimal::one() -
-                        // threshold`) we get a 0% requirement for no
-                        // votes. Zero no votes do indeed meet a 0%
-                        // threshold.
+                        // We need this check becuase
+                        // otherwise when we invert the
+                        // threshold (`Decimal::one() -
+                        // threshold`) we get a 0% requirement
+                        // for no votes. Zero no votes do
+                        // indeed meet a 0% threshold.
                         return self.votes.no >= Uint128::new(1);
                     }
                 }
-
-                does_vote_count_fail(self.votes.no, options, percentage_needed)
+                does_vote_count_fail(self.votes.no, options, threshold)
             }
-            Threshold::ThresholdQuorum { threshold, quorum } => {
-                match (
-                    does_vote_count_pass(self.votes.total(), self.total_power, quorum),
-                    self.expiration.is_expired(block),
-                ) {
-                    // Has met quorum and is expired.
-                    (true, true) => {
-                        // => consider only votes cast and see if no
-                        //    votes meet threshold.
-                        let options = self.votes.total() - self.votes.abstain;
-
-                        // If there is a 100% passing threshold..
-                        if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
-                            if options == Uint128::zero() {
-                                // and there are no possible votes (zero
-                                // voting power or all abstain), then this
-                                // proposal has been rejected.
-                                return true;
-                            } else {
-                                // and there are possible votes, then this is
-                                // rejected if there is a single no vote.
-                                //
-                                // We need this check becuase
-                                // otherwise when we invert the
-                                // threshold (`Decimal::one() -
-                                // threshold`) we get a 0% requirement
-                                // for no votes. Zero no votes do
-                                // indeed meet a 0% threshold.
-                                return self.votes.no >= Uint128::new(1);
-                            }
-                        }
-                        does_vote_count_fail(self.votes.no, options, threshold)
-                    }
-                    // Has met quorum and is not expired.
-                    // | Hasn't met quorum and is not expired.
-                    (true, false) | (false, false) => {
-                        // => consider all possible votes and see if
-                        //    no votes meet threshold.
-                        let options = self.total_power - self.votes.abstain;
-
-                        // If there is a 100% passing threshold..
-                        if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
-                            if options == Uint128::zero() {
-                                // and there are no possible votes (zero
-                                // voting power or all abstain), then this
-                                // proposal has been rejected.
-                                return true;
-                            } else {
-                                // and there are possible votes, then this is
-                                // rejected if there is a single no vote.
-                                //
-                                // We need this check because otherwise
-                                // when we invert the threshold
-                                // (`Decimal::one() - threshold`) we
-                                // get a 0% requirement for no
-                                // votes. Zero no votes do indeed meet
-                                // a 0% threshold.
-                                return self.votes.no >= Uint128::new(1);
-                            }
-                        }
-
-                        does_vote_count_fail(self.votes.no, options, threshold)
+            // Has met quorum and is not expired.
+            // | Hasn't met quorum and is not expired.
+            (true, false) | (false, false) => {
+                // => consider all possible votes and see if
+                //    no votes meet threshold.
+                let options = self.total_power - self.votes.abstain;
+
+                if let Some(passes) = self.tie_outcome(options, threshold) {
+                    return !passes;
+                }
+
+                // If there is a 100% passing threshold..
+                if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
+                    if options == Uint128::zero() {
+                        // and there are no possible votes (zero
+                        // voting power or all abstain), then this
+                        // proposal has been rejected.
+                        return true;
+                    } else {
+                        // and there are possible votes, then this is
+                        // rejected if there is a single no vote.
+                        //
+                        // We need this check because otherwise
+                        // when we invert the threshold
+                        // (`Decimal::one() - threshold`) we
+                        // get a 0% requirement for no
+                        // votes. Zero no votes do indeed meet
+                        // a 0% threshold.
+                        return self.votes.no >= Uint128::new(1);
                     }
-                    // Hasn't met quorum requirement and voting has closed => rejected.
-                    (false, true) => true,
                 }
+
+                does_vote_count_fail(self.votes.no, options, threshold)
             }
-            Threshold::AbsoluteCount { threshold } => {
-                // If all the outstanding votes voting yes would not
-                // cause this proposal to pass then it is rejected.
-                let outstanding_votes = self.total_power - self.votes.total();
-                self.votes.yes + outstanding_votes < threshold
+            // Hasn't met quorum requirement and voting has closed => rejected.
+            (false, true) => true,
+        }
+    }
+
+    /// Returns true if enough no votes have been cast that
+    /// `percentage_needed` of the total weight can no longer vote
+    /// yes.
+    fn is_absolute_percentage_rejected(&self, percentage_needed: PercentageThreshold) -> bool {
+        let options = self.total_power - self.votes.abstain;
+
+        if let Some(passes) = self.tie_outcome(options, percentage_needed) {
+            return !passes;
+        }
+
+        // If there is a 100% passing threshold..
+        if percentage_needed == PercentageThreshold::Percent(Decimal::percent(100)) {
+            if options == Uint128::zero() {
+                // and there are no possible votes (zero
+                // voting power or all abstain), then this
+                // proposal has been rejected.
+                return true;
+            } else {
+                // and there are possible votes, then this is
+                // rejected if there is a single no vote.
+                //
+                // We need this check becuase otherwise when
+                // we invert the threshold (`Decimal::one() -
+                // threshold`) we get a 0% requirement for no
+                // votes. Zero no votes do indeed meet a 0%
+                // threshold.
+                return self.votes.no >= Uint128::new(1);
             }
         }
+
+        does_vote_count_fail(self.votes.no, options, percentage_needed)
+    }
+
+    /// Returns true if yes votes make up `percent` of `options`,
+    /// resolving exact ties according to this proposal's tie policy.
+    fn does_vote_count_pass(&self, options: Uint128, percent: PercentageThreshold) -> bool {
+        match self.tie_outcome(options, percent) {
+            Some(passes) => passes,
+            None => does_vote_count_pass(self.votes.yes, options, percent),
+        }
+    }
+
+    /// If yes and no votes are exactly tied, together make up all of
+    /// `options`, and `percent` requires half of `options` to vote
+    /// yes, returns whether the tie passes under this proposal's tie
+    /// policy. Returns None otherwise, including for proposals
+    /// without a tie policy.
+    fn tie_outcome(&self, options: Uint128, percent: PercentageThreshold) -> Option<bool> {
+        let tie_policy = self.tie_policy?;
+        let is_half = match percent {
+            PercentageThreshold::Majority {} => true,
+            PercentageThreshold::Percent(percent) => percent == Decimal::percent(50),
+        };
+        let is_tie = !self.votes.yes.is_zero()
+            && self.votes.yes == self.votes.no
+            && self.votes.yes + self.votes.no == options;
+        if is_half && is_tie {
+            Some(tie_policy == SingleChoiceTiePolicy::Pass)
+        } else {
+            None
+        }
     }
 }
 
@@ -277,6 +650,17 @@ mod test {
             threshold,
             total_power,
             votes,
+            execution_attempts: 0,
+            execution_height: None,
+            executed_msgs: 0,
+            only_members_execute: None,
+            created_at: Timestamp::default(),
+            precondition: None,
+            tie_policy: None,
+            discussion_url: None,
+            abstain_quorum_weight: None,
+            phase: None,
+            passed_at: None,
         };
         (prop, block)
     }
@@ -564,6 +948,344 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_threshold_and_absolute() {
+        let threshold = Threshold::ThresholdAndAbsolute {
+            percentage: PercentageThreshold::Percent(Decimal::percent(60)),
+            absolute: Uint128::new(1_000_000),
+        };
+
+        // 90% yes but below the absolute minimum. Not passed, and as
+        // all voting power has been used, rejected.
+        let votes = Votes {
+            yes: Uint128::new(900_000),
+            no: Uint128::new(100_000),
+            abstain: Uint128::zero(),
+        };
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(1_000_000),
+            false,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold.clone(),
+            votes,
+            Uint128::new(1_000_000),
+            false,
+            true,
+            false
+        ));
+
+        // Meets the absolute minimum but only 50% yes. Not passed,
+        // and rejected once enough no votes are cast.
+        let votes = Votes {
+            yes: Uint128::new(1_000_000),
+            no: Uint128::new(1_000_000),
+            abstain: Uint128::zero(),
+        };
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(2_000_000),
+            false,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold.clone(),
+            votes,
+            Uint128::new(2_000_000),
+            false,
+            true,
+            false
+        ));
+
+        // Meets both.
+        let votes = Votes {
+            yes: Uint128::new(1_200_000),
+            no: Uint128::new(800_000),
+            abstain: Uint128::zero(),
+        };
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(2_000_000),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold,
+            votes,
+            Uint128::new(2_000_000),
+            false,
+            true,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_threshold_quorum_veto() {
+        let threshold = Threshold::ThresholdQuorumVeto {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+            veto: PercentageThreshold::Percent(Decimal::percent(30)),
+        };
+
+        // Meets the threshold and quorum, but 35% of voting power has
+        // voted no. Vetoed.
+        let votes = Votes {
+            yes: Uint128::new(65),
+            no: Uint128::new(35),
+            abstain: Uint128::zero(),
+        };
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold.clone(),
+            votes,
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // A majority has voted yes, but the outstanding 40% may still
+        // veto. Neither passed nor rejected.
+        let votes = Votes {
+            yes: Uint128::new(60),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            false
+        ));
+
+        // Once expired the outstanding power can no longer veto.
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes,
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // The outstanding 25% can not reach the veto. Passes early.
+        let votes = Votes {
+            yes: Uint128::new(75),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+        assert!(check_is_passed(
+            threshold,
+            votes,
+            Uint128::new(100),
+            false,
+            true,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_win_margin_percent() {
+        let threshold = Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        };
+        // 15 of 200 voting power.
+        let votes = Votes {
+            yes: Uint128::new(25),
+            no: Uint128::new(40),
+            abstain: Uint128::new(30),
+        };
+        let (prop, _) = setup_prop(
+            threshold.clone(),
+            votes,
+            Uint128::new(200),
+            false,
+            true,
+            false,
+        );
+        assert_eq!(prop.win_margin_percent(), Some(Decimal::permille(75)));
+
+        // Ties have no margin.
+        let votes = Votes {
+            yes: Uint128::new(40),
+            no: Uint128::new(40),
+            abstain: Uint128::zero(),
+        };
+        let (prop, _) = setup_prop(
+            threshold.clone(),
+            votes,
+            Uint128::new(100),
+            false,
+            true,
+            false,
+        );
+        assert_eq!(prop.win_margin_percent(), Some(Decimal::zero()));
+
+        // Without voting power there is nothing to normalize by.
+        let (prop, _) = setup_prop(
+            threshold,
+            Votes::zero(),
+            Uint128::zero(),
+            false,
+            true,
+            false,
+        );
+        assert_eq!(prop.win_margin_percent(), None);
+    }
+
+    #[test]
+    fn test_abstain_quorum_weight() {
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(35)),
+        };
+        // 40% of voting power has voted, half of it abstaining.
+        let votes = Votes {
+            yes: Uint128::new(15),
+            no: Uint128::new(5),
+            abstain: Uint128::new(20),
+        };
+        let (mut prop, block) = setup_prop(threshold, votes, Uint128::new(100), true, true, false);
+
+        // At full weight the abstentions carry the proposal past
+        // quorum.
+        assert_eq!(prop.quorum_votes(), Uint128::new(40));
+        assert_eq!(prop.quorum_gap().unwrap(), Uint128::zero());
+        assert!(prop.is_passed(&block));
+
+        // At half weight they do not.
+        prop.abstain_quorum_weight = Some(PercentageThreshold::Percent(Decimal::percent(50)));
+        assert_eq!(prop.quorum_votes(), Uint128::new(30));
+        assert_eq!(prop.quorum_gap().unwrap(), Uint128::new(5));
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+
+        // Turnout is unaffected.
+        assert_eq!(prop.turnout(), Decimal::percent(40));
+    }
+
+    #[test]
+    fn test_non_abstain_percentage() {
+        let threshold = Threshold::NonAbstainPercentage {
+            percentage: PercentageThreshold::Percent(Decimal::percent(67)),
+        };
+
+        // Most voting power abstains but 70% of those expressing an
+        // opinion vote yes. Passed once voting closes.
+        let votes = Votes {
+            yes: Uint128::new(7),
+            no: Uint128::new(3),
+            abstain: Uint128::new(90),
+        };
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // Before voting closes the outstanding voting power could
+        // still vote no, so the proposal is neither passed nor
+        // rejected.
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(110),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            votes,
+            Uint128::new(110),
+            false,
+            true,
+            false
+        ));
+
+        // 60% of those expressing an opinion vote yes. Rejected.
+        let votes = Votes {
+            yes: Uint128::new(6),
+            no: Uint128::new(4),
+            abstain: Uint128::new(90),
+        };
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold.clone(),
+            votes,
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // No one expressed an opinion. Rejected.
+        let votes = Votes {
+            yes: Uint128::zero(),
+            no: Uint128::zero(),
+            abstain: Uint128::new(100),
+        };
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold,
+            votes,
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+    }
+
     /// Tests that revoting works as expected with an absolute count
     /// style threshold.
     #[test]
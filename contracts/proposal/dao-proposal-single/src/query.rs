@@ -1,7 +1,9 @@
 use crate::proposal::SingleChoiceProposal;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use dao_voting::voting::Vote;
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use cw2::ContractVersion;
+use cw_utils::Expiration;
+use dao_voting::{deposit::CheckedDepositInfo, status::Status, threshold::Threshold, voting::Vote};
 
 /// Information about a proposal returned by proposal queries.
 #[cw_serde]
@@ -37,9 +39,206 @@ pub struct VoteListResponse {
     pub votes: Vec<VoteInfo>,
 }
 
+/// The addresses that have voted on a proposal.
+#[cw_serde]
+pub struct VoterListResponse {
+    pub voters: Vec<Addr>,
+}
+
+/// The number of voters in a tier with a position and their combined
+/// voting power.
+#[cw_serde]
+#[derive(Default)]
+pub struct TierTally {
+    pub count: u64,
+    pub power: Uint128,
+}
+
+/// The votes cast on a proposal by voters in a tier.
+#[cw_serde]
+pub struct TierVotes {
+    /// The minimum voting power of voters in this tier.
+    pub min_power: Uint128,
+    pub yes: TierTally,
+    pub no: TierTally,
+    pub abstain: TierTally,
+}
+
+/// The votes cast on a proposal grouped by the voting power of their
+/// voters.
+#[cw_serde]
+pub struct VotesByTierResponse {
+    /// The tiers in ascending order of minimum voting power.
+    pub tiers: Vec<TierVotes>,
+}
+
 /// A list of proposals returned by `ListProposals` and
 /// `ReverseProposals`.
 #[cw_serde]
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// Information about a proposal that has been executed.
+#[cw_serde]
+pub struct ExecutionInfo {
+    /// The ID of the executed proposal.
+    pub id: u64,
+    pub title: String,
+    /// The block height at which the proposal was executed.
+    pub execution_height: u64,
+}
+
+/// The outcome of executing a passed proposal.
+#[cw_serde]
+#[derive(Copy)]
+pub enum ExecutionOutcome {
+    /// The proposal was executed.
+    Succeeded,
+    /// The proposal's messages failed to execute.
+    Failed,
+    /// The proposal has passed but has not yet been executed, or its
+    /// execution has not completed.
+    Pending,
+}
+
+/// A list of executed proposals returned by `ExecutionHistory`.
+#[cw_serde]
+pub struct ExecutionHistoryResponse {
+    pub executions: Vec<ExecutionInfo>,
+}
+
+/// Statistics about the proposals created by a proposer.
+#[cw_serde]
+pub struct ProposerStatsResponse {
+    /// The number of proposals the proposer has created.
+    pub proposals: u64,
+    /// The number of the proposer's proposals that were executed.
+    pub executed: u64,
+    /// The number of the proposer's proposals whose execution failed.
+    pub execution_failed: u64,
+    /// The number of the proposer's proposals that were closed.
+    pub closed: u64,
+    /// The average percentage of voting power that voted on the
+    /// proposer's completed proposals.
+    pub average_turnout: Decimal,
+}
+
+/// The configuration that was in effect when a proposal was created.
+#[cw_serde]
+pub struct ProposalConfigResponse {
+    /// The threshold the proposal must reach to pass.
+    pub threshold: Threshold,
+    /// Whether votes on the proposal may be changed.
+    pub allow_revoting: bool,
+    /// Whether only members could execute passed proposals when the
+    /// proposal was created. None if this was not recorded.
+    pub only_members_execute: Option<bool>,
+    /// The deposit paid to create the proposal, if any, as reported
+    /// by the module's current pre-propose module.
+    pub deposit_info: Option<CheckedDepositInfo>,
+}
+
+/// How a proposal's total voting power has been used.
+/// `voted_power + abstained_power + uncast_power == total_power`.
+#[cw_serde]
+pub struct TurnoutBreakdownResponse {
+    /// The voting power that voted yes or no.
+    pub voted_power: Uint128,
+    /// The voting power that voted abstain.
+    pub abstained_power: Uint128,
+    /// The voting power that has not been cast.
+    pub uncast_power: Uint128,
+    /// The total voting power at the proposal's start height.
+    pub total_power: Uint128,
+}
+
+/// Which of the voting module queries used by this module a contract
+/// responds to.
+#[cw_serde]
+pub struct VotingModuleCompatibilityResponse {
+    /// The version reported by the contract's `Info {}` query, or
+    /// None if it did not respond.
+    pub info: Option<ContractVersion>,
+    /// Whether the contract responds to `VotingPowerAtHeight`.
+    pub voting_power_at_height: bool,
+    /// Whether the contract responds to `TotalPowerAtHeight`.
+    pub total_power_at_height: bool,
+    /// Whether the contract responds to `Dao {}`.
+    pub dao: bool,
+    /// True if the contract responds to all of the above queries.
+    pub compatible: bool,
+}
+
+/// Whether a voter may currently change their vote on a proposal.
+#[cw_serde]
+pub struct CanRevoteResponse {
+    pub can_revote: bool,
+    /// Why the voter may not revote. None if they may.
+    pub reason: Option<String>,
+}
+
+/// Whether an address may currently vote on a proposal.
+#[cw_serde]
+pub struct VoteStatusResponse {
+    /// True if a vote cast by the address would be accepted: the
+    /// proposal has not expired, the address had voting power when
+    /// the proposal was created, the address is not in an unstake
+    /// cooldown, and it has not voted or the proposal allows
+    /// revoting.
+    pub can_vote: bool,
+    pub has_voted: bool,
+    /// The address's voting power at the proposal's start height.
+    pub power: Uint128,
+}
+
+/// An amount of a cw20 token.
+#[cw_serde]
+pub struct Cw20Amount {
+    /// The address of the cw20 token contract.
+    pub token: String,
+    pub amount: Uint128,
+}
+
+/// The tokens a proposal's messages send to an address.
+#[cw_serde]
+pub struct ProposalSends {
+    pub proposal_id: u64,
+    /// Native tokens sent by `BankMsg::Send` messages.
+    pub native: Vec<Coin>,
+    /// cw20 tokens sent by cw20 `Transfer` and `Send` messages.
+    pub cw20: Vec<Cw20Amount>,
+}
+
+#[cw_serde]
+pub struct ProposalsSendingToResponse {
+    pub proposals: Vec<ProposalSends>,
+}
+
+/// A compact description of a proposal for list views. Omits the
+/// proposal's messages and tally.
+#[cw_serde]
+pub struct ProposalSummary {
+    pub id: u64,
+    pub title: String,
+    /// The proposal's status at the current block.
+    pub status: Status,
+    pub proposer: Addr,
+    pub expiration: Expiration,
+}
+
+#[cw_serde]
+pub struct ProposalSummariesResponse {
+    pub proposals: Vec<ProposalSummary>,
+}
+
+/// A single field of the module's config.
+#[cw_serde]
+pub struct Parameter {
+    /// The name of the config field.
+    pub key: String,
+    /// The JSON encoded value of the field.
+    pub value: String,
+    /// True if the DAO may change the field with `UpdateConfig`.
+    pub mutable: bool,
+}
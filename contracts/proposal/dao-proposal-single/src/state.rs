@@ -1,9 +1,15 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Empty, Event, StdResult, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
-use dao_voting::{pre_propose::ProposalCreationPolicy, threshold::Threshold, voting::Vote};
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy,
+    status::Status,
+    text_limits::ProposalTextLimits,
+    threshold::{PercentageThreshold, Threshold},
+    voting::Vote,
+};
 
 use crate::proposal::SingleChoiceProposal;
 
@@ -21,6 +27,140 @@ pub struct Ballot {
     #[serde(default)]
     pub rationale: Option<String>,
 }
+
+/// A change in a proposal's status.
+#[cw_serde]
+pub struct StatusTransition {
+    /// The status the proposal moved to.
+    pub status: Status,
+    /// The block height at which the transition happened.
+    pub height: u64,
+    /// The action that caused the transition, for example `vote`.
+    pub cause: String,
+}
+
+/// Statistics about the proposals created by a proposer.
+#[cw_serde]
+#[derive(Default)]
+pub struct ProposerStats {
+    /// The number of proposals the proposer has created.
+    pub proposals: u64,
+    /// The number of the proposer's proposals that were executed.
+    pub executed: u64,
+    /// The number of the proposer's proposals whose execution failed.
+    pub execution_failed: u64,
+    /// The number of the proposer's proposals that were closed.
+    pub closed: u64,
+    /// The sum of the turnout of the proposer's completed proposals.
+    pub total_turnout: Decimal,
+}
+
+impl ProposerStats {
+    /// The number of the proposer's proposals that have completed.
+    pub fn completed(&self) -> u64 {
+        self.executed + self.execution_failed + self.closed
+    }
+
+    fn count_mut(&mut self, status: Status) -> Option<&mut u64> {
+        match status {
+            Status::Executed => Some(&mut self.executed),
+            Status::ExecutionFailed => Some(&mut self.execution_failed),
+            Status::Closed => Some(&mut self.closed),
+            _ => None,
+        }
+    }
+
+    /// Records that a proposal with TURNOUT completed with STATUS. If
+    /// REPLACES is set, the proposal was previously recorded as
+    /// completing with that status and only its status is updated.
+    pub fn record_completion(
+        &mut self,
+        status: Status,
+        turnout: Decimal,
+        replaces: Option<Status>,
+    ) -> StdResult<()> {
+        match replaces.and_then(|old| self.count_mut(old)) {
+            Some(count) => *count = count.saturating_sub(1),
+            None => self.total_turnout = self.total_turnout.checked_add(turnout)?,
+        }
+        if let Some(count) = self.count_mut(status) {
+            *count += 1;
+        }
+        Ok(())
+    }
+}
+
+/// The outcome of executing one of a proposal's messages in
+/// best-effort mode. Gas used is not available to the module and so
+/// is not recorded.
+#[cw_serde]
+pub struct MessageExecution {
+    /// Whether the message executed successfully.
+    pub success: bool,
+    /// The error the message failed with, if it failed.
+    pub error: Option<String>,
+    /// The events emitted by the message, if it succeeded.
+    pub events: Vec<Event>,
+}
+
+/// How a proposal is resolved when its yes and no votes are exactly
+/// equal and together make up all of the votes its threshold is
+/// measured against, so that yes votes are exactly half. Depending on
+/// the threshold, such a tie would otherwise pass (a 50% threshold) or
+/// be rejected (a majority threshold). Exact ties are rare but
+/// possible, especially in DAOs with few members.
+#[cw_serde]
+#[derive(Copy)]
+pub enum SingleChoiceTiePolicy {
+    /// Tied proposals are rejected.
+    Reject,
+    /// Tied proposals pass.
+    Pass,
+}
+
+impl Default for SingleChoiceTiePolicy {
+    fn default() -> Self {
+        Self::Reject
+    }
+}
+
+/// A second round of voting that proposals must pass after their
+/// initial intent phase. The intent phase is voted on with the
+/// module's threshold for its full voting period. If it passes, the
+/// proposal's votes are reset and it is voted on again for this
+/// phase's voting period with this phase's threshold.
+#[cw_serde]
+pub struct ConfirmationPhase {
+    /// How long the confirmation phase lasts. Must use the same units
+    /// (height or time) as the module's max voting period.
+    pub voting_period: Duration,
+    /// The threshold the confirmation phase must reach for the
+    /// proposal to pass.
+    pub threshold: Threshold,
+}
+
+/// A window of block heights during which governance is frozen, for
+/// example around a chain upgrade.
+#[cw_serde]
+pub struct Blackout {
+    /// The first height of the window.
+    pub start_height: u64,
+    /// The height the window ends at. Blocks at this height are not
+    /// in the window.
+    pub end_height: u64,
+    /// If true, passed proposals may not be executed during the
+    /// window either.
+    #[serde(default)]
+    pub block_execution: bool,
+}
+
+impl Blackout {
+    /// True if HEIGHT falls within the window.
+    pub fn contains(&self, height: u64) -> bool {
+        self.start_height <= height && height < self.end_height
+    }
+}
+
 /// The governance module's configuration.
 #[cw_serde]
 pub struct Config {
@@ -55,6 +195,97 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The number of times execution of a passed proposal may fail
+    /// before the proposal is moved to the `ExecutionFailed` status
+    /// and its deposit is returned. Failed attempts before this limit
+    /// is reached leave the proposal passed so that it may be
+    /// executed again. If None, failures are handled according to
+    /// `close_proposal_on_execution_failure`.
+    pub max_execution_attempts: Option<u64>,
+    /// The maximum number of a proposal's messages that are executed
+    /// at once. Proposals with more messages than this are executed
+    /// in chunks over multiple `Execute` and `ContinueExecution`
+    /// calls and remain passed until all of their messages have
+    /// executed. If None, all messages are executed at once.
+    pub max_msgs_per_execution: Option<u64>,
+    /// If true, proposals may only contain messages that target the
+    /// DAO or contracts in the audited contracts allowlist.
+    #[serde(default)]
+    pub audited_only: bool,
+    /// The maximum number of messages a proposal may contain. If
+    /// None, proposals are only limited by `MAX_PROPOSAL_SIZE`.
+    pub max_proposal_msgs: Option<u64>,
+    /// Limits on the length of proposal titles and descriptions for
+    /// this module. If None, they are only limited by
+    /// `MAX_PROPOSAL_SIZE`.
+    pub text_limits: Option<ProposalTextLimits>,
+    /// The minimum voting power an address must have to create a
+    /// proposal. If None, any address permitted by the proposal
+    /// creation policy may propose.
+    pub min_proposer_power: Option<Uint128>,
+    /// How proposals with exactly tied yes and no votes are
+    /// resolved.
+    #[serde(default)]
+    pub tie_policy: SingleChoiceTiePolicy,
+    /// If true, the DAO may be this proposal module's own address.
+    #[serde(default)]
+    pub allow_self_dao: bool,
+    /// A window of heights during which proposals may not be created
+    /// and, if configured, executed. If None, there is no blackout.
+    #[serde(default)]
+    pub blackout: Option<Blackout>,
+    /// If true, each message of a passed proposal is executed on its
+    /// own and messages that fail do not fail the proposal's
+    /// execution. The outcome of each message is recorded in the
+    /// proposal's execution report. When enabled,
+    /// `max_execution_attempts` and
+    /// `close_proposal_on_execution_failure` have no effect.
+    #[serde(default)]
+    pub best_effort_execution: bool,
+    /// How long after unstaking an address may not create or vote
+    /// on proposals. If None, there is no cooldown.
+    pub unstake_cooldown: Option<Duration>,
+    /// If true, only the proposer of a passed proposal may execute
+    /// it until its grace period has ended.
+    #[serde(default)]
+    pub proposer_must_execute: bool,
+    /// How long after a proposal expires only its proposer may
+    /// execute it when `proposer_must_execute` is set. If None, only
+    /// the proposer may ever execute it.
+    pub proposer_execution_grace_period: Option<Duration>,
+    /// If set, proposers must hold at least `min_count` NFTs of
+    /// `collection` to create proposals.
+    pub proposer_nft_gate: Option<ProposerNftGate>,
+    /// A voting module queried for voting power only when querying
+    /// the DAO fails. Voting power from the two sources may differ.
+    pub fallback_voting_module: Option<Addr>,
+    /// If true, proposals must be created with a non-empty
+    /// `discussion_url`.
+    #[serde(default)]
+    pub require_discussion_url: bool,
+    /// The portion of abstaining voting power that counts toward
+    /// quorum. If None, abstentions count in full.
+    #[serde(default)]
+    pub abstain_quorum_weight: Option<PercentageThreshold>,
+    /// A second round of voting that proposals must pass before they
+    /// may be executed. If None, proposals have a single round.
+    #[serde(default)]
+    pub confirmation_phase: Option<ConfirmationPhase>,
+    /// How long after passing proposals must wait before they may be
+    /// executed. If None, proposals may be executed once passed.
+    #[serde(default)]
+    pub execution_delay: Option<Duration>,
+}
+
+/// Requires proposers to hold NFTs of a cw721 collection, for example
+/// founding member NFTs.
+#[cw_serde]
+pub struct ProposerNftGate {
+    /// The cw721 collection proposers must hold NFTs of.
+    pub collection: Addr,
+    /// The minimum number of the collection's NFTs a proposer must
+    /// hold.
+    pub min_count: u64,
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -64,6 +295,9 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// Ballots cast during the confirmation phase of proposals with one.
+/// Ballots cast during their intent phase are kept in `BALLOTS`.
+pub const CONFIRMATION_BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("confirmation_ballots");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -71,3 +305,43 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// Proposal statistics for each address that has created a proposal.
+pub const PROPOSER_STATS: Map<&Addr, ProposerStats> = Map::new("proposer_stats");
+/// Index of the proposals created by each address, keyed by
+/// `(proposer, proposal_id)`.
+pub const PROPOSALS_BY_PROPOSER: Map<(&Addr, u64), Empty> = Map::new("proposals_by_proposer");
+/// The number of proposals each address has cast a ballot on. Votes
+/// cast before this count was tracked are not included.
+pub const VOTER_PARTICIPATION: Map<&Addr, u64> = Map::new("voter_participation");
+/// A sibling proposal module whose participation is included in the
+/// `CombinedParticipation {}` query, if any.
+pub const LINKED_MODULE: Item<Addr> = Item::new("linked_module");
+/// Contracts that proposals may target when `audited_only` is
+/// enabled.
+pub const AUDITED_CONTRACTS: Map<&Addr, Empty> = Map::new("audited_contracts");
+/// The status transitions of each proposal in the order they
+/// happened. At most `MAX_STATUS_HISTORY` transitions are kept per
+/// proposal.
+pub const STATUS_HISTORY: Map<u64, Vec<StatusTransition>> = Map::new("status_history");
+/// The maximum number of transitions kept in a proposal's status
+/// history. Proposals rarely change status more than a few times, so
+/// when this is exceeded the oldest transitions are dropped.
+pub const MAX_STATUS_HISTORY: usize = 16;
+/// The outcome of each message of proposals executed in best-effort
+/// mode, in the order the messages were executed.
+pub const EXECUTION_REPORTS: Map<u64, Vec<MessageExecution>> = Map::new("execution_reports");
+/// The total votes cast on each proposal after each vote, keyed by
+/// `(proposal_id, seconds)` where seconds is the time of the block
+/// the vote was cast in. Only the last vote of each block is kept.
+pub const VOTE_TIMELINE: Map<(u64, u64), Uint128> = Map::new("vote_timeline");
+/// Index of the proposals whose executed messages were sent to each
+/// address, keyed by `(address, message_hash, proposal_id)` where
+/// message_hash is the hex encoded SHA-256 hash of the message's JSON
+/// encoding. Messages that are not sent to an address are keyed
+/// under the DAO.
+pub const MESSAGE_ORIGINS: Map<(&str, &str, u64), Empty> = Map::new("message_origins");
+/// The message indexes `(start, end)` of the chunk of each proposal
+/// currently being executed, so that its messages may be removed from
+/// `MESSAGE_ORIGINS` if the chunk fails. Removed once the chunk's
+/// outcome is replied to.
+pub const EXECUTING_CHUNKS: Map<u64, (u64, u64)> = Map::new("executing_chunks");
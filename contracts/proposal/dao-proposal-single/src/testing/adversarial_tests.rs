@@ -177,6 +177,25 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -273,6 +292,25 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -128,6 +128,25 @@ where
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     };
 
     let core_addr = setup_governance(&mut app, instantiate, Some(initial_balances));
@@ -193,6 +212,10 @@ where
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+                manual_deposit_handling: false,
             },
         },
         &funds,
@@ -44,6 +44,7 @@ pub(crate) fn make_proposal(
                     denom,
                     amount,
                     refund_policy: _,
+                    staking_contract: _,
                 }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
@@ -78,6 +79,9 @@ pub(crate) fn make_proposal(
                     description: "description".to_string(),
                     msgs: msgs.clone(),
                     proposer: None,
+                    precondition: None,
+                    proposal_duration: None,
+                    discussion_url: None,
                 }),
                 &[],
             )
@@ -91,6 +95,10 @@ pub(crate) fn make_proposal(
                         title: "title".to_string(),
                         description: "description".to_string(),
                         msgs: msgs.clone(),
+                        precondition: None,
+                        proposal_duration: None,
+                        discussion_url: None,
+                        manual_deposit_handling: false,
                     },
                 },
                 &funds,
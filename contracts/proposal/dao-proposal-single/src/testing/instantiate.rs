@@ -37,6 +37,8 @@ pub(crate) fn get_pre_propose_info(
             msg: to_binary(&cppbps::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                deposit_min_lock: None,
+                dao_reimburses_passed_deposits: false,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -66,6 +68,25 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
             false,
         ),
         close_proposal_on_execution_failure: true,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     }
 }
 
@@ -84,6 +105,25 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        max_execution_attempts: None,
+        max_msgs_per_execution: None,
+        audited_only: false,
+        max_proposal_msgs: None,
+        text_limits: None,
+        min_proposer_power: None,
+        tie_policy: None,
+        allow_self_dao: false,
+        blackout: None,
+        best_effort_execution: false,
+        unstake_cooldown: None,
+        proposer_must_execute: false,
+        proposer_execution_grace_period: None,
+        proposer_nft_gate: None,
+        fallback_voting_module: None,
+        require_discussion_url: false,
+        abstain_quorum_weight: None,
+        confirmation_phase: None,
+        execution_delay: None,
     }
 }
 
@@ -264,6 +304,7 @@ pub(crate) fn instantiate_with_native_staked_balances_governance(
                 manager: None,
                 denom: "ujuno".to_string(),
                 unstaking_duration: None,
+                inactivity_decay: None,
             })
             .unwrap(),
             admin: None,
@@ -379,6 +420,7 @@ pub(crate) fn instantiate_with_staked_balances_governance(
                     staking_code_id: cw20_stake_id,
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             })
             .unwrap(),
@@ -488,6 +530,7 @@ pub(crate) fn instantiate_with_staking_active_threshold(
                     staking_code_id: cw20_staking_id,
                     unstaking_duration: None,
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
                 active_threshold,
             })
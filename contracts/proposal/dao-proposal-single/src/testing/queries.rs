@@ -1,15 +1,24 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw_multi_test::App;
+use cw_utils::Duration;
 use dao_core::state::{ProposalModule, ProposalModuleStatus};
 
 use cw_hooks::HooksResponse;
 use dao_pre_propose_single as cppbps;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy, proposal::ParticipationResponse,
+    threshold::NormalizedThreshold,
+};
 
 use crate::{
     msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse, VoteListResponse, VoteResponse},
-    state::Config,
+    query::{
+        CanRevoteResponse, ExecutionHistoryResponse, ExecutionOutcome, Parameter,
+        ProposalConfigResponse, ProposalListResponse, ProposalResponse, ProposalSummariesResponse,
+        ProposalsSendingToResponse, ProposerStatsResponse, TurnoutBreakdownResponse,
+        VoteListResponse, VoteResponse, VoteStatusResponse, VoterListResponse, VotesByTierResponse,
+    },
+    state::{Config, MessageExecution, StatusTransition},
 };
 
 pub(crate) fn query_deposit_config_and_pre_propose_module(
@@ -53,6 +62,59 @@ pub(crate) fn query_list_proposals(
         .unwrap()
 }
 
+pub(crate) fn query_proposal_summaries(
+    app: &App,
+    proposal_single: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalSummariesResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ProposalSummaries { start_after, limit },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_normalized_threshold(app: &App, proposal_single: &Addr) -> NormalizedThreshold {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::NormalizedThreshold {})
+        .unwrap()
+}
+
+pub(crate) fn query_closeable_proposals(
+    app: &App,
+    proposal_single: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::CloseableProposals { start_after, limit },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_expiring_proposals(
+    app: &App,
+    proposal_single: &Addr,
+    within: Duration,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ExpiringProposals {
+                within,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
 pub(crate) fn query_list_votes(
     app: &App,
     proposal_single: &Addr,
@@ -67,11 +129,65 @@ pub(crate) fn query_list_votes(
                 proposal_id,
                 start_after,
                 limit,
+                reverse: None,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_list_votes_reverse(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    start_before: Option<String>,
+    limit: Option<u64>,
+) -> VoteListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ListVotes {
+                proposal_id,
+                start_after: start_before,
+                limit,
+                reverse: Some(true),
             },
         )
         .unwrap()
 }
 
+pub(crate) fn query_voters(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    start_after: Option<String>,
+    limit: Option<u64>,
+) -> VoterListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::Voters {
+                proposal_id,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_votes_by_tier(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    tiers: Vec<Uint128>,
+) -> VotesByTierResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::VotesByTier { proposal_id, tiers },
+        )
+        .unwrap()
+}
+
 pub(crate) fn query_vote(
     app: &App,
     proposal_module: &Addr,
@@ -212,3 +328,260 @@ pub(crate) fn query_proposal_count(app: &App, proposal_single: &Addr) -> u64 {
         .query_wasm_smart(proposal_single, &QueryMsg::ProposalCount {})
         .unwrap()
 }
+
+pub(crate) fn query_execution_history(
+    app: &App,
+    proposal_single: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ExecutionHistoryResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ExecutionHistory { start_after, limit },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_proposals_by_execution_outcome(
+    app: &App,
+    proposal_single: &Addr,
+    outcome: ExecutionOutcome,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ProposalsByExecutionOutcome {
+                outcome,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_participation(
+    app: &App,
+    proposal_single: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ParticipationResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::Participation { start_after, limit },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_combined_participation(
+    app: &App,
+    proposal_single: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ParticipationResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::CombinedParticipation { start_after, limit },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_is_voting_open(app: &App, proposal_single: &Addr, proposal_id: u64) -> bool {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::IsVotingOpen { proposal_id })
+        .unwrap()
+}
+
+pub(crate) fn query_proposer_stats(
+    app: &App,
+    proposal_single: &Addr,
+    proposer: &str,
+) -> ProposerStatsResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ProposerStats {
+                proposer: proposer.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_voter_participation_count(
+    app: &App,
+    proposal_single: &Addr,
+    voter: &str,
+) -> u64 {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::VoterParticipationCount {
+                voter: voter.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_proposals_by_proposer(
+    app: &App,
+    proposal_single: &Addr,
+    proposer: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ProposalsByProposer {
+                proposer: proposer.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_proposals_sending_to(
+    app: &App,
+    proposal_single: &Addr,
+    recipient: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalsSendingToResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ProposalsSendingTo {
+                recipient: recipient.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_vote_status(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    address: &str,
+) -> VoteStatusResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::VoteStatus {
+                proposal_id,
+                address: address.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_turnout_breakdown(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> TurnoutBreakdownResponse {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::TurnoutBreakdown { proposal_id })
+        .unwrap()
+}
+
+pub(crate) fn query_proposal_config_snapshot(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> ProposalConfigResponse {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::ProposalConfig { proposal_id })
+        .unwrap()
+}
+
+pub(crate) fn query_can_revote(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    voter: &str,
+) -> CanRevoteResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::CanRevote {
+                proposal_id,
+                voter: voter.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_status_history(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> Vec<StatusTransition> {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::StatusHistory { proposal_id })
+        .unwrap()
+}
+
+pub(crate) fn query_execution_report(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> Vec<MessageExecution> {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::ExecutionReport { proposal_id })
+        .unwrap()
+}
+
+pub(crate) fn query_message_origin(
+    app: &App,
+    proposal_single: &Addr,
+    contract: &str,
+    msg_hash: &str,
+) -> Option<u64> {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::MessageOrigin {
+                contract: contract.to_string(),
+                msg_hash: msg_hash.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_min_proposer_power(app: &App, proposal_single: &Addr) -> Option<Uint128> {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::MinProposerPower {})
+        .unwrap()
+}
+
+pub(crate) fn query_time_weighted_turnout(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> Option<Decimal> {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::TimeWeightedTurnout { proposal_id },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_quorum_gap(app: &App, proposal_single: &Addr, proposal_id: u64) -> Uint128 {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::QuorumGap { proposal_id })
+        .unwrap()
+}
+
+pub(crate) fn query_parameters(app: &App, proposal_single: &Addr) -> Vec<Parameter> {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::Parameters {})
+        .unwrap()
+}
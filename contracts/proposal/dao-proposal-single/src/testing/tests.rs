@@ -1,42 +1,50 @@
 use cosmwasm_std::{
-    coins,
+    coins, from_binary,
     testing::{mock_dependencies, mock_env},
-    to_binary, Addr, Attribute, BankMsg, Binary, ContractInfoResponse, CosmosMsg, Decimal, Empty,
-    Reply, StdError, SubMsgResult, Uint128, WasmMsg, WasmQuery,
+    to_binary, Addr, Attribute, BankMsg, Binary, ContractInfoResponse, CosmosMsg, Decimal, Deps,
+    DepsMut, Empty, Env, MessageInfo, Reply, Response, StdError, StdResult, SubMsgResult,
+    Timestamp, Uint128, WasmMsg, WasmQuery,
 };
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
-use cw_denom::CheckedDenom;
+use cw_denom::{CheckedDenom, UncheckedDenom};
 use cw_hooks::{HookError, HooksResponse};
-use cw_multi_test::{next_block, App, Executor};
-use cw_utils::Duration;
+use cw_multi_test::{next_block, App, ContractWrapper, Executor};
+use cw_utils::{Duration, Expiration};
 use dao_interface::{voting::InfoResponse, Admin, ModuleInstantiateInfo};
 use dao_testing::{ShouldExecute, TestSingleChoiceVote};
 use dao_voting::{
-    deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    deposit::{CheckedDepositInfo, DepositRefundPolicy, UncheckedDepositInfo},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
+    precondition::{CheckedPrecondition, Precondition},
+    proposal::{ParticipationResponse, SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
         mask_proposal_hook_index, mask_vote_hook_index,
     },
     status::Status,
-    threshold::{PercentageThreshold, Threshold},
+    text_limits::{ProposalTextLimits, TextLimitError},
+    threshold::{NormalizedThreshold, PercentageThreshold, Threshold},
     voting::{Vote, Votes},
 };
 use dao_voting_cw20_staked::msg::ActiveThreshold;
+use sha2::{Digest, Sha256};
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
-    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-    proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
-    state::Config,
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, UncheckedProposerNftGate},
+    proposal::{ProposalPhase, SingleChoiceProposal},
+    query::{
+        CanRevoteResponse, ExecutionInfo, ExecutionOutcome, Parameter, ProposalConfigResponse,
+        ProposalResponse, TierTally, TierVotes, TurnoutBreakdownResponse, VoteInfo,
+        VoteStatusResponse, VotesByTierResponse, VotingModuleCompatibilityResponse,
+    },
+    state::{Blackout, Config, ConfirmationPhase, SingleChoiceTiePolicy, StatusTransition},
     testing::{
         contracts::{
             cw20_base_contract, cw20_stake_contract, cw20_staked_balances_voting_contract,
-            cw_core_contract, pre_propose_single_contract, proposal_single_contract,
-            v1_proposal_single_contract,
+            cw721_base_contract, cw_core_contract, pre_propose_single_contract,
+            proposal_single_contract, v1_proposal_single_contract,
         },
         execute::{
             add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
@@ -53,12 +61,14 @@ use crate::{
             instantiate_with_staking_active_threshold,
         },
         queries::{
-            query_balance_cw20, query_balance_native, query_creation_policy, query_dao_token,
-            query_deposit_config_and_pre_propose_module, query_list_proposals,
-            query_list_proposals_reverse, query_list_votes, query_pre_proposal_single_config,
+            query_balance_cw20, query_balance_native, query_closeable_proposals,
+            query_creation_policy, query_dao_token, query_deposit_config_and_pre_propose_module,
+            query_execution_history, query_execution_report, query_expiring_proposals,
+            query_list_proposals, query_list_proposals_reverse, query_list_votes,
+            query_list_votes_reverse, query_normalized_threshold, query_pre_proposal_single_config,
             query_pre_proposal_single_deposit_info, query_proposal, query_proposal_config,
-            query_proposal_hooks, query_single_proposal_module, query_vote_hooks,
-            query_voting_module,
+            query_proposal_hooks, query_proposal_summaries, query_proposals_by_execution_outcome,
+            query_single_proposal_module, query_vote_hooks, query_voters, query_voting_module,
         },
     },
     ContractError,
@@ -67,7 +77,14 @@ use crate::{
 use super::{
     do_votes::do_votes_staked_balances,
     execute::vote_on_proposal_with_rationale,
-    queries::{query_next_proposal_id, query_vote},
+    queries::{
+        query_can_revote, query_combined_participation, query_is_voting_open, query_message_origin,
+        query_min_proposer_power, query_next_proposal_id, query_parameters, query_participation,
+        query_proposal_config_snapshot, query_proposals_by_proposer, query_proposals_sending_to,
+        query_proposer_stats, query_quorum_gap, query_status_history, query_time_weighted_turnout,
+        query_turnout_breakdown, query_vote, query_vote_status, query_voter_participation_count,
+        query_votes_by_tier,
+    },
     CREATOR_ADDR,
 };
 
@@ -129,6 +146,17 @@ fn test_simple_propose_staked_balances() {
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        execution_attempts: 0,
+        execution_height: None,
+        executed_msgs: 0,
+        only_members_execute: Some(true),
+        created_at: current_block.time,
+        precondition: None,
+        tie_policy: Some(SingleChoiceTiePolicy::Reject),
+        discussion_url: None,
+        abstain_quorum_weight: None,
+        phase: None,
+        passed_at: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -144,7 +172,8 @@ fn test_simple_propose_staked_balances() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(gov_token),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            staking_contract: None,
         })
     );
 }
@@ -178,6 +207,17 @@ fn test_simple_proposal_cw4_voting() {
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        execution_attempts: 0,
+        execution_height: None,
+        executed_msgs: 0,
+        only_members_execute: Some(true),
+        created_at: current_block.time,
+        precondition: None,
+        tie_policy: Some(SingleChoiceTiePolicy::Reject),
+        discussion_url: None,
+        abstain_quorum_weight: None,
+        phase: None,
+        passed_at: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -282,6 +322,17 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        execution_attempts: 0,
+        execution_height: None,
+        executed_msgs: 0,
+        only_members_execute: Some(true),
+        created_at: current_block.time,
+        precondition: None,
+        tie_policy: Some(SingleChoiceTiePolicy::Reject),
+        discussion_url: None,
+        abstain_quorum_weight: None,
+        phase: None,
+        passed_at: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -297,7 +348,8 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(alt_cw20),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            staking_contract: None,
         })
     );
 }
@@ -586,6 +638,25 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                max_execution_attempts: None,
+                max_msgs_per_execution: None,
+                audited_only: false,
+                max_proposal_msgs: None,
+                text_limits: None,
+                min_proposer_power: None,
+                tie_policy: None,
+                allow_self_dao: false,
+                blackout: None,
+                best_effort_execution: false,
+                unstake_cooldown: None,
+                proposer_must_execute: false,
+                proposer_execution_grace_period: None,
+                proposer_nft_gate: None,
+                fallback_voting_module: None,
+                require_discussion_url: false,
+                abstain_quorum_weight: None,
+                confirmation_phase: None,
+                execution_delay: None,
             })
             .unwrap(),
             funds: vec![],
@@ -614,6 +685,25 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: SingleChoiceTiePolicy::Reject,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         }
     );
 
@@ -632,6 +722,25 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                max_execution_attempts: None,
+                max_msgs_per_execution: None,
+                audited_only: false,
+                max_proposal_msgs: None,
+                text_limits: None,
+                min_proposer_power: None,
+                tie_policy: None,
+                allow_self_dao: false,
+                blackout: None,
+                best_effort_execution: false,
+                unstake_cooldown: None,
+                proposer_must_execute: false,
+                proposer_execution_grace_period: None,
+                proposer_nft_gate: None,
+                fallback_voting_module: None,
+                require_discussion_url: false,
+                abstain_quorum_weight: None,
+                confirmation_phase: None,
+                execution_delay: None,
             },
             &[],
         )
@@ -717,11 +826,104 @@ fn test_anyone_may_propose_and_proposal_listing() {
                     no: Uint128::zero(),
                     abstain: Uint128::zero()
                 },
+                execution_attempts: 0,
+                execution_height: Some(current_block.height),
+                executed_msgs: 0,
+                only_members_execute: Some(true),
+                created_at: current_block.time,
+                precondition: None,
+                tie_policy: Some(SingleChoiceTiePolicy::Reject),
+                discussion_url: None,
+                abstain_quorum_weight: None,
+                phase: None,
+                passed_at: None,
             }
         }
     )
 }
 
+#[test]
+fn test_execution_history() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let mut execution_heights = vec![];
+    for _ in 0..3 {
+        let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+        vote_on_proposal(
+            &mut app,
+            &proposal_module,
+            CREATOR_ADDR,
+            proposal_id,
+            Vote::Yes,
+        );
+        app.update_block(next_block);
+        execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+        execution_heights.push((proposal_id, app.block_info().height));
+
+        // Proposals that are not executed are excluded from the
+        // history.
+        let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+        vote_on_proposal(
+            &mut app,
+            &proposal_module,
+            CREATOR_ADDR,
+            proposal_id,
+            Vote::No,
+        );
+        app.update_block(next_block);
+    }
+
+    // Nor are proposals whose messages failed to execute. The DAO
+    // has no tokens to send, so this fails.
+    let failed = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![BankMsg::Send {
+            to_address: CREATOR_ADDR.to_string(),
+            amount: coins(10, "ujuno"),
+        }
+        .into()],
+    );
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, failed, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, failed);
+    let proposal = query_proposal(&app, &proposal_module, failed);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+
+    let history = query_execution_history(&app, &proposal_module, None, None);
+    assert_eq!(
+        history.executions,
+        execution_heights
+            .iter()
+            .map(|(id, height)| ExecutionInfo {
+                id: *id,
+                title: "title".to_string(),
+                execution_height: *height,
+            })
+            .collect::<Vec<_>>()
+    );
+
+    // The limit bounds the proposals examined, not the executions
+    // returned.
+    let history = query_execution_history(&app, &proposal_module, Some(1), Some(1));
+    assert!(history.executions.is_empty());
+    let history = query_execution_history(&app, &proposal_module, Some(2), Some(1));
+    assert_eq!(history.executions.len(), 1);
+    assert_eq!(history.executions[0].id, 3);
+    assert_eq!(
+        history.executions[0].execution_height,
+        execution_heights[1].1
+    );
+
+    let proposal = query_proposal(&app, &proposal_module, 2);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    assert_eq!(proposal.proposal.execution_height, None);
+}
+
 #[test]
 fn test_proposal_hook_registration() {
     let CommonTest {
@@ -877,6 +1079,9 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             }),
             &[],
         )
@@ -915,6 +1120,9 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             }),
             &[],
         )
@@ -958,6 +1166,9 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             }),
             &[],
         )
@@ -997,6 +1208,9 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             }),
             &[],
         )
@@ -1194,6 +1408,25 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         },
         &[],
     )
@@ -1496,6 +1729,25 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         },
         Some(vec![
             Cw20Coin {
@@ -1620,6 +1872,7 @@ fn test_migrate_from_v1() {
                     staking_code_id: cw20_stake_id,
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             })
             .unwrap(),
@@ -1730,6 +1983,8 @@ fn test_migrate_from_v1() {
                         refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
                     }),
                     open_proposal_submission: false,
+                    deposit_min_lock: None,
+                    dao_reimburses_passed_deposits: false,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -1780,6 +2035,25 @@ fn test_migrate_from_v1() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: true,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: SingleChoiceTiePolicy::Reject,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         }
     );
 
@@ -1823,10 +2097,13 @@ fn test_migrate_from_v1() {
         pre_propose_config,
         cppbps::Config {
             open_proposal_submission: false,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
             deposit_info: Some(CheckedDepositInfo {
                 denom: CheckedDenom::Cw20(token_contract.clone()),
                 amount: Uint128::new(1),
                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                staking_contract: None,
             })
         }
     );
@@ -1879,9 +2156,14 @@ fn test_execution_failed() {
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
 
-    // Make sure the deposit was returned.
+    // Make sure the deposit was returned, and that the pre-propose
+    // module accepted the completion hook rather than being removed.
     let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
     assert_eq!(balance, Uint128::new(10_000_000));
+    assert!(matches!(
+        query_creation_policy(&app, &proposal_module),
+        ProposalCreationPolicy::Module { .. }
+    ));
 
     // ExecutionFailed is an end state.
     let err = close_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
@@ -1913,6 +2195,25 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
         },
         &[],
     )
@@ -1949,124 +2250,516 @@ fn test_execution_failed() {
     assert_eq!(balance, Uint128::zero());
 }
 
-#[test]
-fn test_reply_proposal_mock() {
-    use crate::contract::reply;
-    use crate::state::PROPOSALS;
+fn update_max_execution_attempts(
+    app: &mut App,
+    core_addr: &Addr,
+    proposal_module: &Addr,
+    max_execution_attempts: Option<u64>,
+) -> Result<(), ContractError> {
+    let config = query_proposal_config(app, proposal_module);
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.into_string(),
+            close_proposal_on_execution_failure: false,
+            max_execution_attempts,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
+        },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
 
-    let mut deps = mock_dependencies();
-    let env = mock_env();
+// - Verify that a proposal remains passed and keeps its deposit while
+//   it has execution attempts remaining.
+// - Verify that it goes to execution failed and that its deposit is
+//   returned once the last attempt fails.
+#[test]
+fn test_execution_failed_after_max_attempts() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id,
+    } = setup_test(vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()]);
 
-    let m_proposal_id = mask_proposal_execution_proposal_id(1);
-    PROPOSALS
-        .save(
-            deps.as_mut().storage,
-            1,
-            &SingleChoiceProposal {
-                title: "A simple text proposal".to_string(),
-                description: "This is a simple text proposal".to_string(),
-                proposer: Addr::unchecked(CREATOR_ADDR),
-                start_height: env.block.height,
-                expiration: cw_utils::Duration::Height(6).after(&env.block),
-                min_voting_period: None,
-                threshold: Threshold::AbsolutePercentage {
-                    percentage: PercentageThreshold::Majority {},
-                },
-                allow_revoting: false,
-                total_power: Uint128::new(100_000_000),
-                msgs: vec![],
-                status: Status::Open,
-                votes: Votes::zero(),
-            },
-        )
-        .unwrap();
+    let err =
+        update_max_execution_attempts(&mut app, &core_addr, &proposal_module, Some(0)).unwrap_err();
+    assert!(matches!(err, ContractError::ZeroMaxExecutionAttempts {}));
+    update_max_execution_attempts(&mut app, &core_addr, &proposal_module, Some(3)).unwrap();
 
-    // PROPOSALS
-    let reply_msg = Reply {
-        id: m_proposal_id,
-        result: SubMsgResult::Err("error_msg".to_string()),
-    };
-    let res = reply(deps.as_mut(), env, reply_msg).unwrap();
-    assert_eq!(
-        res.attributes[0],
-        Attribute {
-            key: "proposal_execution_failed".to_string(),
-            value: 1.to_string()
-        }
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
     );
 
-    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
-    assert_eq!(prop.status, Status::ExecutionFailed);
-}
+    for attempt in 1..3 {
+        execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
 
-#[test]
-fn test_proposal_too_large() {
-    let mut app = App::default();
-    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
-    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
-    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
-    let proposal_module = query_single_proposal_module(&app, &core_addr);
+        let proposal = query_proposal(&app, &proposal_module, proposal_id);
+        assert_eq!(proposal.proposal.status, Status::Passed);
+        assert_eq!(proposal.proposal.execution_attempts, attempt);
 
-    let err = app
-        .execute_contract(
-            Addr::unchecked(CREATOR_ADDR),
-            proposal_module,
-            &ExecuteMsg::Propose(ProposeMsg {
-                title: "".to_string(),
-                description: "a".repeat(MAX_PROPOSAL_SIZE as usize),
-                msgs: vec![],
-                proposer: None,
-            }),
-            &[],
-        )
-        .unwrap_err()
-        .downcast()
-        .unwrap();
+        // The deposit is held until the proposal completes.
+        let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+        assert_eq!(balance, Uint128::zero());
+    }
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    assert_eq!(proposal.proposal.execution_attempts, 3);
 
+    // Make sure the deposit was returned, and that the pre-propose
+    // module accepted the completion hook rather than being removed.
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(10_000_000));
     assert!(matches!(
-        err,
-        ContractError::ProposalTooLarge {
-            size: _,
-            max: MAX_PROPOSAL_SIZE
-        }
-    ))
+        query_creation_policy(&app, &proposal_module),
+        ProposalCreationPolicy::Module { .. }
+    ));
+
+    // ExecutionFailed is an end state.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::NotPassed {}));
+    let err = close_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::WrongCloseStatus {}));
 }
 
 #[test]
-fn test_vote_not_registered() {
+fn test_execution_succeeds_after_failed_attempt() {
     let CommonTest {
         mut app,
-        core_addr: _,
+        core_addr,
         proposal_module,
-        gov_token: _,
+        gov_token,
         proposal_id,
-    } = setup_test(vec![]);
+    } = setup_test(vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()]);
 
-    let err =
-        vote_on_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
-    assert!(matches!(err, ContractError::NotRegistered {}))
+    update_max_execution_attempts(&mut app, &core_addr, &proposal_module, Some(2)).unwrap();
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.proposal.execution_attempts, 1);
+
+    // Fund the DAO so that the next attempt succeeds.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(proposal.proposal.execution_attempts, 1);
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+
+    // Make sure the deposit was returned.
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(10_000_000));
+
+    // The executed chunk is no longer remembered.
+    let chunk = crate::state::EXECUTING_CHUNKS
+        .query(&app.wrap(), proposal_module.clone(), proposal_id)
+        .unwrap();
+    assert_eq!(chunk, None);
+}
+
+fn update_max_msgs_per_execution(
+    app: &mut App,
+    core_addr: &Addr,
+    proposal_module: &Addr,
+    max_msgs_per_execution: Option<u64>,
+) {
+    let config = query_proposal_config(app, proposal_module);
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.into_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            max_execution_attempts: config.max_execution_attempts,
+            max_msgs_per_execution,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
+        },
+        &[],
+    )
+    .unwrap();
 }
 
 #[test]
-fn test_proposal_creation_permissions() {
+fn test_chunked_execution() {
     let CommonTest {
         mut app,
         core_addr,
         proposal_module,
-        gov_token: _,
-        proposal_id: _,
-    } = setup_test(vec![]);
+        gov_token,
+        proposal_id,
+    } = setup_test(
+        ["one", "two", "three"]
+            .into_iter()
+            .map(|to_address| {
+                BankMsg::Send {
+                    to_address: to_address.to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()
+            })
+            .collect(),
+    );
+    mint_natives(&mut app, core_addr.as_str(), coins(30, "ujuno"));
 
-    // Non pre-propose may not propose.
-    let err = app
+    update_max_msgs_per_execution(&mut app, &core_addr, &proposal_module, Some(2));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // Execution must be started before it can be continued.
+    let err: ContractError = app
         .execute_contract(
-            Addr::unchecked("notprepropose"),
+            Addr::unchecked(CREATOR_ADDR),
             proposal_module.clone(),
-            &ExecuteMsg::Propose(ProposeMsg {
-                title: "title".to_string(),
-                description: "description".to_string(),
+            &ExecuteMsg::ContinueExecution { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ExecutionNotStarted {}));
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.proposal.executed_msgs, 2);
+    assert_eq!(query_balance_native(&app, "one", "ujuno"), Uint128::new(10));
+    assert_eq!(query_balance_native(&app, "two", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        query_balance_native(&app, "three", "ujuno"),
+        Uint128::zero()
+    );
+
+    // The deposit is held until the proposal is fully executed.
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::zero());
+
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::ExecutionInProgress {}));
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::ContinueExecution { proposal_id },
+        &[],
+    )
+    .unwrap();
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(proposal.proposal.executed_msgs, 3);
+    assert_eq!(
+        query_balance_native(&app, "three", "ujuno"),
+        Uint128::new(10)
+    );
+
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(10_000_000));
+
+    // The executed chunk is no longer remembered.
+    let chunk = crate::state::EXECUTING_CHUNKS
+        .query(&app.wrap(), proposal_module.clone(), proposal_id)
+        .unwrap();
+    assert_eq!(chunk, None);
+}
+
+// - Verify that a proposal whose first chunk fails is closed as
+//   execution failed and that its deposit is returned.
+#[test]
+fn test_chunked_execution_failed() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id,
+    } = setup_test(
+        ["one", "two", "three"]
+            .into_iter()
+            .map(|to_address| {
+                BankMsg::Send {
+                    to_address: to_address.to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()
+            })
+            .collect(),
+    );
+    // Only enough to pay one message of the first chunk.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    update_max_msgs_per_execution(&mut app, &core_addr, &proposal_module, Some(2));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    assert_eq!(proposal.proposal.executed_msgs, 0);
+    assert_eq!(query_balance_native(&app, "one", "ujuno"), Uint128::zero());
+
+    // Make sure the deposit was returned.
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(10_000_000));
+
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::NotPassed {}));
+}
+
+#[test]
+fn test_reply_proposal_mock() {
+    use crate::contract::reply;
+    use crate::state::{CONFIG, PROPOSALS};
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let m_proposal_id = mask_proposal_execution_proposal_id(1);
+    PROPOSALS
+        .save(
+            deps.as_mut().storage,
+            1,
+            &SingleChoiceProposal {
+                title: "A simple text proposal".to_string(),
+                description: "This is a simple text proposal".to_string(),
+                proposer: Addr::unchecked(CREATOR_ADDR),
+                start_height: env.block.height,
+                expiration: cw_utils::Duration::Height(6).after(&env.block),
+                min_voting_period: None,
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                allow_revoting: false,
+                total_power: Uint128::new(100_000_000),
+                msgs: vec![],
+                status: Status::Open,
+                votes: Votes::zero(),
+                execution_attempts: 0,
+                execution_height: None,
+                tie_policy: None,
+                executed_msgs: 0,
+                only_members_execute: Some(true),
+                created_at: env.block.time,
+                precondition: None,
+                discussion_url: None,
+            },
+        )
+        .unwrap();
+
+    CONFIG
+        .save(
+            deps.as_mut().storage,
+            &Config {
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                max_voting_period: Duration::Height(6),
+                min_voting_period: None,
+                only_members_execute: false,
+                allow_revoting: false,
+                dao: Addr::unchecked("dao"),
+                close_proposal_on_execution_failure: true,
+                max_execution_attempts: None,
+                max_msgs_per_execution: None,
+                audited_only: false,
+                max_proposal_msgs: None,
+                text_limits: None,
+                min_proposer_power: None,
+                tie_policy: SingleChoiceTiePolicy::Reject,
+                allow_self_dao: false,
+                blackout: None,
+                best_effort_execution: false,
+                unstake_cooldown: None,
+                proposer_must_execute: false,
+                proposer_execution_grace_period: None,
+                proposer_nft_gate: None,
+                fallback_voting_module: None,
+                require_discussion_url: false,
+                abstain_quorum_weight: None,
+                confirmation_phase: None,
+                execution_delay: None,
+            },
+        )
+        .unwrap();
+
+    // PROPOSALS
+    let reply_msg = Reply {
+        id: m_proposal_id,
+        result: SubMsgResult::Err("error_msg".to_string()),
+    };
+    let res = reply(deps.as_mut(), env, reply_msg).unwrap();
+    assert_eq!(
+        res.attributes[0],
+        Attribute {
+            key: "proposal_execution_failed".to_string(),
+            value: 1.to_string()
+        }
+    );
+
+    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
+    assert_eq!(prop.status, Status::ExecutionFailed);
+}
+
+#[test]
+fn test_proposal_too_large() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module,
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "".to_string(),
+                description: "a".repeat(MAX_PROPOSAL_SIZE as usize),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+
+    assert!(matches!(
+        err,
+        ContractError::ProposalTooLarge {
+            size: _,
+            max: MAX_PROPOSAL_SIZE
+        }
+    ))
+}
+
+#[test]
+fn test_vote_not_registered() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    let err =
+        vote_on_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    assert!(matches!(err, ContractError::NotRegistered {}))
+}
+
+#[test]
+fn test_proposal_creation_permissions() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token: _,
+        proposal_id: _,
+    } = setup_test(vec![]);
+
+    // Non pre-propose may not propose.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("notprepropose"),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             }),
             &[],
         )
@@ -2092,6 +2785,9 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             }),
             &[],
         )
@@ -2122,6 +2818,9 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
             }),
             &[],
         )
@@ -2359,6 +3058,91 @@ fn test_query_list_votes() {
             },
         ]
     );
+
+    let voters = |votes: crate::query::VoteListResponse| -> Vec<String> {
+        votes
+            .votes
+            .into_iter()
+            .map(|v| v.voter.into_string())
+            .collect()
+    };
+    let votes = query_list_votes_reverse(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(voters(votes), vec!["two", "three", "one", "four", "five"]);
+
+    let votes = query_list_votes_reverse(
+        &app,
+        &proposal_module,
+        proposal_id,
+        Some("three".to_string()),
+        Some(2),
+    );
+    assert_eq!(voters(votes), vec!["one", "four"]);
+
+    let votes = query_list_votes_reverse(
+        &app,
+        &proposal_module,
+        proposal_id,
+        Some("five".to_string()),
+        None,
+    );
+    assert_eq!(voters(votes), Vec::<String>::new());
+}
+
+#[test]
+fn test_query_voters() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(3),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            ["one", "two", "three", "four", "five"]
+                .into_iter()
+                .map(|address| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(1),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![]);
+
+    let voters = query_voters(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(voters.voters, Vec::<Addr>::new());
+
+    vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "four", proposal_id, Vote::Yes);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "one",
+        proposal_id,
+        Vote::Abstain,
+    );
+
+    let voters = query_voters(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(
+        voters.voters,
+        vec![
+            Addr::unchecked("four"),
+            Addr::unchecked("one"),
+            Addr::unchecked("two")
+        ]
+    );
+
+    let voters = query_voters(
+        &app,
+        &proposal_module,
+        proposal_id,
+        Some("four".to_string()),
+        Some(1),
+    );
+    assert_eq!(voters.voters, vec![Addr::unchecked("one")]);
 }
 
 #[test]
@@ -2400,6 +3184,8 @@ fn test_update_pre_propose_module() {
                                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
                             }),
                             open_proposal_submission: false,
+                            deposit_min_lock: None,
+                            dao_reimburses_passed_deposits: false,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -2447,8 +3233,11 @@ fn test_update_pre_propose_module() {
                 denom: CheckedDenom::Cw20(gov_token.clone()),
                 amount: Uint128::new(1),
                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                staking_contract: None,
             }),
             open_proposal_submission: false,
+            deposit_min_lock: None,
+            dao_reimburses_passed_deposits: false,
         }
     );
 
@@ -2678,3 +3467,3343 @@ fn test_proposal_count_goes_up() {
     let next = query_next_proposal_id(&app, &proposal_module);
     assert_eq!(next, 3);
 }
+
+#[test]
+fn test_combined_participation() {
+    // A sibling proposal module that reports a fixed participation.
+    fn sibling_query(_deps: Deps, _env: Env, _msg: QueryMsg) -> StdResult<Binary> {
+        to_binary(&ParticipationResponse {
+            open_proposals: 2,
+            votes_cast: Uint128::new(4),
+            total_power: Uint128::new(20),
+        })
+    }
+    fn sibling_noop(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(3),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            ["one", "two", "three", "four", "five"]
+                .into_iter()
+                .map(|address| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(1),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let first = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    let second = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    let passed = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "one", first, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", first, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "three", second, Vote::Abstain);
+    for voter in ["one", "two", "three"] {
+        vote_on_proposal(&mut app, &proposal_module, voter, passed, Vote::Yes);
+    }
+
+    // Only open proposals are counted.
+    let own = ParticipationResponse {
+        open_proposals: 2,
+        votes_cast: Uint128::new(3),
+        total_power: Uint128::new(10),
+    };
+    assert_eq!(query_participation(&app, &proposal_module, None, None), own);
+    assert_eq!(
+        query_combined_participation(&app, &proposal_module, None, None),
+        own
+    );
+
+    // Participation may be summed over pages of proposals.
+    assert_eq!(
+        query_participation(&app, &proposal_module, None, Some(1))
+            .checked_add(query_participation(
+                &app,
+                &proposal_module,
+                Some(first),
+                None
+            ))
+            .unwrap(),
+        own
+    );
+    assert_eq!(
+        query_participation(&app, &proposal_module, Some(second), None),
+        ParticipationResponse::default()
+    );
+
+    let sibling_id = app.store_code(Box::new(ContractWrapper::new(
+        sibling_noop,
+        sibling_noop,
+        sibling_query,
+    )));
+    let sibling = app
+        .instantiate_contract(
+            sibling_id,
+            core_addr.clone(),
+            &Empty {},
+            &[],
+            "sibling",
+            None,
+        )
+        .unwrap();
+
+    // Only the DAO may link modules.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("one"),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateLinkedModule {
+                address: Some(sibling.to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateLinkedModule {
+            address: Some(sibling.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    let linked: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::LinkedModule {})
+        .unwrap();
+    assert_eq!(linked, Some(sibling));
+
+    // Linking does not change this module's own participation.
+    assert_eq!(query_participation(&app, &proposal_module, None, None), own);
+    assert_eq!(
+        query_combined_participation(&app, &proposal_module, None, None),
+        ParticipationResponse {
+            open_proposals: 4,
+            votes_cast: Uint128::new(7),
+            total_power: Uint128::new(30),
+        }
+    );
+
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateLinkedModule { address: None },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_combined_participation(&app, &proposal_module, None, None),
+        own
+    );
+}
+
+#[test]
+fn test_audited_only() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.audited_only = true;
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Some contract that has not been audited.
+    let unaudited = query_voting_module(&app, &core_addr);
+    let msgs = |target: &Addr| -> Vec<CosmosMsg> {
+        vec![WasmMsg::Execute {
+            contract_addr: target.to_string(),
+            msg: to_binary(&Empty {}).unwrap(),
+            funds: vec![],
+        }
+        .into()]
+    };
+    let propose = |app: &mut App, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs,
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+    };
+
+    let err: ContractError = propose(&mut app, msgs(&unaudited))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::UnauditedContract { address } if address == unaudited.as_str()
+    ));
+
+    // Messages to the DAO and non-contract messages are always
+    // permitted.
+    let mut dao_msgs = msgs(&core_addr);
+    dao_msgs.push(
+        BankMsg::Send {
+            to_address: CREATOR_ADDR.to_string(),
+            amount: coins(10, "ujuno"),
+        }
+        .into(),
+    );
+    propose(&mut app, dao_msgs).unwrap();
+
+    // Only the DAO may manage the allowlist.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::AddAuditedContract {
+                address: unaudited.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::AddAuditedContract {
+            address: unaudited.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let audited: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_module,
+            &QueryMsg::AuditedContracts {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(audited, vec![unaudited.clone()]);
+
+    propose(&mut app, msgs(&unaudited)).unwrap();
+
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &ExecuteMsg::RemoveAuditedContract {
+            address: unaudited.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = propose(&mut app, msgs(&unaudited))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::UnauditedContract { .. }));
+}
+
+#[test]
+fn test_is_voting_open() {
+    for max_voting_period in [Duration::Height(10), Duration::Time(100)] {
+        let mut app = App::default();
+        let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+        instantiate.max_voting_period = max_voting_period;
+        instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+        let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+        let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+        let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+        assert!(query_is_voting_open(&app, &proposal_module, proposal_id));
+
+        // Advance to one block / second before expiration.
+        app.update_block(|block| match max_voting_period {
+            Duration::Height(blocks) => block.height += blocks - 1,
+            Duration::Time(seconds) => block.time = block.time.plus_seconds(seconds - 1),
+        });
+        assert!(query_is_voting_open(&app, &proposal_module, proposal_id));
+
+        app.update_block(|block| match max_voting_period {
+            Duration::Height(_) => block.height += 1,
+            Duration::Time(_) => block.time = block.time.plus_seconds(1),
+        });
+        assert!(!query_is_voting_open(&app, &proposal_module, proposal_id));
+    }
+
+    // Proposals that have passed are no longer open for voting.
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    assert!(!query_is_voting_open(&app, &proposal_module, proposal_id));
+}
+
+#[test]
+fn test_proposer_stats() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(3),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            ["one", "two", "three", "four", "five"]
+                .into_iter()
+                .map(|address| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(1),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let stats = query_proposer_stats(&app, &proposal_module, "one");
+    assert_eq!(stats.proposals, 0);
+    assert_eq!(stats.average_turnout, Decimal::zero());
+
+    // Executed with 3/5 turnout.
+    let executed = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    for voter in ["one", "two", "three"] {
+        vote_on_proposal(&mut app, &proposal_module, voter, executed, Vote::Yes);
+    }
+    execute_proposal(&mut app, &proposal_module, "one", executed);
+
+    // Closed with 2/5 turnout once expired.
+    let closed = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "two", closed, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "four", closed, Vote::Yes);
+
+    // Still open, so it does not count towards turnout.
+    make_proposal(&mut app, &proposal_module, "one", vec![]);
+    make_proposal(&mut app, &proposal_module, "two", vec![]);
+
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    close_proposal(&mut app, &proposal_module, "one", closed);
+
+    let stats = query_proposer_stats(&app, &proposal_module, "one");
+    assert_eq!(stats.proposals, 3);
+    assert_eq!(stats.executed, 1);
+    assert_eq!(stats.closed, 1);
+    assert_eq!(stats.execution_failed, 0);
+    assert_eq!(stats.average_turnout, Decimal::percent(50));
+
+    let stats = query_proposer_stats(&app, &proposal_module, "two");
+    assert_eq!(stats.proposals, 1);
+    assert_eq!(stats.executed + stats.closed + stats.execution_failed, 0);
+    assert_eq!(stats.average_turnout, Decimal::zero());
+}
+
+#[test]
+fn test_lifecycle_events() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(2),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            ["one", "two", "three"]
+                .into_iter()
+                .map(|address| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(1),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Gets the attributes this module emitted in response to SENDER
+    // sending MSG.
+    let mut execute = |sender: &str, msg: ExecuteMsg| -> Vec<(String, String)> {
+        let res = app
+            .execute_contract(Addr::unchecked(sender), proposal_module.clone(), &msg, &[])
+            .unwrap();
+        res.events
+            .into_iter()
+            .filter(|event| {
+                event.ty == "wasm"
+                    && event.attributes.iter().any(|attr| {
+                        attr.key == "_contract_addr" && attr.value == proposal_module.as_str()
+                    })
+            })
+            .flat_map(|event| event.attributes)
+            .map(|attr| (attr.key, attr.value))
+            .collect()
+    };
+    let has = |attributes: &[(String, String)], key: &str, value: &str| {
+        attributes.iter().any(|(k, v)| k == key && v == value)
+    };
+    let propose = || {
+        ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+            proposer: None,
+            precondition: None,
+            proposal_duration: None,
+            discussion_url: None,
+        })
+    };
+    let vote = |proposal_id, vote| ExecuteMsg::Vote {
+        proposal_id,
+        vote,
+        rationale: None,
+    };
+
+    let events = execute("one", propose());
+    assert!(has(&events, "action", "propose"));
+    assert!(has(&events, "proposal_id", "1"));
+    assert!(has(&events, "status", "open"));
+    assert!(has(&events, "sender", "one"));
+
+    let events = execute("one", vote(1, Vote::Yes));
+    assert!(has(&events, "action", "vote"));
+    assert!(has(&events, "proposal_id", "1"));
+    assert!(has(&events, "status", "open"));
+    assert!(has(&events, "sender", "one"));
+    assert!(has(&events, "vote", "yes"));
+    assert!(has(&events, "power", "1"));
+
+    let events = execute("two", vote(1, Vote::Yes));
+    assert!(has(&events, "status", "passed"));
+    assert!(has(&events, "sender", "two"));
+
+    let events = execute("three", ExecuteMsg::Execute { proposal_id: 1 });
+    assert!(has(&events, "action", "execute"));
+    assert!(has(&events, "proposal_id", "1"));
+    assert!(has(&events, "status", "executed"));
+    assert!(has(&events, "sender", "three"));
+
+    execute("two", propose());
+    execute("one", vote(2, Vote::No));
+    let events = execute("two", vote(2, Vote::No));
+    assert!(has(&events, "status", "rejected"));
+
+    let events = execute("three", ExecuteMsg::Close { proposal_id: 2 });
+    assert!(has(&events, "action", "close"));
+    assert!(has(&events, "proposal_id", "2"));
+    assert!(has(&events, "status", "closed"));
+    assert!(has(&events, "sender", "three"));
+}
+
+#[test]
+fn test_voter_participation_count() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(3),
+    };
+    instantiate.allow_revoting = true;
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            ["one", "two", "three"]
+                .into_iter()
+                .map(|address| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(1),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    assert_eq!(
+        query_voter_participation_count(&app, &proposal_module, "one"),
+        0
+    );
+
+    let first = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    let second = make_proposal(&mut app, &proposal_module, "one", vec![]);
+    make_proposal(&mut app, &proposal_module, "one", vec![]);
+
+    vote_on_proposal(&mut app, &proposal_module, "one", first, Vote::Yes);
+    assert_eq!(
+        query_voter_participation_count(&app, &proposal_module, "one"),
+        1
+    );
+
+    // Changing a vote does not count as participating again.
+    vote_on_proposal(&mut app, &proposal_module, "one", first, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "one", first, Vote::Abstain);
+    assert_eq!(
+        query_voter_participation_count(&app, &proposal_module, "one"),
+        1
+    );
+
+    vote_on_proposal(&mut app, &proposal_module, "one", second, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", second, Vote::No);
+    assert_eq!(
+        query_voter_participation_count(&app, &proposal_module, "one"),
+        2
+    );
+    assert_eq!(
+        query_voter_participation_count(&app, &proposal_module, "two"),
+        1
+    );
+    assert_eq!(
+        query_voter_participation_count(&app, &proposal_module, "three"),
+        0
+    );
+}
+
+#[test]
+fn test_proposal_config_snapshot() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    let expected = ProposalConfigResponse {
+        threshold: Threshold::ThresholdQuorum {
+            quorum: PercentageThreshold::Percent(Decimal::percent(15)),
+            threshold: PercentageThreshold::Majority {},
+        },
+        allow_revoting: false,
+        only_members_execute: Some(true),
+        deposit_info: Some(CheckedDepositInfo {
+            denom: CheckedDenom::Cw20(gov_token.clone()),
+            amount: Uint128::new(10_000_000),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            staking_contract: None,
+        }),
+    };
+    assert_eq!(
+        query_proposal_config_snapshot(&app, &proposal_module, proposal_id),
+        expected
+    );
+
+    // Changing the module's config does not change the config
+    // reported for existing proposals.
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            threshold: Threshold::AbsoluteCount {
+                threshold: Uint128::new(1),
+            },
+            max_voting_period: Duration::Height(6),
+            min_voting_period: None,
+            only_members_execute: false,
+            allow_revoting: true,
+            dao: core_addr.to_string(),
+            close_proposal_on_execution_failure: false,
+            max_execution_attempts: None,
+            max_msgs_per_execution: None,
+            audited_only: false,
+            max_proposal_msgs: None,
+            text_limits: None,
+            min_proposer_power: None,
+            tie_policy: None,
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: false,
+            proposer_execution_grace_period: None,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal_config_snapshot(&app, &proposal_module, proposal_id),
+        expected
+    );
+
+    // New proposals pick up the new config.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    assert_eq!(
+        query_proposal_config_snapshot(&app, &proposal_module, proposal_id),
+        ProposalConfigResponse {
+            threshold: Threshold::AbsoluteCount {
+                threshold: Uint128::new(1),
+            },
+            allow_revoting: true,
+            only_members_execute: Some(false),
+            ..expected
+        }
+    );
+
+    // Querying a proposal that does not exist fails.
+    app.wrap()
+        .query_wasm_smart::<ProposalConfigResponse>(
+            &proposal_module,
+            &QueryMsg::ProposalConfig { proposal_id: 3 },
+        )
+        .unwrap_err();
+}
+
+#[test]
+fn test_proposals_in_range() {
+    use crate::contract::query;
+    use crate::query::ProposalListResponse;
+    use crate::state::PROPOSALS;
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    // Proposal 1 has the zero timestamp of a proposal migrated from
+    // v1. The others were created 100 seconds apart.
+    for (id, created_at) in [(1, 0), (2, 100), (3, 200), (4, 300), (5, 400)] {
+        PROPOSALS
+            .save(
+                deps.as_mut().storage,
+                id,
+                &SingleChoiceProposal {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    proposer: Addr::unchecked(CREATOR_ADDR),
+                    start_height: env.block.height,
+                    expiration: cw_utils::Duration::Height(6).after(&env.block),
+                    min_voting_period: None,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: PercentageThreshold::Majority {},
+                    },
+                    allow_revoting: false,
+                    total_power: Uint128::new(100),
+                    msgs: vec![],
+                    status: Status::Open,
+                    votes: Votes::zero(),
+                    execution_attempts: 0,
+                    execution_height: None,
+                    tie_policy: None,
+                    executed_msgs: 0,
+                    only_members_execute: Some(true),
+                    created_at: Timestamp::from_seconds(created_at),
+                    precondition: None,
+                    discussion_url: None,
+                },
+            )
+            .unwrap();
+    }
+
+    let proposals_in_range = |start_time: u64,
+                              end_time: u64,
+                              start_after: Option<u64>,
+                              limit: Option<u64>|
+     -> Vec<u64> {
+        let res: ProposalListResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ProposalsInRange {
+                    start_time: Timestamp::from_seconds(start_time),
+                    end_time: Timestamp::from_seconds(end_time),
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.proposals.into_iter().map(|p| p.id).collect()
+    };
+
+    // Start is inclusive and end is exclusive.
+    assert_eq!(proposals_in_range(100, 300, None, None), vec![2, 3]);
+    assert_eq!(proposals_in_range(150, 301, None, None), vec![3, 4]);
+    assert_eq!(proposals_in_range(500, 600, None, None), Vec::<u64>::new());
+
+    // Migrated proposals are never included.
+    assert_eq!(proposals_in_range(0, 1000, None, None), vec![2, 3, 4, 5]);
+
+    // Pagination is by proposal ID.
+    assert_eq!(proposals_in_range(0, 1000, None, Some(2)), vec![2, 3]);
+    assert_eq!(proposals_in_range(0, 1000, Some(3), Some(2)), vec![4, 5]);
+}
+
+#[test]
+fn test_vote_no_power_at_snapshot() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    // Noah stakes after the proposal is created.
+    mint_cw20s(&mut app, &gov_token, &core_addr, "noah", 10_000_000);
+    let voting_module = query_voting_module(&app, &core_addr);
+    let staking_contract: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module,
+            &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked("noah"),
+        gov_token,
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: staking_contract.to_string(),
+            amount: Uint128::new(10_000_000),
+            msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let start_height = query_proposal(&app, &proposal_module, proposal_id)
+        .proposal
+        .start_height;
+    let err =
+        vote_on_proposal_should_fail(&mut app, &proposal_module, "noah", proposal_id, Vote::Yes);
+    assert!(matches!(
+        err,
+        ContractError::NoVotingPowerAtSnapshot { snapshot_height } if snapshot_height == start_height
+    ));
+
+    // Addresses with no voting power at all are not registered.
+    let err =
+        vote_on_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    assert!(matches!(err, ContractError::NotRegistered {}));
+}
+
+#[test]
+fn test_blackout() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let start_height = app.block_info().height + 5;
+
+    let code_id = app.store_code(proposal_single_contract());
+    let mut empty = instantiate.clone();
+    empty.blackout = Some(Blackout {
+        start_height,
+        end_height: start_height,
+        block_execution: false,
+    });
+    let err: ContractError = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &empty,
+            &[],
+            "proposal module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InvalidBlackout {}));
+
+    instantiate.blackout = Some(Blackout {
+        start_height,
+        end_height: start_height + 5,
+        block_execution: true,
+    });
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Proposals may be created before the blackout.
+    let passing = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passing, Vote::Yes);
+
+    app.update_block(|block| block.height = start_height);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::InBlackout { start_height: start, end_height: end }
+            if start == start_height && end == start_height + 5
+    ));
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, passing);
+    assert!(matches!(err, ContractError::InBlackout { .. }));
+
+    // The blackout ends at its end height.
+    app.update_block(|block| block.height = start_height + 5);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, passing);
+    let proposal = query_proposal(&app, &proposal_module, passing).proposal;
+    assert_eq!(proposal.status, Status::Executed);
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+}
+
+#[test]
+fn test_abstain_quorum_weight() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+
+    // Weights must be percentages no greater than 100%.
+    let code_id = app.store_code(proposal_single_contract());
+    for weight in [
+        PercentageThreshold::Majority {},
+        PercentageThreshold::Percent(Decimal::percent(101)),
+    ] {
+        let mut instantiate = instantiate.clone();
+        instantiate.abstain_quorum_weight = Some(weight);
+        let err: ContractError = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(CREATOR_ADDR),
+                &instantiate,
+                &[],
+                "proposal module",
+                None,
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert!(matches!(err, ContractError::InvalidAbstainQuorumWeight {}));
+    }
+
+    // The weight is recorded on proposals when they are created.
+    let weight = PercentageThreshold::Percent(Decimal::percent(50));
+    instantiate.abstain_quorum_weight = Some(weight);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.abstain_quorum_weight, Some(weight));
+}
+
+#[test]
+fn test_confirmation_phase() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+
+    // The confirmation phase begins when the intent phase ends, so
+    // their voting periods must have the same units.
+    let code_id = app.store_code(proposal_single_contract());
+    let mut conflicting = instantiate.clone();
+    conflicting.confirmation_phase = Some(ConfirmationPhase {
+        voting_period: Duration::Height(10),
+        threshold: instantiate.threshold.clone(),
+    });
+    let err: ContractError = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &conflicting,
+            &[],
+            "proposal module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::ConfirmationPhaseUnitsConflict {}
+    ));
+
+    let confirmation = ConfirmationPhase {
+        voting_period: Duration::Time(86400),
+        threshold: Threshold::AbsoluteCount {
+            threshold: Uint128::new(15),
+        },
+    };
+    instantiate.confirmation_phase = Some(confirmation.clone());
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(5),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(5),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // A proposal that fails to reach quorum in its intent phase is
+    // rejected without reaching its confirmation phase.
+    let failing = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "ekez", failing, Vote::Yes);
+
+    // The second proposal passes its intent phase.
+    let passing = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passing, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "ekez", passing, Vote::Yes);
+
+    // Intent phases do not pass early.
+    let proposal = query_proposal(&app, &proposal_module, passing).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    assert_eq!(
+        proposal.phase,
+        Some(ProposalPhase::Intent {
+            confirmation: confirmation.clone()
+        })
+    );
+    let intent_expiration = proposal.expiration;
+
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+
+    let proposal = query_proposal(&app, &proposal_module, failing).proposal;
+    assert_eq!(proposal.status, Status::Rejected);
+    assert!(matches!(proposal.phase, Some(ProposalPhase::Intent { .. })));
+    let err = vote_on_proposal_should_fail(&mut app, &proposal_module, "keze", failing, Vote::Yes);
+    assert!(matches!(err, ContractError::Expired { .. }));
+
+    // The confirmation phase begins when the intent phase ends and is
+    // voted on from scratch with its own threshold.
+    let proposal = query_proposal(&app, &proposal_module, passing).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    assert_eq!(proposal.votes, Votes::zero());
+    assert_eq!(proposal.threshold, confirmation.threshold);
+    assert_eq!(
+        proposal.expiration,
+        (intent_expiration + confirmation.voting_period).unwrap()
+    );
+    let Some(ProposalPhase::Confirmation { intent_votes, .. }) = proposal.phase else {
+        panic!("expected the proposal to be in its confirmation phase");
+    };
+    assert_eq!(intent_votes.yes, Uint128::new(15));
+
+    // Voters from the intent phase may vote again.
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passing, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, passing).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    vote_on_proposal(&mut app, &proposal_module, "keze", passing, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, passing).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, passing);
+}
+
+#[test]
+fn test_execution_delay() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+
+    // The delay is added to when proposals pass, so it must have the
+    // same units as the voting period.
+    let code_id = app.store_code(proposal_single_contract());
+    let mut conflicting = instantiate.clone();
+    conflicting.execution_delay = Some(Duration::Height(10));
+    let err: ContractError = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &conflicting,
+            &[],
+            "proposal module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ExecutionDelayUnitsConflict {}));
+
+    instantiate.execution_delay = Some(Duration::Time(86400));
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let passing = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passing, Vote::Yes);
+    let passed_at = app.block_info().time;
+    let proposal = query_proposal(&app, &proposal_module, passing).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+    assert_eq!(proposal.passed_at, Some(Expiration::AtTime(passed_at)));
+
+    // Passed proposals may not be executed until the delay has
+    // elapsed.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, passing);
+    assert!(matches!(
+        err,
+        ContractError::TimelockNotExpired { executable_at }
+            if executable_at == Expiration::AtTime(passed_at.plus_seconds(86400))
+    ));
+    app.update_block(|block| block.time = block.time.plus_seconds(86399));
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, passing);
+    assert!(matches!(err, ContractError::TimelockNotExpired { .. }));
+
+    // Rejected proposals may be closed without waiting.
+    let rejected = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, rejected, Vote::No);
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, rejected);
+    let proposal = query_proposal(&app, &proposal_module, rejected).proposal;
+    assert_eq!(proposal.status, Status::Closed);
+
+    app.update_block(|block| block.time = block.time.plus_seconds(1));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, passing);
+    let proposal = query_proposal(&app, &proposal_module, passing).proposal;
+    assert_eq!(proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_max_proposal_msgs() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.max_proposal_msgs = Some(2);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let msgs = |count: usize| -> Vec<CosmosMsg> {
+        vec![
+            BankMsg::Send {
+                to_address: CREATOR_ADDR.to_string(),
+                amount: coins(1, "ujuno"),
+            }
+            .into();
+            count
+        ]
+    };
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: msgs(3),
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::TooManyProposalMsgs { count: 3, max: 2 }
+    ));
+
+    // Proposals at the limit may be created.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, msgs(2));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.msgs.len(), 2);
+
+    // A limit of zero is invalid.
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.max_proposal_msgs = Some(0);
+    let code_id = app.store_code(proposal_single_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &instantiate,
+            &[],
+            "proposal module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ZeroMaxProposalMsgs {}));
+}
+
+#[test]
+fn test_turnout_breakdown() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            [("ekez", 5), ("keze", 3), ("ezek", 2), ("zeke", 10)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "ekez", vec![]);
+
+    assert_eq!(
+        query_turnout_breakdown(&app, &proposal_module, proposal_id),
+        TurnoutBreakdownResponse {
+            voted_power: Uint128::zero(),
+            abstained_power: Uint128::zero(),
+            uncast_power: Uint128::new(20),
+            total_power: Uint128::new(20),
+        }
+    );
+
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "keze", proposal_id, Vote::No);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "ezek",
+        proposal_id,
+        Vote::Abstain,
+    );
+
+    let breakdown = query_turnout_breakdown(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        breakdown,
+        TurnoutBreakdownResponse {
+            voted_power: Uint128::new(8),
+            abstained_power: Uint128::new(2),
+            uncast_power: Uint128::new(10),
+            total_power: Uint128::new(20),
+        }
+    );
+    assert_eq!(
+        breakdown.voted_power + breakdown.abstained_power + breakdown.uncast_power,
+        breakdown.total_power
+    );
+}
+
+#[test]
+fn test_precondition() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Only execute if the treasury holds at least 100 ujuno.
+    let propose = |app: &mut App| -> u64 {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![BankMsg::Send {
+                    to_address: CREATOR_ADDR.to_string(),
+                    amount: coins(100, "ujuno"),
+                }
+                .into()],
+                proposer: None,
+                precondition: Some(Precondition::MinBalance {
+                    address: core_addr.to_string(),
+                    denom: UncheckedDenom::Native("ujuno".to_string()),
+                    amount: Uint128::new(100),
+                }),
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+        .unwrap();
+        query_next_proposal_id(app, &proposal_module) - 1
+    };
+
+    let proposal_id = propose(&mut app);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, proposal_id)
+            .proposal
+            .precondition,
+        Some(CheckedPrecondition::MinBalance {
+            address: core_addr.clone(),
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(100),
+        })
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // The treasury is empty so the proposal may not be executed.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(
+        err,
+        ContractError::PreconditionNotMet { id } if id == proposal_id
+    ));
+
+    // As it can not be executed, it may be closed.
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, proposal_id)
+            .proposal
+            .status,
+        Status::Closed
+    );
+
+    // Once the precondition holds, passed proposals execute and may
+    // no longer be closed.
+    let proposal_id = propose(&mut app);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    mint_natives(&mut app, core_addr.as_str(), coins(100, "ujuno"));
+    let err = close_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::WrongCloseStatus {}));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, proposal_id)
+            .proposal
+            .status,
+        Status::Executed
+    );
+}
+
+#[test]
+fn test_voting_module_compatible() {
+    // A voting module that only implements the `Info {}` query.
+    fn info_only_query(
+        _deps: Deps,
+        _env: Env,
+        msg: dao_interface::voting::Query,
+    ) -> StdResult<Binary> {
+        match msg {
+            dao_interface::voting::Query::Info {} => to_binary(&InfoResponse {
+                info: ContractVersion {
+                    contract: "info-only".to_string(),
+                    version: "0.1.0".to_string(),
+                },
+            }),
+            _ => Err(StdError::generic_err("unsupported query")),
+        }
+    }
+    fn info_only_noop(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let query_compatible = |app: &App, voting_module: &Addr| -> VotingModuleCompatibilityResponse {
+        app.wrap()
+            .query_wasm_smart(
+                &proposal_module,
+                &QueryMsg::VotingModuleCompatible {
+                    voting_module: voting_module.to_string(),
+                },
+            )
+            .unwrap()
+    };
+
+    let voting_module = query_voting_module(&app, &core_addr);
+    assert_eq!(
+        query_compatible(&app, &voting_module),
+        VotingModuleCompatibilityResponse {
+            info: Some(ContractVersion {
+                contract: "crates.io:dao-voting-cw4".to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            }),
+            voting_power_at_height: true,
+            total_power_at_height: true,
+            dao: true,
+            compatible: true,
+        }
+    );
+
+    let info_only_id = app.store_code(Box::new(ContractWrapper::new(
+        info_only_noop,
+        info_only_noop,
+        info_only_query,
+    )));
+    let info_only = app
+        .instantiate_contract(info_only_id, core_addr, &Empty {}, &[], "info only", None)
+        .unwrap();
+    assert_eq!(
+        query_compatible(&app, &info_only),
+        VotingModuleCompatibilityResponse {
+            info: Some(ContractVersion {
+                contract: "info-only".to_string(),
+                version: "0.1.0".to_string(),
+            }),
+            voting_power_at_height: false,
+            total_power_at_height: false,
+            dao: false,
+            compatible: false,
+        }
+    );
+}
+
+#[test]
+fn test_state_hash() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let mut dao_config: dao_core::state::Config = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &dao_core::msg::QueryMsg::Config {})
+        .unwrap();
+    dao_config.name = "renamed".to_string();
+    let mut module_config = query_proposal_config(&app, &proposal_module);
+    module_config.allow_revoting = true;
+
+    let msgs: Vec<CosmosMsg> = vec![
+        WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_binary(&dao_core::msg::ExecuteMsg::UpdateConfig { config: dao_config })
+                .unwrap(),
+            funds: vec![],
+        }
+        .into(),
+        WasmMsg::Execute {
+            contract_addr: proposal_module.to_string(),
+            msg: to_binary(&ExecuteMsg::UpdateConfig {
+                threshold: module_config.threshold,
+                max_voting_period: module_config.max_voting_period,
+                min_voting_period: module_config.min_voting_period,
+                only_members_execute: module_config.only_members_execute,
+                allow_revoting: module_config.allow_revoting,
+                dao: module_config.dao.to_string(),
+                close_proposal_on_execution_failure: module_config
+                    .close_proposal_on_execution_failure,
+                max_execution_attempts: module_config.max_execution_attempts,
+                max_msgs_per_execution: module_config.max_msgs_per_execution,
+                audited_only: module_config.audited_only,
+                max_proposal_msgs: module_config.max_proposal_msgs,
+                text_limits: module_config.text_limits,
+                min_proposer_power: module_config.min_proposer_power,
+                tie_policy: Some(module_config.tie_policy),
+                allow_self_dao: module_config.allow_self_dao,
+                blackout: module_config.blackout,
+                best_effort_execution: module_config.best_effort_execution,
+                unstake_cooldown: module_config.unstake_cooldown,
+                proposer_must_execute: module_config.proposer_must_execute,
+                proposer_execution_grace_period: module_config.proposer_execution_grace_period,
+                proposer_nft_gate: None,
+                fallback_voting_module: None,
+                require_discussion_url: false,
+                abstain_quorum_weight: None,
+                confirmation_phase: None,
+                execution_delay: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into(),
+    ];
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, msgs);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let res = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap();
+
+    let state_hashes: Vec<String> = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .filter(|attribute| attribute.key == "state_hash")
+        .map(|attribute| attribute.value.clone())
+        .collect();
+
+    // Hashes are of the post-execution `Config {}` query responses.
+    let hash = |contract: &Addr, config: Binary| -> String {
+        let hash: String = Sha256::digest(config)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        format!("{contract}:{hash}")
+    };
+    let dao_config: Binary = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &dao_core::msg::QueryMsg::Config {})
+        .unwrap();
+    let module_config: Binary = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        state_hashes,
+        vec![
+            hash(&core_addr, dao_config),
+            hash(&proposal_module, module_config)
+        ]
+    );
+    assert!(query_proposal_config(&app, &proposal_module).allow_revoting);
+
+    // Proposals that do not update a known contract's config do not
+    // emit hashes.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let res = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap();
+    assert!(!res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .any(|attribute| attribute.key == "state_hash"));
+}
+
+#[test]
+fn test_can_revote() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Revoting is disabled by default.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    assert_eq!(
+        query_can_revote(&app, &proposal_module, proposal_id, CREATOR_ADDR),
+        CanRevoteResponse {
+            can_revote: false,
+            reason: Some("revoting is not allowed on this proposal".to_string()),
+        }
+    );
+
+    let mut config = query_proposal_config(&app, &proposal_module);
+    config.allow_revoting = true;
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.to_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            max_execution_attempts: config.max_execution_attempts,
+            max_msgs_per_execution: config.max_msgs_per_execution,
+            audited_only: config.audited_only,
+            max_proposal_msgs: config.max_proposal_msgs,
+            text_limits: config.text_limits,
+            min_proposer_power: config.min_proposer_power,
+            tie_policy: Some(config.tie_policy),
+            allow_self_dao: config.allow_self_dao,
+            blackout: config.blackout,
+            best_effort_execution: config.best_effort_execution,
+            unstake_cooldown: config.unstake_cooldown,
+            proposer_must_execute: config.proposer_must_execute,
+            proposer_execution_grace_period: config.proposer_execution_grace_period,
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    assert_eq!(
+        query_can_revote(&app, &proposal_module, proposal_id, CREATOR_ADDR),
+        CanRevoteResponse {
+            can_revote: false,
+            reason: Some("voter has not voted on this proposal".to_string()),
+        }
+    );
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    assert_eq!(
+        query_can_revote(&app, &proposal_module, proposal_id, CREATOR_ADDR),
+        CanRevoteResponse {
+            can_revote: true,
+            reason: None,
+        }
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    assert_eq!(
+        query_can_revote(&app, &proposal_module, proposal_id, CREATOR_ADDR),
+        CanRevoteResponse {
+            can_revote: false,
+            reason: Some("proposal has expired".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_vote_status() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(5),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // An address with voting power that has not voted may vote.
+    assert_eq!(
+        query_vote_status(&app, &proposal_module, proposal_id, "ekez"),
+        VoteStatusResponse {
+            can_vote: true,
+            has_voted: false,
+            power: Uint128::new(5),
+        }
+    );
+
+    // Revoting is disabled by default, so an address that has voted
+    // may not vote again.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::No,
+    );
+    assert_eq!(
+        query_vote_status(&app, &proposal_module, proposal_id, CREATOR_ADDR),
+        VoteStatusResponse {
+            can_vote: false,
+            has_voted: true,
+            power: Uint128::new(10),
+        }
+    );
+
+    // Addresses without voting power may not vote.
+    assert_eq!(
+        query_vote_status(&app, &proposal_module, proposal_id, "keze"),
+        VoteStatusResponse {
+            can_vote: false,
+            has_voted: false,
+            power: Uint128::zero(),
+        }
+    );
+
+    // No one may vote once the proposal has expired.
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    assert!(!query_vote_status(&app, &proposal_module, proposal_id, "ekez").can_vote);
+}
+
+#[test]
+fn test_votes_by_tier() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let members = [
+        ("ekez", 1),
+        ("keze", 2),
+        ("zeke", 3),
+        ("whale", 50),
+        (CREATOR_ADDR, 60),
+    ];
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(
+            members
+                .iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(*amount),
+                })
+                .collect(),
+        ),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "keze", proposal_id, Vote::No);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "zeke",
+        proposal_id,
+        Vote::Abstain,
+    );
+    vote_on_proposal(&mut app, &proposal_module, "whale", proposal_id, Vote::No);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    let tally = |count: u64, power: u128| TierTally {
+        count,
+        power: Uint128::new(power),
+    };
+    let tiers = |mins: &[u128]| mins.iter().copied().map(Uint128::new).collect::<Vec<_>>();
+
+    // Voters are counted in the highest tier their power reaches.
+    assert_eq!(
+        query_votes_by_tier(&app, &proposal_module, proposal_id, tiers(&[0, 10, 100])),
+        VotesByTierResponse {
+            tiers: vec![
+                TierVotes {
+                    min_power: Uint128::zero(),
+                    yes: tally(1, 1),
+                    no: tally(1, 2),
+                    abstain: tally(1, 3),
+                },
+                TierVotes {
+                    min_power: Uint128::new(10),
+                    yes: tally(1, 60),
+                    no: tally(1, 50),
+                    abstain: TierTally::default(),
+                },
+                TierVotes {
+                    min_power: Uint128::new(100),
+                    yes: TierTally::default(),
+                    no: TierTally::default(),
+                    abstain: TierTally::default(),
+                },
+            ]
+        }
+    );
+
+    // Voters below the lowest tier are not counted.
+    assert_eq!(
+        query_votes_by_tier(&app, &proposal_module, proposal_id, tiers(&[2])),
+        VotesByTierResponse {
+            tiers: vec![TierVotes {
+                min_power: Uint128::new(2),
+                yes: tally(1, 60),
+                no: tally(2, 52),
+                abstain: tally(1, 3),
+            }]
+        }
+    );
+
+    // Tiers must be strictly ascending and bounded in number.
+    let query = |tiers| {
+        app.wrap().query_wasm_smart::<VotesByTierResponse>(
+            &proposal_module,
+            &QueryMsg::VotesByTier { proposal_id, tiers },
+        )
+    };
+    let err = query(tiers(&[10, 10])).unwrap_err();
+    assert!(err.to_string().contains("strictly ascending"));
+    let err = query((0..11).map(Uint128::new).collect()).unwrap_err();
+    assert!(err.to_string().contains("at most 10 tiers"));
+}
+
+#[test]
+fn test_status_history() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let start_height = app.block_info().height;
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    app.update_block(next_block);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    app.update_block(next_block);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    assert_eq!(
+        query_status_history(&app, &proposal_module, proposal_id),
+        vec![
+            StatusTransition {
+                status: Status::Open,
+                height: start_height,
+                cause: "propose".to_string(),
+            },
+            StatusTransition {
+                status: Status::Passed,
+                height: start_height + 1,
+                cause: "vote".to_string(),
+            },
+            StatusTransition {
+                status: Status::Executed,
+                height: start_height + 2,
+                cause: "execute".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_text_limits() {
+    // A signaling poll that allows long descriptions and a parameter
+    // change module that does not.
+    let mut app = App::default();
+    let mut signaling = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    signaling.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    signaling.text_limits = Some(ProposalTextLimits {
+        max_title_length: Some(10),
+        max_description_length: Some(100),
+    });
+    let mut parameter_change = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    parameter_change.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    parameter_change.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(33)),
+    };
+    parameter_change.text_limits = Some(ProposalTextLimits {
+        max_title_length: Some(10),
+        max_description_length: Some(20),
+    });
+
+    let signaling_core = instantiate_with_cw4_groups_governance(&mut app, signaling, None);
+    let signaling = query_single_proposal_module(&app, &signaling_core);
+    let parameter_change_core =
+        instantiate_with_cw4_groups_governance(&mut app, parameter_change, None);
+    let parameter_change = query_single_proposal_module(&app, &parameter_change_core);
+
+    let propose = |app: &mut App, module: &Addr, title: &str, description: &str| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: title.to_string(),
+                description: description.to_string(),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+    };
+
+    let description = "a".repeat(50);
+    propose(&mut app, &signaling, "title", &description).unwrap();
+    let err: ContractError = propose(&mut app, &parameter_change, "title", &description)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::TextLimitError(TextLimitError::DescriptionTooLong {
+            length: 50,
+            max: 20
+        })
+    ));
+    propose(&mut app, &parameter_change, "title", "short").unwrap();
+
+    let err: ContractError = propose(&mut app, &signaling, "a long title", "")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::TextLimitError(TextLimitError::TitleTooLong {
+            length: 12,
+            max: 10
+        })
+    ));
+}
+
+#[test]
+fn test_min_proposer_power() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    assert_eq!(query_min_proposer_power(&app, &proposal_module), None);
+
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.min_proposer_power = Some(Uint128::new(2));
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(2),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    assert_eq!(
+        query_min_proposer_power(&app, &proposal_module),
+        Some(Uint128::new(2))
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::InsufficientProposerPower { power, min }
+            if power == Uint128::new(1) && min == Uint128::new(2)
+    ));
+
+    make_proposal(&mut app, &proposal_module, "whale", vec![]);
+}
+
+#[test]
+fn test_tie_policy() {
+    let tied_status = |tie_policy: Option<SingleChoiceTiePolicy>| {
+        let mut app = App::default();
+        let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+        instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+        // Without a tie policy, a tie would pass a 50% threshold.
+        instantiate.threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Percent(Decimal::percent(50)),
+            quorum: PercentageThreshold::Majority {},
+        };
+        instantiate.tie_policy = tie_policy;
+        let core_addr = instantiate_with_cw4_groups_governance(
+            &mut app,
+            instantiate,
+            Some(vec![
+                Cw20Coin {
+                    address: CREATOR_ADDR.to_string(),
+                    amount: Uint128::new(1),
+                },
+                Cw20Coin {
+                    address: "ekez".to_string(),
+                    amount: Uint128::new(1),
+                },
+            ]),
+        );
+        let proposal_module = query_single_proposal_module(&app, &core_addr);
+        let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+        vote_on_proposal(
+            &mut app,
+            &proposal_module,
+            CREATOR_ADDR,
+            proposal_id,
+            Vote::Yes,
+        );
+        vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::No);
+        query_proposal(&app, &proposal_module, proposal_id)
+            .proposal
+            .status
+    };
+
+    assert_eq!(tied_status(None), Status::Rejected);
+    assert_eq!(
+        tied_status(Some(SingleChoiceTiePolicy::Reject)),
+        Status::Rejected
+    );
+    assert_eq!(
+        tied_status(Some(SingleChoiceTiePolicy::Pass)),
+        Status::Passed
+    );
+}
+
+#[test]
+fn test_quorum_gap() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+    };
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(90),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    assert_eq!(
+        query_quorum_gap(&app, &proposal_module, proposal_id),
+        Uint128::new(50)
+    );
+
+    // Below quorum.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Abstain,
+    );
+    assert_eq!(
+        query_quorum_gap(&app, &proposal_module, proposal_id),
+        Uint128::new(40)
+    );
+
+    // Above quorum.
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    assert_eq!(
+        query_quorum_gap(&app, &proposal_module, proposal_id),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_self_dao_rejected() {
+    use crate::contract::instantiate;
+    use cosmwasm_std::testing::{mock_info, MOCK_CONTRACT_ADDR};
+
+    let mut app = App::default();
+    let mut instantiate_msg = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate_msg.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+
+    // The module may not be instantiated by itself, making it its
+    // own DAO.
+    let mut deps = mock_dependencies();
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        instantiate_msg.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::SelfDao {}));
+
+    // Unless explicitly allowed.
+    instantiate_msg.allow_self_dao = true;
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        instantiate_msg.clone(),
+    )
+    .unwrap();
+
+    // The DAO may not be updated to the module either.
+    instantiate_msg.allow_self_dao = false;
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate_msg, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::Config {})
+        .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            core_addr,
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateConfig {
+                threshold: config.threshold,
+                max_voting_period: config.max_voting_period,
+                min_voting_period: config.min_voting_period,
+                only_members_execute: config.only_members_execute,
+                allow_revoting: config.allow_revoting,
+                dao: proposal_module.to_string(),
+                close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+                max_execution_attempts: config.max_execution_attempts,
+                max_msgs_per_execution: config.max_msgs_per_execution,
+                audited_only: config.audited_only,
+                max_proposal_msgs: config.max_proposal_msgs,
+                text_limits: config.text_limits,
+                min_proposer_power: config.min_proposer_power,
+                tie_policy: Some(config.tie_policy),
+                allow_self_dao: false,
+                blackout: None,
+                best_effort_execution: false,
+                unstake_cooldown: None,
+                proposer_must_execute: false,
+                proposer_execution_grace_period: None,
+                proposer_nft_gate: None,
+                fallback_voting_module: None,
+                require_discussion_url: false,
+                abstain_quorum_weight: None,
+                confirmation_phase: None,
+                execution_delay: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::SelfDao {}));
+}
+
+#[test]
+fn test_proposals_by_proposer() {
+    use crate::query::ProposalListResponse;
+
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    make_proposal(&mut app, &proposal_module, "ekez", vec![]);
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    let ids = |res: ProposalListResponse| -> Vec<u64> {
+        res.proposals.into_iter().map(|p| p.id).collect()
+    };
+
+    let res = query_proposals_by_proposer(&app, &proposal_module, CREATOR_ADDR, None, None);
+    assert!(res
+        .proposals
+        .iter()
+        .all(|p| p.proposal.proposer == Addr::unchecked(CREATOR_ADDR)));
+    assert_eq!(ids(res), vec![1, 3, 4]);
+    assert_eq!(
+        ids(query_proposals_by_proposer(
+            &app,
+            &proposal_module,
+            "ekez",
+            None,
+            None
+        )),
+        vec![2]
+    );
+
+    // Pagination.
+    assert_eq!(
+        ids(query_proposals_by_proposer(
+            &app,
+            &proposal_module,
+            CREATOR_ADDR,
+            Some(1),
+            Some(1)
+        )),
+        vec![3]
+    );
+    assert!(
+        query_proposals_by_proposer(&app, &proposal_module, "keze", None, None)
+            .proposals
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_proposals_sending_to() {
+    use crate::query::{Cw20Amount, ProposalSends};
+
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let bank_send = |to: &str, amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: to.to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let cw20_transfer = |to: &str, amount: u128| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: "token".to_string(),
+            msg: to_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                recipient: to.to_string(),
+                amount: Uint128::new(amount),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+
+    make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![bank_send("ekez", 10), bank_send("ekez", 5)],
+    );
+    make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![bank_send("keze", 10), cw20_transfer("keze", 10)],
+    );
+    make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![cw20_transfer("ekez", 7), bank_send("keze", 10)],
+    );
+
+    let res = query_proposals_sending_to(&app, &proposal_module, "ekez", None, None);
+    assert_eq!(
+        res.proposals,
+        vec![
+            ProposalSends {
+                proposal_id: 1,
+                native: coins(15, "ujuno"),
+                cw20: vec![],
+            },
+            ProposalSends {
+                proposal_id: 3,
+                native: vec![],
+                cw20: vec![Cw20Amount {
+                    token: "token".to_string(),
+                    amount: Uint128::new(7),
+                }],
+            },
+        ]
+    );
+
+    // The limit bounds the proposals scanned, not the proposals
+    // returned.
+    let res = query_proposals_sending_to(&app, &proposal_module, "ekez", Some(1), Some(1));
+    assert!(res.proposals.is_empty());
+    let res = query_proposals_sending_to(&app, &proposal_module, "ekez", Some(1), Some(2));
+    assert_eq!(res.proposals.len(), 1);
+    assert_eq!(res.proposals[0].proposal_id, 3);
+
+    assert!(
+        query_proposals_sending_to(&app, &proposal_module, "nobody", None, None)
+            .proposals
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_best_effort_execution_report() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.best_effort_execution = true;
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    mint_natives(&mut app, core_addr.as_str(), coins(100, "ujuno"));
+
+    let bank_send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+
+    // The second message fails as the DAO does not have enough
+    // tokens.
+    let proposal_id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![bank_send(10), bank_send(1000), bank_send(5)],
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(15)
+    );
+
+    let report = query_execution_report(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        report.iter().map(|m| m.success).collect::<Vec<_>>(),
+        vec![true, false, true]
+    );
+    assert!(report[0].error.is_none());
+    assert!(!report[0].events.is_empty());
+    assert!(report[1].error.is_some());
+    assert!(report[1].events.is_empty());
+}
+
+#[test]
+fn test_message_origin() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    mint_natives(&mut app, core_addr.as_str(), coins(100, "ujuno"));
+
+    let bank_send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let hash = |msg: &CosmosMsg| -> String {
+        Sha256::digest(cosmwasm_std::to_vec(msg).unwrap())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    };
+    let pass_and_execute = |app: &mut App, msgs: Vec<CosmosMsg>| -> u64 {
+        let proposal_id = make_proposal(app, &proposal_module, CREATOR_ADDR, msgs);
+        vote_on_proposal(app, &proposal_module, CREATOR_ADDR, proposal_id, Vote::Yes);
+        execute_proposal(app, &proposal_module, CREATOR_ADDR, proposal_id);
+        proposal_id
+    };
+
+    let transfer = bank_send(10);
+    assert_eq!(
+        query_message_origin(&app, &proposal_module, "ekez", &hash(&transfer)),
+        None
+    );
+
+    let proposal_id = pass_and_execute(&mut app, vec![bank_send(5), transfer.clone()]);
+    assert_eq!(
+        query_message_origin(&app, &proposal_module, "ekez", &hash(&transfer)),
+        Some(proposal_id)
+    );
+    assert_eq!(
+        query_message_origin(&app, &proposal_module, "ekez", &hash(&bank_send(5))),
+        Some(proposal_id)
+    );
+    // The hash is only matched against messages sent to CONTRACT.
+    assert_eq!(
+        query_message_origin(&app, &proposal_module, "keze", &hash(&transfer)),
+        None
+    );
+
+    // The most recent proposal to execute a message is its origin.
+    let repeated = pass_and_execute(&mut app, vec![transfer.clone()]);
+    assert_eq!(
+        query_message_origin(&app, &proposal_module, "ekez", &hash(&transfer)),
+        Some(repeated)
+    );
+
+    // Messages of proposals whose execution failed are not indexed.
+    let overdraft = bank_send(1000);
+    let failed = pass_and_execute(&mut app, vec![overdraft.clone()]);
+    let proposal = query_proposal(&app, &proposal_module, failed);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    assert_eq!(
+        query_message_origin(&app, &proposal_module, "ekez", &hash(&overdraft)),
+        None
+    );
+}
+
+#[test]
+fn test_unstake_cooldown() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.unstake_cooldown = Some(Duration::Height(10));
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let voting_module = query_voting_module(&app, &core_addr);
+    let staking_contract: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module,
+            &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+        )
+        .unwrap();
+
+    // Addresses that have never unstaked are not in a cooldown.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        staking_contract,
+        &cw20_stake::msg::ExecuteMsg::Unstake {
+            amount: Uint128::new(1),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: Vote::Yes,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::UnstakeCooldown { .. }));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::UnstakeCooldown { .. }));
+
+    // Once the cooldown ends the address may vote again.
+    app.update_block(|block| block.height += 9);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+}
+
+#[test]
+fn test_unstake_cooldown_unsupported() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // cw4 voting modules do not stake, so there is no unstake to
+    // cool down from.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module,
+            &ExecuteMsg::UpdateConfig {
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                max_voting_period: Duration::Height(10),
+                min_voting_period: None,
+                only_members_execute: false,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                close_proposal_on_execution_failure: true,
+                max_execution_attempts: None,
+                max_msgs_per_execution: None,
+                audited_only: false,
+                max_proposal_msgs: None,
+                text_limits: None,
+                min_proposer_power: None,
+                tie_policy: None,
+                allow_self_dao: false,
+                blackout: None,
+                best_effort_execution: false,
+                unstake_cooldown: Some(Duration::Height(10)),
+                proposer_must_execute: false,
+                proposer_execution_grace_period: None,
+                proposer_nft_gate: None,
+                fallback_voting_module: None,
+                require_discussion_url: false,
+                abstain_quorum_weight: None,
+                confirmation_phase: None,
+                execution_delay: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::UnstakeCooldownUnsupported {}));
+}
+
+#[test]
+fn test_closeable_proposals() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let mut propose_and_vote = |vote: Option<Vote>| -> u64 {
+        let id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+        if let Some(vote) = vote {
+            vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, id, vote);
+        }
+        id
+    };
+    propose_and_vote(Some(Vote::Yes)); // Passed.
+    let rejected = propose_and_vote(Some(Vote::No));
+    propose_and_vote(None); // Open.
+    let closed = propose_and_vote(Some(Vote::No));
+    let rejected_too = propose_and_vote(Some(Vote::No));
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, closed);
+
+    let ids = |app: &App, start_after: Option<u64>, limit: Option<u64>| -> Vec<u64> {
+        query_closeable_proposals(app, &proposal_module, start_after, limit)
+            .proposals
+            .into_iter()
+            .map(|p| p.id)
+            .collect()
+    };
+    assert_eq!(ids(&app, None, None), vec![rejected, rejected_too]);
+    // The limit bounds the proposals examined, not those returned.
+    assert_eq!(ids(&app, Some(rejected), Some(1)), Vec::<u64>::new());
+    assert_eq!(ids(&app, Some(rejected), Some(3)), vec![rejected_too]);
+}
+
+#[test]
+fn test_proposal_summaries() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    })];
+    let passed = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, msgs);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passed, Vote::Yes);
+    let expired = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // The stored status of the unvoted proposal is still open, but
+    // summaries report its status at the current block.
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    assert_eq!(
+        query_proposal_summaries(&app, &proposal_module, None, None)
+            .proposals
+            .into_iter()
+            .map(|s| (s.id, s.status))
+            .collect::<Vec<_>>(),
+        vec![(passed, Status::Passed), (expired, Status::Rejected)]
+    );
+
+    let summaries = query_proposal_summaries(&app, &proposal_module, Some(passed), Some(1));
+    assert_eq!(summaries.proposals.len(), 1);
+    let summary = &summaries.proposals[0];
+    let proposal = query_proposal(&app, &proposal_module, expired).proposal;
+    assert_eq!(summary.id, expired);
+    assert_eq!(summary.title, proposal.title);
+    assert_eq!(summary.proposer, proposal.proposer);
+    assert_eq!(summary.expiration, proposal.expiration);
+
+    // Messages and tallies are not included.
+    let raw = to_binary(&query_proposal_summaries(
+        &app,
+        &proposal_module,
+        None,
+        None,
+    ))
+    .unwrap();
+    let raw = String::from_utf8(raw.to_vec()).unwrap();
+    assert!(!raw.contains("msgs"));
+    assert!(!raw.contains("votes"));
+}
+
+#[test]
+fn test_proposer_must_execute() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.only_members_execute = false;
+    instantiate.proposer_must_execute = true;
+    instantiate.proposer_execution_grace_period = Some(Duration::Time(86400));
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let by_proposer = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        by_proposer,
+        Vote::Yes,
+    );
+    let by_anyone = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        by_anyone,
+        Vote::Yes,
+    );
+
+    // Within the grace period only the proposer may execute.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, "ekez", by_anyone);
+    assert!(matches!(err, ContractError::ProposerMustExecute { .. }));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, by_proposer);
+
+    // Still within the grace period after the proposal expires.
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, "ekez", by_anyone);
+    assert!(matches!(err, ContractError::ProposerMustExecute { .. }));
+
+    // Anyone may execute once the grace period ends.
+    app.update_block(|b| b.time = b.time.plus_seconds(86400));
+    execute_proposal(&mut app, &proposal_module, "ekez", by_anyone);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, by_anyone)
+            .proposal
+            .status,
+        Status::Executed
+    );
+}
+
+#[test]
+fn test_normalized_threshold() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+    };
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let normalized = query_normalized_threshold(&app, &proposal_module);
+    assert_eq!(
+        normalized,
+        NormalizedThreshold {
+            quorum: Some(PercentageThreshold::Percent(Decimal::percent(20))),
+            threshold: Some(PercentageThreshold::Majority {}),
+            absolute: None,
+            veto: None,
+        }
+    );
+
+    // The quorum is comparable with that of a multiple choice module
+    // using the same quorum.
+    let multiple = dao_voting::multiple_choice::VotingStrategy::SingleChoice {
+        quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+    }
+    .normalize();
+    assert_eq!(normalized.quorum, multiple.quorum);
+}
+
+#[test]
+fn test_close_expired() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: dao_voting::deposit::DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    mint_natives(&mut app, CREATOR_ADDR, coins(50, "ujuno"));
+
+    let passed = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passed, Vote::Yes);
+    let expired: Vec<u64> = (0..3)
+        .map(|_| make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]))
+        .collect();
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let open = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    assert_eq!(
+        query_balance_native(&app, CREATOR_ADDR, "ujuno"),
+        Uint128::zero()
+    );
+
+    let close_expired =
+        |app: &mut App, start_after: Option<u64>, limit: Option<u32>| -> (String, String) {
+            let res = app
+                .execute_contract(
+                    Addr::unchecked(CREATOR_ADDR),
+                    proposal_module.clone(),
+                    &ExecuteMsg::CloseExpired { start_after, limit },
+                    &[],
+                )
+                .unwrap();
+            let attribute = |key: &str| {
+                res.events
+                    .iter()
+                    .flat_map(|event| &event.attributes)
+                    .find(|attr| attr.key == key)
+                    .unwrap()
+                    .value
+                    .clone()
+            };
+            (attribute("proposal_ids"), attribute("last_scanned"))
+        };
+
+    // Rejected proposals are closed in order up to the limit, and
+    // scanning resumes after the last proposal examined.
+    assert_eq!(
+        close_expired(&mut app, None, Some(2)),
+        (
+            format!("{},{}", expired[0], expired[1]),
+            expired[1].to_string()
+        )
+    );
+    assert_eq!(
+        close_expired(&mut app, Some(expired[1]), None),
+        (expired[2].to_string(), open.to_string())
+    );
+    assert_eq!(
+        close_expired(&mut app, None, None),
+        ("".to_string(), open.to_string())
+    );
+    assert_eq!(
+        close_expired(&mut app, Some(open), None),
+        ("".to_string(), "_none".to_string())
+    );
+
+    for id in expired {
+        let proposal = query_proposal(&app, &proposal_module, id);
+        assert_eq!(proposal.proposal.status, Status::Closed);
+    }
+    let proposal = query_proposal(&app, &proposal_module, passed);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    let proposal = query_proposal(&app, &proposal_module, open);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // Deposits of the closed proposals were refunded.
+    assert_eq!(
+        query_balance_native(&app, CREATOR_ADDR, "ujuno"),
+        Uint128::new(30)
+    );
+}
+
+#[test]
+fn test_proposals_by_execution_outcome() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let mut passed_proposal = |msgs: Vec<CosmosMsg>| -> u64 {
+        let id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, msgs);
+        vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, id, Vote::Yes);
+        id
+    };
+    let succeeded = passed_proposal(vec![]);
+    // The DAO has no tokens to send, so this fails to execute.
+    let failed = passed_proposal(vec![BankMsg::Send {
+        to_address: CREATOR_ADDR.to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()]);
+    let pending = passed_proposal(vec![]);
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, succeeded);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, failed);
+
+    let ids = |app: &App, outcome: ExecutionOutcome| -> Vec<u64> {
+        query_proposals_by_execution_outcome(app, &proposal_module, outcome, None, None)
+            .proposals
+            .into_iter()
+            .map(|p| p.id)
+            .collect()
+    };
+    assert_eq!(ids(&app, ExecutionOutcome::Succeeded), vec![succeeded]);
+    assert_eq!(ids(&app, ExecutionOutcome::Failed), vec![failed]);
+    assert_eq!(ids(&app, ExecutionOutcome::Pending), vec![pending]);
+
+    let proposals = query_proposals_by_execution_outcome(
+        &app,
+        &proposal_module,
+        ExecutionOutcome::Failed,
+        Some(failed),
+        None,
+    );
+    assert!(proposals.proposals.is_empty());
+
+    // The limit bounds the proposals examined, not those returned.
+    let proposals = query_proposals_by_execution_outcome(
+        &app,
+        &proposal_module,
+        ExecutionOutcome::Pending,
+        None,
+        Some(2),
+    );
+    assert!(proposals.proposals.is_empty());
+}
+
+#[test]
+fn test_proposer_nft_gate() {
+    let mut app = App::default();
+    let cw721_id = app.store_code(cw721_base_contract());
+    let collection = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw721_base::msg::InstantiateMsg {
+                minter: CREATOR_ADDR.to_string(),
+                symbol: "FOUNDER".to_string(),
+                name: "founding members".to_string(),
+            },
+            &[],
+            "founding members",
+            None,
+        )
+        .unwrap();
+    for (owner, count) in [(CREATOR_ADDR, 2), ("ekez", 1)] {
+        for i in 0..count {
+            app.execute_contract(
+                Addr::unchecked(CREATOR_ADDR),
+                collection.clone(),
+                &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::Mint(
+                    cw721_base::msg::MintMsg::<Option<Empty>> {
+                        token_id: format!("{owner}_{i}"),
+                        owner: owner.to_string(),
+                        token_uri: None,
+                        extension: None,
+                    },
+                ),
+                &[],
+            )
+            .unwrap();
+        }
+    }
+
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.proposer_nft_gate = Some(UncheckedProposerNftGate {
+        collection: collection.to_string(),
+        min_count: 2,
+    });
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, proposer: &str| {
+        app.execute_contract(
+            Addr::unchecked(proposer),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: None,
+            }),
+            &[],
+        )
+    };
+
+    // Holders of too few NFTs may not propose.
+    for (proposer, count) in [("ekez", 1), ("keze", 0)] {
+        let err: ContractError = propose(&mut app, proposer).unwrap_err().downcast().unwrap();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientProposerNfts { count: c, min: 2 } if c == count
+        ));
+    }
+
+    // Holders of enough NFTs may.
+    propose(&mut app, CREATOR_ADDR).unwrap();
+    assert_eq!(query_next_proposal_id(&app, &proposal_module), 2);
+}
+
+#[test]
+fn test_time_weighted_turnout() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(2),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // No time has passed, so the turnout is the current one.
+    assert_eq!(
+        query_time_weighted_turnout(&app, &proposal_module, proposal_id),
+        Some(Decimal::zero())
+    );
+
+    // 0% turnout for 100 seconds, 25% for 200 seconds, then 50%
+    // for 100 seconds.
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    app.update_block(|block| block.time = block.time.plus_seconds(200));
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::No);
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+    assert_eq!(
+        query_time_weighted_turnout(&app, &proposal_module, proposal_id),
+        Some(Decimal::from_ratio(200u128 + 2 * 100, 4u128 * 400))
+    );
+
+    // Time after the proposal expires is not counted.
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    assert_eq!(
+        query_time_weighted_turnout(&app, &proposal_module, proposal_id),
+        Some(Decimal::from_ratio(
+            200u128 + 2 * (604800 - 300),
+            4u128 * 604800
+        ))
+    );
+}
+
+#[test]
+fn test_fallback_voting_module() {
+    // Code a voting module may be migrated to that no longer answers
+    // queries.
+    fn broken_query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        Err(StdError::generic_err("broken"))
+    }
+    fn broken_noop(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+    fn broken_migrate(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+
+    let mut app = App::default();
+    // The fallback's weights differ from the DAO's so that it is
+    // clear which was queried.
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let fallback_dao = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let fallback = query_voting_module(&app, &fallback_dao);
+
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.fallback_voting_module = Some(fallback.to_string());
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // The fallback is not queried while the DAO answers queries.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.total_power, Uint128::new(1));
+
+    let broken_id = app.store_code(Box::new(
+        ContractWrapper::new(broken_noop, broken_noop, broken_query).with_migrate(broken_migrate),
+    ));
+    let voting_module = query_voting_module(&app, &core_addr);
+    app.execute(
+        core_addr,
+        WasmMsg::Migrate {
+            contract_addr: voting_module.to_string(),
+            new_code_id: broken_id,
+            msg: to_binary(&Empty {}).unwrap(),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.total_power, Uint128::new(4));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.votes.yes, Uint128::new(3));
+}
+
+#[test]
+fn test_expiring_proposals() {
+    const DAY: u64 = 24 * 60 * 60;
+
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.max_voting_period = Duration::Time(7 * DAY);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let first = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    app.update_block(|block| block.time = block.time.plus_seconds(3 * DAY));
+    let second = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    // Passed proposals are no longer open and are not listed.
+    let passed = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passed, Vote::Yes);
+
+    let ids = |app: &App, within: Duration, start_after: Option<u64>| -> Vec<u64> {
+        query_expiring_proposals(app, &proposal_module, within, start_after, None)
+            .proposals
+            .into_iter()
+            .map(|p| p.id)
+            .collect()
+    };
+    assert_eq!(ids(&app, Duration::Time(DAY), None), Vec::<u64>::new());
+    assert_eq!(ids(&app, Duration::Time(5 * DAY), None), vec![first]);
+    assert_eq!(
+        ids(&app, Duration::Time(7 * DAY), None),
+        vec![first, second]
+    );
+    assert_eq!(
+        ids(&app, Duration::Time(7 * DAY), Some(first)),
+        vec![second]
+    );
+    // Height windows do not match time based expirations.
+    assert_eq!(
+        ids(&app, Duration::Height(1_000_000), None),
+        Vec::<u64>::new()
+    );
+
+    // Expired proposals are no longer open.
+    app.update_block(|block| block.time = block.time.plus_seconds(5 * DAY));
+    assert_eq!(ids(&app, Duration::Time(7 * DAY), None), vec![second]);
+
+    // The limit bounds the proposals examined, not those returned.
+    let limited = query_expiring_proposals(
+        &app,
+        &proposal_module,
+        Duration::Time(7 * DAY),
+        None,
+        Some(1),
+    );
+    assert!(limited.proposals.is_empty());
+}
+
+#[test]
+fn test_proposal_duration() {
+    const DAY: u64 = 24 * 60 * 60;
+
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.max_voting_period = Duration::Time(7 * DAY);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, proposal_duration: Option<Duration>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration,
+                discussion_url: None,
+            }),
+            &[],
+        )
+    };
+
+    // A shorter voting period sets an earlier expiration.
+    propose(&mut app, Some(Duration::Time(DAY))).unwrap();
+    propose(&mut app, None).unwrap();
+    let block = app.block_info();
+    let short = query_proposal(&app, &proposal_module, 1).proposal;
+    let default = query_proposal(&app, &proposal_module, 2).proposal;
+    assert_eq!(
+        short.expiration,
+        Expiration::AtTime(block.time.plus_seconds(DAY))
+    );
+    assert_eq!(
+        default.expiration,
+        Expiration::AtTime(block.time.plus_seconds(7 * DAY))
+    );
+    assert_eq!(short.start_height, default.start_height);
+    assert_eq!(short.total_power, default.total_power);
+
+    // Voting periods longer than the max may not be requested.
+    let err: ContractError = propose(&mut app, Some(Duration::Time(8 * DAY)))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ProposalDurationTooLong {}));
+
+    let err: ContractError = propose(&mut app, Some(Duration::Height(10)))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::ProposalDurationUnitsConflict {}
+    ));
+    assert_eq!(query_next_proposal_id(&app, &proposal_module), 3);
+
+    // Proposals with shorter voting periods expire first.
+    app.update_block(|block| block.time = block.time.plus_seconds(DAY));
+    assert_eq!(
+        query_proposal(&app, &proposal_module, 1).proposal.status,
+        Status::Rejected
+    );
+    assert_eq!(
+        query_proposal(&app, &proposal_module, 2).proposal.status,
+        Status::Open
+    );
+}
+
+#[test]
+fn test_require_discussion_url() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.require_discussion_url = true;
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, discussion_url: Option<&str>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                precondition: None,
+                proposal_duration: None,
+                discussion_url: discussion_url.map(str::to_string),
+            }),
+            &[],
+        )
+    };
+
+    // Proposals without a discussion reference are rejected.
+    for discussion_url in [None, Some(""), Some("  ")] {
+        let err: ContractError = propose(&mut app, discussion_url)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert!(matches!(err, ContractError::MissingDiscussionUrl {}));
+    }
+
+    propose(&mut app, Some("https://forum.example.com/t/1")).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, 1).proposal;
+    assert_eq!(
+        proposal.discussion_url,
+        Some("https://forum.example.com/t/1".to_string())
+    );
+}
+
+#[test]
+fn test_height_based_expiration() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.only_members_execute = false;
+    instantiate.max_voting_period = Duration::Height(10);
+    instantiate.min_voting_period = Some(Duration::Height(2));
+    instantiate.proposer_must_execute = true;
+    instantiate.proposer_execution_grace_period = Some(Duration::Height(5));
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let rejected = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let passed = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passed, Vote::Yes);
+
+    // Only block height moves height based voting periods forward.
+    app.update_block(|b| b.time = b.time.plus_seconds(604800 * 4));
+    assert_eq!(
+        query_proposal(&app, &proposal_module, rejected)
+            .proposal
+            .status,
+        Status::Open
+    );
+    assert_eq!(
+        query_proposal(&app, &proposal_module, passed)
+            .proposal
+            .status,
+        Status::Open
+    );
+
+    // The minimum voting period elapses.
+    app.update_block(|b| b.height += 2);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, rejected)
+            .proposal
+            .status,
+        Status::Open
+    );
+    assert_eq!(
+        query_proposal(&app, &proposal_module, passed)
+            .proposal
+            .status,
+        Status::Passed
+    );
+
+    // The maximum voting period elapses.
+    app.update_block(|b| b.height += 8);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, rejected)
+            .proposal
+            .status,
+        Status::Rejected
+    );
+
+    // The grace period is counted in blocks after expiration.
+    app.update_block(|b| b.height += 4);
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, "ekez", passed);
+    assert!(matches!(err, ContractError::ProposerMustExecute { .. }));
+
+    // Switching the voting period to time based units leaves existing
+    // proposals with a grace period ending at their expiration.
+    let mut config = query_proposal_config(&app, &proposal_module);
+    config.max_voting_period = Duration::Time(604800);
+    config.min_voting_period = None;
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: core_addr.to_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            max_execution_attempts: config.max_execution_attempts,
+            max_msgs_per_execution: config.max_msgs_per_execution,
+            audited_only: config.audited_only,
+            max_proposal_msgs: config.max_proposal_msgs,
+            text_limits: config.text_limits,
+            min_proposer_power: config.min_proposer_power,
+            tie_policy: Some(config.tie_policy),
+            allow_self_dao: false,
+            blackout: None,
+            best_effort_execution: false,
+            unstake_cooldown: None,
+            proposer_must_execute: true,
+            proposer_execution_grace_period: Some(Duration::Time(86400)),
+            proposer_nft_gate: None,
+            fallback_voting_module: None,
+            require_discussion_url: false,
+            abstain_quorum_weight: None,
+            confirmation_phase: None,
+            execution_delay: None,
+        },
+        &[],
+    )
+    .unwrap();
+    execute_proposal(&mut app, &proposal_module, "ekez", passed);
+    assert_eq!(
+        query_proposal(&app, &proposal_module, passed)
+            .proposal
+            .status,
+        Status::Executed
+    );
+}
+
+#[test]
+fn test_query_parameters() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.min_proposer_power = Some(Uint128::new(5));
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let parameters = query_parameters(&app, &proposal_module);
+    let config = query_proposal_config(&app, &proposal_module);
+    let value = |key: &str| {
+        parameters
+            .iter()
+            .find(|parameter| parameter.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+
+    assert_eq!(parameters.len(), 26);
+    assert_eq!(
+        value("threshold"),
+        r#"{"threshold_quorum":{"threshold":{"percent":"0.15"},"quorum":{"majority":{}}}}"#
+    );
+    assert_eq!(value("max_voting_period"), r#"{"time":604800}"#);
+    assert_eq!(value("min_voting_period"), "null");
+    assert_eq!(value("only_members_execute"), "true");
+    assert_eq!(value("dao"), format!(r#""{}""#, config.dao));
+    assert_eq!(value("min_proposer_power"), r#""5""#);
+    assert_eq!(value("tie_policy"), r#""reject""#);
+
+    // Each value is the JSON encoding of the config field.
+    assert_eq!(
+        value("text_limits"),
+        String::from_utf8(cosmwasm_std::to_vec(&config.text_limits).unwrap()).unwrap()
+    );
+
+    // Every field may be changed with `UpdateConfig`.
+    assert!(parameters.iter().all(|Parameter { mutable, .. }| *mutable));
+}
@@ -16,7 +16,8 @@ use crate::msg::{
     TotalStakedAtHeightResponse, TotalValueResponse,
 };
 use crate::state::{
-    Config, BALANCE, CLAIMS, CONFIG, HOOKS, MAX_CLAIMS, STAKED_BALANCES, STAKED_TOTAL,
+    Config, LastUnstake, BALANCE, CLAIMS, CONFIG, HOOKS, LAST_UNSTAKE, MAX_CLAIMS, STAKED_BALANCES,
+    STAKED_TOTAL,
 };
 use crate::ContractError;
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
@@ -152,6 +153,10 @@ pub fn execute_receive(
     match msg {
         ReceiveMsg::Stake {} => execute_stake(deps, env, sender, wrapper.amount),
         ReceiveMsg::Fund {} => execute_fund(deps, env, &sender, wrapper.amount),
+        ReceiveMsg::StakeFor { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            execute_stake(deps, env, address, wrapper.amount)
+        }
     }
 }
 
@@ -230,6 +235,14 @@ pub fn execute_unstake(
             .checked_sub(amount_to_claim)
             .map_err(StdError::overflow)?,
     )?;
+    LAST_UNSTAKE.save(
+        deps.storage,
+        &info.sender,
+        &LastUnstake {
+            height: env.block.height,
+            time: env.block.time,
+        },
+    )?;
     let hook_msgs = unstake_hook_msgs(deps.storage, info.sender.clone(), amount)?;
     match config.unstaking_duration {
         None => {
@@ -364,6 +377,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::StakedValue { address } => to_binary(&query_staked_value(deps, env, address)?),
         QueryMsg::TotalValue {} => to_binary(&query_total_value(deps, env)?),
         QueryMsg::Claims { address } => to_binary(&query_claims(deps, address)?),
+        QueryMsg::LastUnstake { address } => to_binary(&query_last_unstake(deps, address)?),
         QueryMsg::GetHooks {} => to_binary(&query_hooks(deps)?),
         QueryMsg::ListStakers { start_after, limit } => {
             query_list_stakers(deps, start_after, limit)
@@ -437,6 +451,10 @@ pub fn query_claims(deps: Deps, address: String) -> StdResult<ClaimsResponse> {
     CLAIMS.query_claims(deps, &deps.api.addr_validate(&address)?)
 }
 
+pub fn query_last_unstake(deps: Deps, address: String) -> StdResult<Option<LastUnstake>> {
+    LAST_UNSTAKE.may_load(deps.storage, &deps.api.addr_validate(&address)?)
+}
+
 pub fn query_hooks(deps: Deps) -> StdResult<GetHooksResponse> {
     Ok(GetHooksResponse {
         hooks: HOOKS.query_hooks(deps)?.hooks,
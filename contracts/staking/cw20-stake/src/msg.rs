@@ -34,6 +34,11 @@ pub enum ExecuteMsg {
 pub enum ReceiveMsg {
     Stake {},
     Fund {},
+    /// Stakes the received tokens on behalf of `address` instead of
+    /// the sender.
+    StakeFor {
+        address: String,
+    },
 }
 
 #[cw_serde]
@@ -54,6 +59,10 @@ pub enum QueryMsg {
     GetConfig {},
     #[returns(ClaimsResponse)]
     Claims { address: String },
+    /// Gets the block at which ADDRESS last unstaked, or None if it
+    /// has not unstaked.
+    #[returns(Option<crate::state::LastUnstake>)]
+    LastUnstake { address: String },
     #[returns(GetHooksResponse)]
     GetHooks {},
     #[returns(ListStakersResponse)]
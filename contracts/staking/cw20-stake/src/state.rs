@@ -1,8 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use cw_controllers::Claims;
 use cw_controllers::Hooks;
-use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
 
 #[cw_serde]
@@ -35,5 +35,17 @@ pub const CLAIMS: Claims = Claims::new("claims");
 
 pub const BALANCE: Item<Uint128> = Item::new("balance");
 
+/// The block at which an address last unstaked.
+#[cw_serde]
+pub struct LastUnstake {
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+/// The block at which each address last unstaked. Lets other
+/// contracts, for example proposal modules, enforce a cooldown after
+/// unstaking.
+pub const LAST_UNSTAKE: Map<&Addr, LastUnstake> = Map::new("last_unstake");
+
 // Hooks to contracts that will receive staking and unstaking messages
 pub const HOOKS: Hooks = Hooks::new("hooks");
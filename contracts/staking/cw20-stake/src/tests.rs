@@ -5,7 +5,7 @@ use crate::msg::{
     StakedBalanceAtHeightResponse, StakedValueResponse, StakerBalanceResponse,
     TotalStakedAtHeightResponse, TotalValueResponse,
 };
-use crate::state::{Config, MAX_CLAIMS};
+use crate::state::{Config, LastUnstake, MAX_CLAIMS};
 use crate::ContractError;
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 use cosmwasm_std::{to_binary, Addr, Empty, MessageInfo, Uint128, WasmMsg};
@@ -391,6 +391,85 @@ fn test_staking() {
     assert_eq!(get_balance(&app, &cw20_addr, ADDR1), Uint128::from(30u128));
 }
 
+#[test]
+fn test_last_unstake() {
+    let mut app = mock_app();
+    let initial_balances = vec![Cw20Coin {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(100),
+    }];
+    let (staking_addr, cw20_addr) = setup_test_case(&mut app, initial_balances, None);
+
+    let info = mock_info(ADDR1, &[]);
+    stake_tokens(&mut app, &staking_addr, &cw20_addr, info, Uint128::new(50)).unwrap();
+    app.update_block(next_block);
+
+    let last_unstake: Option<LastUnstake> = app
+        .wrap()
+        .query_wasm_smart(
+            &staking_addr,
+            &QueryMsg::LastUnstake {
+                address: ADDR1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(last_unstake, None);
+
+    let info = mock_info(ADDR1, &[]);
+    unstake_tokens(&mut app, &staking_addr, info, Uint128::new(10)).unwrap();
+
+    let block = app.block_info();
+    let last_unstake: Option<LastUnstake> = app
+        .wrap()
+        .query_wasm_smart(
+            &staking_addr,
+            &QueryMsg::LastUnstake {
+                address: ADDR1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        last_unstake,
+        Some(LastUnstake {
+            height: block.height,
+            time: block.time,
+        })
+    );
+}
+
+#[test]
+fn test_stake_for() {
+    let mut app = mock_app();
+    let initial_balances = vec![Cw20Coin {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(100),
+    }];
+    let (staking_addr, cw20_addr) = setup_test_case(&mut app, initial_balances, None);
+
+    // ADDR1 pays for a stake owned by ADDR2.
+    let msg = cw20::Cw20ExecuteMsg::Send {
+        contract: staking_addr.to_string(),
+        amount: Uint128::new(40),
+        msg: to_binary(&ReceiveMsg::StakeFor {
+            address: ADDR2.to_string(),
+        })
+        .unwrap(),
+    };
+    app.execute_contract(Addr::unchecked(ADDR1), cw20_addr.clone(), &msg, &[])
+        .unwrap();
+    app.update_block(next_block);
+
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR2),
+        Uint128::new(40)
+    );
+    assert_eq!(get_balance(&app, &cw20_addr, ADDR1), Uint128::new(60));
+}
+
 #[test]
 fn text_max_claims() {
     let mut app = mock_app();
@@ -6,18 +6,20 @@ use cosmwasm_std::{
 };
 use cw2::set_contract_version;
 use cw20::{Cw20Coin, TokenInfoResponse};
+use cw20_stake::hooks::StakeChangedHookMsg;
 use cw_utils::parse_reply_instantiate_data;
 use dao_interface::voting::IsActiveResponse;
 use std::convert::TryInto;
 
 use crate::error::ContractError;
 use crate::msg::{
-    ActiveThreshold, ActiveThresholdResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-    StakingInfo, TokenInfo,
+    ActiveThreshold, ActiveThresholdResponse, DerivativeConfigResponse, DerivativeQueryMsg,
+    ExecuteMsg, InstantiateMsg, InstantiationStatusResponse, ListStakersResponse, MigrateMsg,
+    QueryMsg, StakerBalance, StakingInfo, TokenInfo,
 };
 use crate::state::{
-    ACTIVE_THRESHOLD, DAO, STAKING_CONTRACT, STAKING_CONTRACT_CODE_ID,
-    STAKING_CONTRACT_UNSTAKING_DURATION, TOKEN,
+    ACTIVE_THRESHOLD, AUTO_STAKE_BALANCES, DAO, POWER_CHANGED_HOOKS, STAKED_TOKEN,
+    STAKING_CONTRACT, STAKING_CONTRACT_CODE_ID, STAKING_CONTRACT_UNSTAKING_DURATION, TOKEN,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-cw20-staked";
@@ -30,6 +32,9 @@ const INSTANTIATE_STAKING_REPLY_ID: u64 = 1;
 // when using active threshold with percent
 const PRECISION_FACTOR: u128 = 10u128.pow(9);
 
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -63,6 +68,7 @@ pub fn instantiate(
             match staking_contract {
                 StakingInfo::Existing {
                     staking_contract_address,
+                    allow_derivative,
                 } => {
                     let staking_contract_address =
                         deps.api.addr_validate(&staking_contract_address)?;
@@ -71,21 +77,39 @@ pub fn instantiate(
                         &cw20_stake::msg::QueryMsg::GetConfig {},
                     )?;
 
-                    if address != resp.token_address {
+                    // The staking contract's token is compared to the
+                    // governance token first. Only if they differ and
+                    // derivatives are allowed is the staking contract's
+                    // token queried for the token it wraps.
+                    let derivative = address != resp.token_address;
+                    if derivative
+                        && (!allow_derivative
+                            || underlying_token(deps.as_ref(), &resp.token_address).as_ref()
+                                != Some(&address))
+                    {
                         return Err(ContractError::StakingContractMismatch {});
                     }
+                    if derivative {
+                        STAKED_TOKEN.save(deps.storage, &resp.token_address)?;
+                    }
 
                     STAKING_CONTRACT.save(deps.storage, &staking_contract_address)?;
-                    Ok(Response::default()
+                    let response = Response::default()
                         .add_attribute("action", "instantiate")
                         .add_attribute("token", "existing_token")
                         .add_attribute("token_address", address)
-                        .add_attribute("staking_contract", staking_contract_address))
+                        .add_attribute("staking_contract", staking_contract_address);
+                    Ok(if derivative {
+                        response.add_attribute("staked_token", resp.token_address)
+                    } else {
+                        response
+                    })
                 }
                 StakingInfo::New {
                     staking_code_id,
                     unstaking_duration,
                 } => {
+                    STAKING_CONTRACT_UNSTAKING_DURATION.save(deps.storage, &unstaking_duration)?;
                     let msg = WasmMsg::Instantiate {
                         code_id: staking_code_id,
                         funds: vec![],
@@ -117,6 +141,7 @@ pub fn instantiate(
             marketing,
             staking_code_id,
             unstaking_duration,
+            auto_stake,
         } => {
             let initial_supply = initial_balances
                 .iter()
@@ -127,6 +152,20 @@ pub fn instantiate(
                 return Err(ContractError::InitialBalancesError {});
             }
 
+            // Mint the balances to be staked to this contract. They
+            // are staked on behalf of their recipients once the
+            // staking contract has been instantiated.
+            if auto_stake {
+                for balance in initial_balances.iter() {
+                    deps.api.addr_validate(&balance.address)?;
+                }
+                AUTO_STAKE_BALANCES.save(deps.storage, &initial_balances)?;
+                initial_balances = vec![Cw20Coin {
+                    address: env.contract.address.to_string(),
+                    amount: initial_supply,
+                }];
+            }
+
             // Add DAO initial balance to initial_balances vector if defined.
             if let Some(initial_dao_balance) = initial_dao_balance {
                 if initial_dao_balance > Uint128::zero() {
@@ -184,6 +223,15 @@ pub fn assert_valid_absolute_count_threshold(
     Ok(())
 }
 
+/// Gets the token that TOKEN is a derivative of, or None if TOKEN
+/// does not answer the derivative config query.
+fn underlying_token(deps: Deps, token: &Addr) -> Option<Addr> {
+    deps.querier
+        .query_wasm_smart(token, &DerivativeQueryMsg::Config {})
+        .ok()
+        .map(|resp: DerivativeConfigResponse| resp.underlying_token)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -195,6 +243,9 @@ pub fn execute(
         ExecuteMsg::UpdateActiveThreshold { new_threshold } => {
             execute_update_active_threshold(deps, env, info, new_threshold)
         }
+        ExecuteMsg::AddHook { addr } => execute_add_hook(deps, info, addr),
+        ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, info, addr),
+        ExecuteMsg::StakeChangeHook(msg) => execute_stake_change_hook(deps, info, msg),
     }
 }
 
@@ -228,11 +279,77 @@ pub fn execute_update_active_threshold(
 
     Ok(Response::new().add_attribute("action", "update_active_threshold"))
 }
+
+pub fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let hook = deps.api.addr_validate(&addr)?;
+    POWER_CHANGED_HOOKS.add_hook(deps.storage, hook)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_hook")
+        .add_attribute("hook", addr))
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let hook = deps.api.addr_validate(&addr)?;
+    POWER_CHANGED_HOOKS.remove_hook(deps.storage, hook)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("hook", addr))
+}
+
+pub fn execute_stake_change_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: StakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let staking_contract = STAKING_CONTRACT.load(deps.storage)?;
+    if info.sender != staking_contract {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let relayed = to_binary(&ExecuteMsg::StakeChangeHook(msg))?;
+    let hooks = POWER_CHANGED_HOOKS.prepare_hooks(deps.storage, |hook| {
+        Ok(SubMsg::new(WasmMsg::Execute {
+            contract_addr: hook.into_string(),
+            msg: relayed.clone(),
+            funds: vec![],
+        }))
+    })?;
+
+    Ok(Response::new()
+        .add_submessages(hooks)
+        .add_attribute("action", "stake_change_hook"))
+}
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::TokenContract {} => query_token_contract(deps),
         QueryMsg::StakingContract {} => query_staking_contract(deps),
+        QueryMsg::UnstakingDuration {} => query_unstaking_duration(deps),
+        QueryMsg::InstantiationStatus {} => query_instantiation_status(deps),
+        QueryMsg::ListStakers { start_after, limit } => {
+            query_list_stakers(deps, start_after, limit)
+        }
+        QueryMsg::PowerChangedHooks {} => to_binary(&POWER_CHANGED_HOOKS.query_hooks(deps)?),
         QueryMsg::VotingPowerAtHeight { address, height } => {
             query_voting_power_at_height(deps, env, address, height)
         }
@@ -254,6 +371,58 @@ pub fn query_staking_contract(deps: Deps) -> StdResult<Binary> {
     to_binary(&staking_contract)
 }
 
+pub fn query_unstaking_duration(deps: Deps) -> StdResult<Binary> {
+    let unstaking_duration = match STAKING_CONTRACT_UNSTAKING_DURATION.may_load(deps.storage)? {
+        Some(unstaking_duration) => unstaking_duration,
+        // The staking contract was not instantiated by this module so
+        // its unstaking duration was never stored.
+        None => {
+            let staking_contract = STAKING_CONTRACT.load(deps.storage)?;
+            let config: cw20_stake::state::Config = deps
+                .querier
+                .query_wasm_smart(staking_contract, &cw20_stake::msg::QueryMsg::GetConfig {})?;
+            config.unstaking_duration
+        }
+    };
+    to_binary(&unstaking_duration)
+}
+
+pub fn query_instantiation_status(deps: Deps) -> StdResult<Binary> {
+    to_binary(&InstantiationStatusResponse {
+        token: TOKEN.may_load(deps.storage)?,
+        staking_contract: STAKING_CONTRACT.may_load(deps.storage)?,
+    })
+}
+
+pub fn query_list_stakers(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?
+        .map(Addr::into_string);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let staking_contract = STAKING_CONTRACT.load(deps.storage)?;
+    let res: cw20_stake::msg::ListStakersResponse = deps.querier.query_wasm_smart(
+        staking_contract,
+        &cw20_stake::msg::QueryMsg::ListStakers {
+            start_after,
+            limit: Some(limit),
+        },
+    )?;
+    let stakers = res
+        .stakers
+        .into_iter()
+        .map(|staker| StakerBalance {
+            address: staker.address,
+            power: staker.balance,
+        })
+        .collect();
+    to_binary(&ListStakersResponse { stakers })
+}
+
 pub fn query_voting_power_at_height(
     deps: Deps,
     _env: Env,
@@ -435,7 +604,34 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
 
                     STAKING_CONTRACT.save(deps.storage, &staking_contract_addr)?;
 
-                    Ok(Response::new().add_attribute("staking_contract", staking_contract_addr))
+                    let stake_msgs = match AUTO_STAKE_BALANCES.may_load(deps.storage)? {
+                        Some(balances) => {
+                            AUTO_STAKE_BALANCES.remove(deps.storage);
+                            let token = TOKEN.load(deps.storage)?;
+                            balances
+                                .into_iter()
+                                .filter(|balance| !balance.amount.is_zero())
+                                .map(|Cw20Coin { address, amount }| {
+                                    Ok(WasmMsg::Execute {
+                                        contract_addr: token.to_string(),
+                                        msg: to_binary(&cw20::Cw20ExecuteMsg::Send {
+                                            contract: staking_contract_addr.to_string(),
+                                            amount,
+                                            msg: to_binary(
+                                                &cw20_stake::msg::ReceiveMsg::StakeFor { address },
+                                            )?,
+                                        })?,
+                                        funds: vec![],
+                                    })
+                                })
+                                .collect::<StdResult<Vec<_>>>()?
+                        }
+                        None => vec![],
+                    };
+
+                    Ok(Response::new()
+                        .add_attribute("staking_contract", staking_contract_addr)
+                        .add_messages(stake_msgs))
                 }
                 Err(_) => Err(ContractError::StakingInstantiateError {}),
             }
@@ -1,7 +1,8 @@
-use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_schema::{cw_serde, serde::Deserialize, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw20::Cw20Coin;
 use cw20_base::msg::InstantiateMarketingInfo;
+use cw20_stake::hooks::StakeChangedHookMsg;
 use cw_utils::Duration;
 
 use dao_macros::{active_query, token_query, voting_module_query};
@@ -13,6 +14,15 @@ pub enum StakingInfo {
     Existing {
         /// Address of an already instantiated staking contract.
         staking_contract_address: String,
+        /// If true, the staking contract may stake a derivative of
+        /// the governance token, for example a wrapper of it. The
+        /// staking contract's token is first compared to the
+        /// governance token. If they differ, the staking contract's
+        /// token is queried with `{"config": {}}` and its
+        /// `underlying_token` compared to the governance token. The
+        /// staking contract is rejected if neither matches.
+        #[serde(default)]
+        allow_derivative: bool,
     },
     New {
         /// Code ID for staking contract to instantiate.
@@ -48,6 +58,12 @@ pub enum TokenInfo {
         staking_code_id: u64,
         unstaking_duration: Option<Duration>,
         initial_dao_balance: Option<Uint128>,
+        /// If true, `initial_balances` are staked in the new staking
+        /// contract on behalf of their recipients once it has been
+        /// instantiated, so that they have voting power
+        /// immediately. The DAO's initial balance is not staked.
+        #[serde(default)]
+        auto_stake: bool,
     },
 }
 
@@ -66,6 +82,22 @@ pub enum ActiveThreshold {
     Percentage { percent: Decimal },
 }
 
+/// The query made of a staking contract's token to find the token it
+/// is a derivative of. See `StakingInfo::Existing`.
+#[cw_serde]
+pub enum DerivativeQueryMsg {
+    Config {},
+}
+
+/// The part of a derivative token's config that this module
+/// reads. Its other fields are ignored.
+#[derive(Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde")]
+pub struct DerivativeConfigResponse {
+    /// The token that the derivative wraps.
+    pub underlying_token: Addr,
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub token_info: TokenInfo,
@@ -80,6 +112,17 @@ pub enum ExecuteMsg {
     UpdateActiveThreshold {
         new_threshold: Option<ActiveThreshold>,
     },
+    /// Registers ADDR to be sent a `StakeChangeHook` message whenever
+    /// an address's stake, and so its voting power, changes. Only the
+    /// DAO may call this method.
+    AddHook { addr: String },
+    /// Unregisters ADDR from stake change messages. Only the DAO may
+    /// call this method.
+    RemoveHook { addr: String },
+    /// Relays a stake change to the registered hooks. Only the
+    /// staking contract may call this method, which it does once the
+    /// DAO, as its owner, has added this module as one of its hooks.
+    StakeChangeHook(StakeChangedHookMsg),
 }
 
 #[voting_module_query]
@@ -94,6 +137,32 @@ pub enum QueryMsg {
     StakingContract {},
     #[returns(ActiveThresholdResponse)]
     ActiveThreshold {},
+    /// Gets the unstaking duration of the cw20-stake contract this
+    /// voting module is wrapping. Tokens remain locked for this long
+    /// after being unstaked.
+    #[returns(Option<Duration>)]
+    UnstakingDuration {},
+    /// Gets which of the contracts this module sets up during
+    /// instantiation have been set. Instantiating a new token or
+    /// staking contract happens over several replies, so a module
+    /// whose instantiation was interrupted may have a token but no
+    /// staking contract.
+    #[returns(InstantiationStatusResponse)]
+    InstantiationStatus {},
+    /// Lists the addresses with tokens staked in the cw20-stake
+    /// contract this voting module is wrapping, along with their
+    /// voting power, in ascending order of address. At most 100
+    /// stakers are returned per page.
+    #[returns(ListStakersResponse)]
+    ListStakers {
+        /// The address to start listing stakers after.
+        start_after: Option<String>,
+        /// The maximum number of stakers to return. Defaults to 30.
+        limit: Option<u32>,
+    },
+    /// Lists the consumers of stake changes relayed by this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    PowerChangedHooks {},
 }
 
 #[cw_serde]
@@ -101,5 +170,24 @@ pub struct ActiveThresholdResponse {
     pub active_threshold: Option<ActiveThreshold>,
 }
 
+#[cw_serde]
+pub struct InstantiationStatusResponse {
+    /// The cw20 token contract, if it has been set.
+    pub token: Option<Addr>,
+    /// The cw20-stake contract, if it has been set.
+    pub staking_contract: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct StakerBalance {
+    pub address: String,
+    pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct ListStakersResponse {
+    pub stakers: Vec<StakerBalance>,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
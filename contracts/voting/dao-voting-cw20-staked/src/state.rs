@@ -1,5 +1,7 @@
 use crate::msg::ActiveThreshold;
 use cosmwasm_std::Addr;
+use cw20::Cw20Coin;
+use cw_hooks::Hooks;
 use cw_storage_plus::Item;
 use cw_utils::Duration;
 
@@ -7,6 +9,15 @@ pub const ACTIVE_THRESHOLD: Item<ActiveThreshold> = Item::new("active_threshold"
 pub const TOKEN: Item<Addr> = Item::new("token");
 pub const DAO: Item<Addr> = Item::new("dao");
 pub const STAKING_CONTRACT: Item<Addr> = Item::new("staking_contract");
+/// The token staked in the staking contract, if it is a derivative
+/// of `TOKEN` rather than `TOKEN` itself.
+pub const STAKED_TOKEN: Item<Addr> = Item::new("staked_token");
 pub const STAKING_CONTRACT_UNSTAKING_DURATION: Item<Option<Duration>> =
     Item::new("staking_contract_unstaking_duration");
 pub const STAKING_CONTRACT_CODE_ID: Item<u64> = Item::new("staking_contract_code_id");
+/// Initial balances waiting to be staked on behalf of their
+/// recipients once the staking contract has been instantiated. Only
+/// set during instantiation with `auto_stake`.
+pub const AUTO_STAKE_BALANCES: Item<Vec<Cw20Coin>> = Item::new("auto_stake_balances");
+/// Consumers of stake changes relayed from the staking contract.
+pub const POWER_CHANGED_HOOKS: Hooks = Hooks::new("power_changed_hooks");
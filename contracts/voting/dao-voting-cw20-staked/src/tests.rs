@@ -1,18 +1,24 @@
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
+    from_binary,
     testing::{mock_dependencies, mock_env},
-    to_binary, Addr, CosmosMsg, Decimal, Empty, Uint128, WasmMsg,
+    to_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, Uint128, WasmMsg,
 };
 use cw2::ContractVersion;
 use cw20::{BalanceResponse, Cw20Coin, MinterResponse, TokenInfoResponse};
 use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use cw_storage_plus::Item;
+use cw_utils::Duration;
 use dao_interface::voting::{InfoResponse, IsActiveResponse, VotingPowerAtHeightResponse};
 
 use crate::{
-    contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
+    contract::{migrate, query, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{
-        ActiveThreshold, ActiveThresholdResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-        StakingInfo,
+        ActiveThreshold, ActiveThresholdResponse, ExecuteMsg, InstantiateMsg,
+        InstantiationStatusResponse, ListStakersResponse, MigrateMsg, QueryMsg, StakingInfo,
     },
+    state::{STAKED_TOKEN, TOKEN},
 };
 
 const DAO_ADDR: &str = "dao";
@@ -36,6 +42,68 @@ fn staking_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+/// The state of a mock token that wraps another token, which answers
+/// just enough queries to be staked and to be checked as a
+/// derivative.
+const WRAPPER_UNDERLYING_TOKEN: Item<Addr> = Item::new("underlying_token");
+
+#[cw_serde]
+struct WrapperInstantiateMsg {
+    underlying_token: String,
+}
+
+#[cw_serde]
+enum WrapperQueryMsg {
+    TokenInfo {},
+    Config {},
+}
+
+#[cw_serde]
+struct WrapperConfigResponse {
+    underlying_token: Addr,
+    exchange_rate: Decimal,
+}
+
+fn wrapper_instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: WrapperInstantiateMsg,
+) -> StdResult<Response> {
+    let underlying_token = deps.api.addr_validate(&msg.underlying_token)?;
+    WRAPPER_UNDERLYING_TOKEN.save(deps.storage, &underlying_token)?;
+    Ok(Response::new())
+}
+
+fn wrapper_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn wrapper_query(deps: Deps, _env: Env, msg: WrapperQueryMsg) -> StdResult<Binary> {
+    match msg {
+        WrapperQueryMsg::TokenInfo {} => to_binary(&TokenInfoResponse {
+            name: "Wrapped DAO DAO".to_string(),
+            symbol: "WDAO".to_string(),
+            decimals: 3,
+            total_supply: Uint128::zero(),
+        }),
+        WrapperQueryMsg::Config {} => to_binary(&WrapperConfigResponse {
+            underlying_token: WRAPPER_UNDERLYING_TOKEN.load(deps.storage)?,
+            exchange_rate: Decimal::one(),
+        }),
+    }
+}
+
+fn wrapper_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(wrapper_execute, wrapper_instantiate, wrapper_query);
+    Box::new(contract)
+}
+
 fn staked_balance_voting_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         crate::contract::execute,
@@ -94,6 +162,7 @@ fn test_instantiate_zero_supply() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::zero()),
+                auto_stake: false,
             },
             active_threshold: None,
         },
@@ -122,6 +191,7 @@ fn test_instantiate_no_balances() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::zero()),
+                auto_stake: false,
             },
             active_threshold: None,
         },
@@ -153,6 +223,7 @@ fn test_instantiate_zero_active_threshold_count() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::zero()),
+                auto_stake: false,
             },
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(0),
@@ -186,6 +257,7 @@ fn test_contract_info() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::zero()),
+                auto_stake: false,
             },
             active_threshold: None,
         },
@@ -237,6 +309,7 @@ fn test_new_cw20() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(10u64)),
+                auto_stake: false,
             },
             active_threshold: None,
         },
@@ -599,6 +672,7 @@ fn test_existing_cw20_existing_staking() {
                 address: token_addr.to_string(),
                 staking_contract: StakingInfo::Existing {
                     staking_contract_address: staking_addr.to_string(),
+                    allow_derivative: false,
                 },
             },
             active_threshold: None,
@@ -714,6 +788,7 @@ fn test_existing_cw20_existing_staking() {
                 address: different_token.to_string(),
                 staking_contract: StakingInfo::Existing {
                     staking_contract_address: staking_addr.to_string(),
+                    allow_derivative: false,
                 },
             },
             active_threshold: None,
@@ -725,6 +800,144 @@ fn test_existing_cw20_existing_staking() {
     .unwrap_err();
 }
 
+#[test]
+fn test_existing_cw20_existing_derivative_staking() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_contract());
+    let wrapper_id = app.store_code(wrapper_contract());
+    let voting_id = app.store_code(staked_balance_voting_contract());
+    let staking_id = app.store_code(staking_contract());
+
+    let cw20_instantiate = |symbol: &str| cw20_base::msg::InstantiateMsg {
+        name: "DAO DAO".to_string(),
+        symbol: symbol.to_string(),
+        decimals: 3,
+        initial_balances: vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::from(2u64),
+        }],
+        mint: None,
+        marketing: None,
+    };
+    let token_addr = app
+        .instantiate_contract(
+            cw20_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw20_instantiate("DAO"),
+            &[],
+            "voting token",
+            None,
+        )
+        .unwrap();
+    let wrapper_addr = app
+        .instantiate_contract(
+            wrapper_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &WrapperInstantiateMsg {
+                underlying_token: token_addr.to_string(),
+            },
+            &[],
+            "wrapped voting token",
+            None,
+        )
+        .unwrap();
+    let different_token = app
+        .instantiate_contract(
+            cw20_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw20_instantiate("DAOM"),
+            &[],
+            "voting token",
+            None,
+        )
+        .unwrap();
+
+    let mut instantiate_staking = |token: &Addr| {
+        app.instantiate_contract(
+            staking_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw20_stake::msg::InstantiateMsg {
+                owner: Some(DAO_ADDR.to_string()),
+                unstaking_duration: None,
+                token_address: token.to_string(),
+            },
+            &[],
+            "staking",
+            None,
+        )
+        .unwrap()
+    };
+    let wrapper_staking_addr = instantiate_staking(&wrapper_addr);
+    let different_staking_addr = instantiate_staking(&different_token);
+
+    let instantiate_msg = |staking_addr: &Addr, allow_derivative: bool| InstantiateMsg {
+        token_info: crate::msg::TokenInfo::Existing {
+            address: token_addr.to_string(),
+            staking_contract: StakingInfo::Existing {
+                staking_contract_address: staking_addr.to_string(),
+                allow_derivative,
+            },
+        },
+        active_threshold: None,
+    };
+
+    // Staking contracts for a derivative are rejected unless
+    // derivatives are allowed.
+    let err: crate::ContractError = app
+        .instantiate_contract(
+            voting_id,
+            Addr::unchecked(DAO_ADDR),
+            &instantiate_msg(&wrapper_staking_addr, false),
+            &[],
+            "voting module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        crate::ContractError::StakingContractMismatch {}
+    ));
+
+    // Tokens that do not wrap the governance token are rejected even
+    // when derivatives are allowed.
+    let err: crate::ContractError = app
+        .instantiate_contract(
+            voting_id,
+            Addr::unchecked(DAO_ADDR),
+            &instantiate_msg(&different_staking_addr, true),
+            &[],
+            "voting module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        crate::ContractError::StakingContractMismatch {}
+    ));
+
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        instantiate_msg(&wrapper_staking_addr, true),
+    );
+    let token: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::TokenContract {})
+        .unwrap();
+    assert_eq!(token, token_addr);
+    let staking: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::StakingContract {})
+        .unwrap();
+    assert_eq!(staking, wrapper_staking_addr);
+    let staked_token = STAKED_TOKEN.query(&app.wrap(), voting_addr).unwrap();
+    assert_eq!(staked_token, wrapper_addr);
+}
+
 #[test]
 fn test_different_heights() {
     let mut app = App::default();
@@ -950,6 +1163,7 @@ fn test_active_threshold_absolute_count() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(100u64)),
+                auto_stake: false,
             },
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(100),
@@ -1010,6 +1224,7 @@ fn test_active_threshold_percent() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(100u64)),
+                auto_stake: false,
             },
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(20),
@@ -1070,6 +1285,7 @@ fn test_active_threshold_percent_rounds_up() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: None,
+                auto_stake: false,
             },
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(50),
@@ -1145,6 +1361,7 @@ fn test_active_threshold_none() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(100u64)),
+                auto_stake: false,
             },
             active_threshold: None,
         },
@@ -1183,6 +1400,7 @@ fn test_update_active_threshold() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(100u64)),
+                auto_stake: false,
             },
             active_threshold: None,
         },
@@ -1251,6 +1469,7 @@ fn test_active_threshold_percentage_gt_100() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(100u64)),
+                auto_stake: false,
             },
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(120),
@@ -1285,6 +1504,7 @@ fn test_active_threshold_percentage_lte_0() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(100u64)),
+                auto_stake: false,
             },
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(0),
@@ -1319,6 +1539,7 @@ fn test_active_threshold_absolute_count_invalid() {
                 unstaking_duration: None,
                 staking_code_id: staking_contract_id,
                 initial_dao_balance: Some(Uint128::from(100u64)),
+                auto_stake: false,
             },
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(10000),
@@ -1353,6 +1574,7 @@ fn test_migrate() {
                     unstaking_duration: None,
                     staking_code_id: staking_contract_id,
                     initial_dao_balance: Some(Uint128::zero()),
+                    auto_stake: false,
                 },
                 active_threshold: None,
             },
@@ -1399,3 +1621,491 @@ pub fn test_migrate_update_version() {
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
 }
+
+#[test]
+fn test_unstaking_duration() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_contract());
+    let voting_id = app.store_code(staked_balance_voting_contract());
+    let staking_id = app.store_code(staking_contract());
+
+    // New token and staking contract.
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::New {
+                code_id: cw20_id,
+                label: "DAO DAO voting".to_string(),
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: CREATOR_ADDR.to_string(),
+                    amount: Uint128::from(2u64),
+                }],
+                marketing: None,
+                unstaking_duration: Some(Duration::Height(5)),
+                staking_code_id: staking_id,
+                initial_dao_balance: None,
+                auto_stake: false,
+            },
+            active_threshold: None,
+        },
+    );
+    let unstaking_duration: Option<Duration> = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::UnstakingDuration {})
+        .unwrap();
+    assert_eq!(unstaking_duration, Some(Duration::Height(5)));
+
+    // Existing token and new staking contract.
+    let token_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr, &QueryMsg::TokenContract {})
+        .unwrap();
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::Existing {
+                address: token_addr.to_string(),
+                staking_contract: StakingInfo::New {
+                    staking_code_id: staking_id,
+                    unstaking_duration: Some(Duration::Time(100)),
+                },
+            },
+            active_threshold: None,
+        },
+    );
+    let unstaking_duration: Option<Duration> = app
+        .wrap()
+        .query_wasm_smart(voting_addr, &QueryMsg::UnstakingDuration {})
+        .unwrap();
+    assert_eq!(unstaking_duration, Some(Duration::Time(100)));
+
+    // Existing token and staking contract.
+    let staking_addr = app
+        .instantiate_contract(
+            staking_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw20_stake::msg::InstantiateMsg {
+                owner: Some(DAO_ADDR.to_string()),
+                token_address: token_addr.to_string(),
+                unstaking_duration: Some(Duration::Height(7)),
+            },
+            &[],
+            "staking",
+            None,
+        )
+        .unwrap();
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::Existing {
+                address: token_addr.to_string(),
+                staking_contract: StakingInfo::Existing {
+                    staking_contract_address: staking_addr.to_string(),
+                    allow_derivative: false,
+                },
+            },
+            active_threshold: None,
+        },
+    );
+    let unstaking_duration: Option<Duration> = app
+        .wrap()
+        .query_wasm_smart(voting_addr, &QueryMsg::UnstakingDuration {})
+        .unwrap();
+    assert_eq!(unstaking_duration, Some(Duration::Height(7)));
+}
+
+#[test]
+fn test_instantiation_status() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_contract());
+    let voting_id = app.store_code(staked_balance_voting_contract());
+    let staking_id = app.store_code(staking_contract());
+
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::New {
+                code_id: cw20_id,
+                label: "DAO DAO voting".to_string(),
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: CREATOR_ADDR.to_string(),
+                    amount: Uint128::from(2u64),
+                }],
+                marketing: None,
+                unstaking_duration: None,
+                staking_code_id: staking_id,
+                initial_dao_balance: None,
+                auto_stake: false,
+            },
+            active_threshold: None,
+        },
+    );
+    let status: InstantiationStatusResponse = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::InstantiationStatus {})
+        .unwrap();
+    let token: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::TokenContract {})
+        .unwrap();
+    let staking_contract: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr, &QueryMsg::StakingContract {})
+        .unwrap();
+    assert_eq!(
+        status,
+        InstantiationStatusResponse {
+            token: Some(token),
+            staking_contract: Some(staking_contract),
+        }
+    );
+
+    // A module whose instantiation stopped after the token reply but
+    // before the staking contract reply.
+    let mut deps = mock_dependencies();
+    TOKEN
+        .save(deps.as_mut().storage, &Addr::unchecked("token"))
+        .unwrap();
+    let status: InstantiationStatusResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::InstantiationStatus {}).unwrap())
+            .unwrap();
+    assert_eq!(
+        status,
+        InstantiationStatusResponse {
+            token: Some(Addr::unchecked("token")),
+            staking_contract: None,
+        }
+    );
+}
+
+#[test]
+fn test_auto_stake() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_contract());
+    let voting_id = app.store_code(staked_balance_voting_contract());
+    let staking_id = app.store_code(staking_contract());
+
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::New {
+                code_id: cw20_id,
+                label: "DAO DAO voting".to_string(),
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: vec![
+                    Cw20Coin {
+                        address: CREATOR_ADDR.to_string(),
+                        amount: Uint128::new(2),
+                    },
+                    Cw20Coin {
+                        address: "ekez".to_string(),
+                        amount: Uint128::new(3),
+                    },
+                ],
+                marketing: None,
+                unstaking_duration: None,
+                staking_code_id: staking_id,
+                initial_dao_balance: Some(Uint128::new(10)),
+                auto_stake: true,
+            },
+            active_threshold: None,
+        },
+    );
+    app.update_block(next_block);
+
+    let voting_power = |app: &App, address: &str| {
+        let resp: VotingPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                voting_addr.clone(),
+                &QueryMsg::VotingPowerAtHeight {
+                    address: address.to_string(),
+                    height: None,
+                },
+            )
+            .unwrap();
+        resp.power
+    };
+    assert_eq!(voting_power(&app, CREATOR_ADDR), Uint128::new(2));
+    assert_eq!(voting_power(&app, "ekez"), Uint128::new(3));
+    // The DAO's balance is not staked.
+    assert_eq!(voting_power(&app, DAO_ADDR), Uint128::zero());
+
+    let token_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::TokenContract {})
+        .unwrap();
+    let balance = |app: &App, address: &str| {
+        let resp: BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                token_addr.clone(),
+                &cw20::Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        resp.balance
+    };
+    assert_eq!(balance(&app, CREATOR_ADDR), Uint128::zero());
+    assert_eq!(balance(&app, voting_addr.as_str()), Uint128::zero());
+    assert_eq!(balance(&app, DAO_ADDR), Uint128::new(10));
+}
+
+#[test]
+fn test_list_stakers() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_contract());
+    let voting_id = app.store_code(staked_balance_voting_contract());
+    let staking_id = app.store_code(staking_contract());
+
+    let stakers: Vec<String> = (0..105).map(|i| format!("staker{i:03}")).collect();
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::New {
+                code_id: cw20_id,
+                label: "DAO DAO voting".to_string(),
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: stakers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, address)| Cw20Coin {
+                        address: address.clone(),
+                        amount: Uint128::new(i as u128 + 1),
+                    })
+                    .collect(),
+                marketing: None,
+                unstaking_duration: None,
+                staking_code_id: staking_id,
+                initial_dao_balance: None,
+                auto_stake: true,
+            },
+            active_threshold: None,
+        },
+    );
+    app.update_block(next_block);
+
+    let list = |app: &App, start_after: Option<&str>, limit: Option<u32>| {
+        let resp: ListStakersResponse = app
+            .wrap()
+            .query_wasm_smart(
+                voting_addr.clone(),
+                &QueryMsg::ListStakers {
+                    start_after: start_after.map(str::to_string),
+                    limit,
+                },
+            )
+            .unwrap();
+        resp.stakers
+    };
+
+    // Pages default to 30 stakers and are capped at 100.
+    let page = list(&app, None, None);
+    assert_eq!(page.len(), 30);
+    assert_eq!(page[0].address, "staker000");
+    assert_eq!(page[0].power, Uint128::new(1));
+    assert_eq!(list(&app, None, Some(1000)).len(), 100);
+
+    // Paging through returns every staker once.
+    let mut listed = vec![];
+    let mut start_after: Option<String> = None;
+    loop {
+        let page = list(&app, start_after.as_deref(), Some(40));
+        match page.last() {
+            Some(last) => start_after = Some(last.address.clone()),
+            None => break,
+        }
+        listed.extend(page.into_iter().map(|staker| staker.address));
+    }
+    assert_eq!(listed, stakers);
+}
+
+#[test]
+fn test_power_changed_hooks() {
+    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+    use cw20_stake::hooks::StakeChangedHookMsg;
+    use cw_storage_plus::Item;
+
+    const RECEIVED: Item<StakeChangedHookMsg> = Item::new("received");
+
+    fn receiver_instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn receiver_execute(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> StdResult<Response> {
+        if let ExecuteMsg::StakeChangeHook(msg) = msg {
+            RECEIVED.save(deps.storage, &msg)?;
+        }
+        Ok(Response::new())
+    }
+
+    fn receiver_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_binary(&RECEIVED.may_load(deps.storage)?)
+    }
+
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_contract());
+    let voting_id = app.store_code(staked_balance_voting_contract());
+    let staking_id = app.store_code(staking_contract());
+    let receiver_id = app.store_code(Box::new(ContractWrapper::new(
+        receiver_execute,
+        receiver_instantiate,
+        receiver_query,
+    )));
+
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::New {
+                code_id: cw20_id,
+                label: "DAO DAO voting".to_string(),
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: CREATOR_ADDR.to_string(),
+                    amount: Uint128::new(2),
+                }],
+                marketing: None,
+                unstaking_duration: None,
+                staking_code_id: staking_id,
+                initial_dao_balance: None,
+                auto_stake: false,
+            },
+            active_threshold: None,
+        },
+    );
+    let token_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::TokenContract {})
+        .unwrap();
+    let staking_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::StakingContract {})
+        .unwrap();
+    let receiver_addr = app
+        .instantiate_contract(
+            receiver_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &Empty {},
+            &[],
+            "receiver",
+            None,
+        )
+        .unwrap();
+
+    // The DAO owns the staking contract and routes its hooks through
+    // the voting module.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        staking_addr.clone(),
+        &cw20_stake::msg::ExecuteMsg::AddHook {
+            addr: voting_addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the DAO may add hooks.
+    let err: crate::ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            voting_addr.clone(),
+            &ExecuteMsg::AddHook {
+                addr: receiver_addr.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, crate::ContractError::Unauthorized {}));
+
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        voting_addr.clone(),
+        &ExecuteMsg::AddHook {
+            addr: receiver_addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let hooks: cw_hooks::HooksResponse = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::PowerChangedHooks {})
+        .unwrap();
+    assert_eq!(hooks.hooks, vec![receiver_addr.to_string()]);
+
+    // Only the staking contract may report stake changes.
+    let err: crate::ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            voting_addr.clone(),
+            &ExecuteMsg::StakeChangeHook(StakeChangedHookMsg::Stake {
+                addr: Addr::unchecked(CREATOR_ADDR),
+                amount: Uint128::new(100),
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, crate::ContractError::Unauthorized {}));
+
+    stake_tokens(&mut app, staking_addr, token_addr, CREATOR_ADDR, 2);
+    let received: Option<StakeChangedHookMsg> = app
+        .wrap()
+        .query_wasm_smart(receiver_addr.clone(), &Empty {})
+        .unwrap();
+    assert_eq!(
+        received,
+        Some(StakeChangedHookMsg::Stake {
+            addr: Addr::unchecked(CREATOR_ADDR),
+            amount: Uint128::new(2),
+        })
+    );
+
+    // Removed hooks are no longer listed.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        voting_addr.clone(),
+        &ExecuteMsg::RemoveHook {
+            addr: receiver_addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let hooks: cw_hooks::HooksResponse = app
+        .wrap()
+        .query_wasm_smart(voting_addr, &QueryMsg::PowerChangedHooks {})
+        .unwrap();
+    assert!(hooks.hooks.is_empty());
+}
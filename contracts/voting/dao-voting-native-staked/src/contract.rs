@@ -1,20 +1,25 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coins, to_binary, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
-    StdResult, Uint128,
+    coins, to_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Storage, Uint128,
 };
 use cw2::set_contract_version;
 use cw_controllers::ClaimsResponse;
 use cw_utils::{must_pay, Duration};
 use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
 use dao_interface::Admin;
+use dao_proposal_hooks::ProposalHookMsg;
+use dao_vote_hooks::VoteHookMsg;
 
 use crate::error::ContractError;
 use crate::msg::{
     ExecuteMsg, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg, StakerBalanceResponse,
 };
-use crate::state::{Config, CLAIMS, CONFIG, DAO, MAX_CLAIMS, STAKED_BALANCES, STAKED_TOTAL};
+use crate::state::{
+    Config, InactivityDecay, CLAIMS, CONFIG, DAO, INACTIVITY_DECAY, LAST_VOTED, MAX_CLAIMS,
+    PROPOSALS_CREATED, STAKED_BALANCES, STAKED_TOTAL,
+};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-native-staked";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -37,10 +42,35 @@ fn validate_duration(duration: Option<Duration>) -> Result<(), ContractError> {
     Ok(())
 }
 
+fn validate_inactivity_decay(decay: &Option<InactivityDecay>) -> Result<(), ContractError> {
+    if let Some(InactivityDecay {
+        inactive_after,
+        factor,
+    }) = decay
+    {
+        if *inactive_after == 0 || *factor > Decimal::one() {
+            return Err(ContractError::InvalidInactivityDecay {});
+        }
+    }
+    Ok(())
+}
+
+/// Records DECAY as the inactivity decay in effect from HEIGHT.
+fn save_inactivity_decay(
+    storage: &mut dyn Storage,
+    decay: &Option<InactivityDecay>,
+    height: u64,
+) -> StdResult<()> {
+    match decay {
+        Some(decay) => INACTIVITY_DECAY.save(storage, decay, height),
+        None => INACTIVITY_DECAY.remove(storage, height),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
@@ -60,15 +90,18 @@ pub fn instantiate(
         .transpose()?;
 
     validate_duration(msg.unstaking_duration)?;
+    validate_inactivity_decay(&msg.inactivity_decay)?;
 
     let config = Config {
         owner,
         manager,
         denom: msg.denom,
         unstaking_duration: msg.unstaking_duration,
+        inactivity_decay: msg.inactivity_decay,
     };
 
     CONFIG.save(deps.storage, &config)?;
+    save_inactivity_decay(deps.storage, &config.inactivity_decay, env.block.height)?;
     DAO.save(deps.storage, &info.sender)?;
 
     Ok(Response::new()
@@ -105,6 +138,11 @@ pub fn execute(
             duration,
         } => execute_update_config(deps, info, owner, manager, duration),
         ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+        ExecuteMsg::UpdateInactivityDecay { inactivity_decay } => {
+            execute_update_inactivity_decay(deps, env, info, inactivity_decay)
+        }
+        ExecuteMsg::ProposalHook(msg) => execute_proposal_hook(deps, env, info, msg),
+        ExecuteMsg::VoteHook(msg) => execute_vote_hook(deps, env, info, msg),
     }
 }
 
@@ -116,6 +154,15 @@ pub fn execute_stake(
     let config = CONFIG.load(deps.storage)?;
     let amount = must_pay(&info, &config.denom)?;
 
+    // Stakers are only considered inactive after missing proposals
+    // created since they first staked.
+    if LAST_VOTED.may_load(deps.storage, &info.sender)?.is_none() {
+        let created = PROPOSALS_CREATED
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        LAST_VOTED.save(deps.storage, &info.sender, &created, env.block.height)?;
+    }
+
     STAKED_BALANCES.update(
         deps.storage,
         &info.sender,
@@ -250,6 +297,86 @@ pub fn execute_update_config(
         ))
 }
 
+pub fn execute_update_inactivity_decay(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    inactivity_decay: Option<InactivityDecay>,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+    if Some(info.sender.clone()) != config.owner && Some(info.sender) != config.manager {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_inactivity_decay(&inactivity_decay)?;
+    config.inactivity_decay = inactivity_decay;
+    CONFIG.save(deps.storage, &config)?;
+    save_inactivity_decay(deps.storage, &config.inactivity_decay, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_inactivity_decay")
+        .add_attribute("inactivity_decay", format!("{:?}", config.inactivity_decay)))
+}
+
+/// Errors unless SENDER is one of the DAO's proposal modules.
+fn assert_proposal_module(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    let module = dao_core::state::PROPOSAL_MODULES
+        .query(&deps.querier, dao, sender.clone())
+        .unwrap_or_default();
+    match module {
+        Some(_) => Ok(()),
+        None => Err(ContractError::NotProposalModule {}),
+    }
+}
+
+pub fn execute_proposal_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ProposalHookMsg,
+) -> Result<Response, ContractError> {
+    assert_proposal_module(deps.as_ref(), &info.sender)?;
+
+    match msg {
+        ProposalHookMsg::NewProposal { .. } => {
+            let created = PROPOSALS_CREATED
+                .may_load(deps.storage)?
+                .unwrap_or_default()
+                + 1;
+            PROPOSALS_CREATED.save(deps.storage, &created, env.block.height)?;
+            Ok(Response::new()
+                .add_attribute("action", "proposal_hook")
+                .add_attribute("proposals_created", created.to_string()))
+        }
+        ProposalHookMsg::ProposalStatusChanged { .. } => {
+            Ok(Response::new().add_attribute("action", "proposal_hook"))
+        }
+    }
+}
+
+pub fn execute_vote_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: VoteHookMsg,
+) -> Result<Response, ContractError> {
+    assert_proposal_module(deps.as_ref(), &info.sender)?;
+
+    match msg {
+        VoteHookMsg::NewVote { voter, .. } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            let created = PROPOSALS_CREATED
+                .may_load(deps.storage)?
+                .unwrap_or_default();
+            LAST_VOTED.save(deps.storage, &voter, &created, env.block.height)?;
+            Ok(Response::new()
+                .add_attribute("action", "vote_hook")
+                .add_attribute("voter", voter))
+        }
+    }
+}
+
 pub fn execute_claim(
     deps: DepsMut,
     env: Env,
@@ -303,6 +430,26 @@ pub fn query_voting_power_at_height(
     let power = STAKED_BALANCES
         .may_load_at_height(deps.storage, &address, height)?
         .unwrap_or_default();
+
+    let power = match INACTIVITY_DECAY.may_load_at_height(deps.storage, height)? {
+        Some(InactivityDecay {
+            inactive_after,
+            factor,
+        }) => {
+            let created = PROPOSALS_CREATED
+                .may_load_at_height(deps.storage, height)?
+                .unwrap_or_default();
+            let last_voted = LAST_VOTED
+                .may_load_at_height(deps.storage, &address, height)?
+                .unwrap_or_default();
+            if created - last_voted >= inactive_after {
+                power * factor
+            } else {
+                power
+            }
+        }
+        None => power,
+    };
     Ok(VotingPowerAtHeightResponse { power, height })
 }
 
@@ -30,4 +30,10 @@ pub enum ContractError {
 
     #[error("Amount being unstaked must be non-zero")]
     ZeroUnstake {},
+
+    #[error("Inactivity decay must apply after a non-zero number of proposals and retain at most all voting power")]
+    InvalidInactivityDecay {},
+
+    #[error("Only the DAO's proposal modules may send hooks")]
+    NotProposalModule {},
 }
@@ -3,6 +3,10 @@ use cosmwasm_std::Uint128;
 use cw_utils::Duration;
 use dao_interface::Admin;
 use dao_macros::voting_module_query;
+use dao_proposal_hooks::ProposalHookMsg;
+use dao_vote_hooks::VoteHookMsg;
+
+use crate::state::InactivityDecay;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -14,6 +18,8 @@ pub struct InstantiateMsg {
     pub denom: String,
     // How long until the tokens become liquid again
     pub unstaking_duration: Option<Duration>,
+    // If set, the voting power of stakers who have not voted on recent proposals is decayed
+    pub inactivity_decay: Option<InactivityDecay>,
 }
 
 #[cw_serde]
@@ -28,6 +34,17 @@ pub enum ExecuteMsg {
         duration: Option<Duration>,
     },
     Claim {},
+    /// Sets or removes the decay applied to the voting power of
+    /// inactive stakers. Only the owner or manager may call this.
+    UpdateInactivityDecay {
+        inactivity_decay: Option<InactivityDecay>,
+    },
+    /// Proposal hook used to count the proposals created in the
+    /// DAO. Only the DAO's proposal modules may call this.
+    ProposalHook(ProposalHookMsg),
+    /// Vote hook used to record when stakers last voted. Only the
+    /// DAO's proposal modules may call this.
+    VoteHook(VoteHookMsg),
 }
 
 #[voting_module_query]
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw_controllers::Claims;
 use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
@@ -10,6 +10,29 @@ pub struct Config {
     pub manager: Option<Addr>,
     pub denom: String,
     pub unstaking_duration: Option<Duration>,
+    /// If set, the voting power of stakers who have not voted recently
+    /// is decayed. Voting power is computed with the decay in effect
+    /// at the queried height, as stored in `INACTIVITY_DECAY`.
+    pub inactivity_decay: Option<InactivityDecay>,
+}
+
+/// Reduces the voting power of stakers who have not voted on any of
+/// the most recently created proposals.
+///
+/// This is a significant change to how voting power is computed.
+/// Voting power is no longer a pure function of staked balances: a
+/// staker's power at a height also depends on how many proposals had
+/// been created, and when they last voted, as of that height. Total
+/// voting power is not decayed, so decayed stakers make thresholds
+/// harder to reach rather than easier.
+#[cw_serde]
+pub struct InactivityDecay {
+    /// The number of proposals that may be created without a staker
+    /// voting before their voting power is decayed. Must be non-zero.
+    pub inactive_after: u64,
+    /// The fraction of their voting power that inactive stakers
+    /// retain. Must be less than or equal to one.
+    pub factor: Decimal,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -32,3 +55,31 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
 pub const MAX_CLAIMS: u64 = 100;
 
 pub const CLAIMS: Claims = Claims::new("claims");
+
+/// The number of proposals that have been created in the DAO, as
+/// reported by proposal hooks.
+pub const PROPOSALS_CREATED: SnapshotItem<u64> = SnapshotItem::new(
+    "proposals_created",
+    "proposals_created__checkpoints",
+    "proposals_created__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The inactivity decay in effect at each height, so that changing it
+/// does not change voting power at past heights. Absent while there
+/// is no decay.
+pub const INACTIVITY_DECAY: SnapshotItem<InactivityDecay> = SnapshotItem::new(
+    "inactivity_decay",
+    "inactivity_decay__checkpoints",
+    "inactivity_decay__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The value of `PROPOSALS_CREATED` when each address last voted, or
+/// when it first staked if it has not voted since.
+pub const LAST_VOTED: SnapshotMap<&Addr, u64> = SnapshotMap::new(
+    "last_voted",
+    "last_voted__checkpoints",
+    "last_voted__changelog",
+    Strategy::EveryBlock,
+);
@@ -1,10 +1,14 @@
 use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::error::ContractError;
 use crate::msg::{
     ExecuteMsg, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg, StakerBalanceResponse,
 };
-use crate::state::Config;
+use crate::state::{Config, InactivityDecay};
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{coins, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{
+    coins, Addr, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, Uint128,
+};
 use cw_controllers::ClaimsResponse;
 use cw_multi_test::{
     custom_app, next_block, App, AppResponse, Contract, ContractWrapper, Executor,
@@ -14,12 +18,15 @@ use dao_interface::voting::{
     InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
 };
 use dao_interface::Admin;
+use dao_proposal_hooks::ProposalHookMsg;
+use dao_vote_hooks::VoteHookMsg;
 
 const DAO_ADDR: &str = "dao";
 const ADDR1: &str = "addr1";
 const ADDR2: &str = "addr2";
 const DENOM: &str = "ujuno";
 const INVALID_DENOM: &str = "uinvalid";
+const PROPOSAL_MODULE: &str = "proposal_module";
 
 fn staking_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -210,6 +217,7 @@ fn test_instantiate() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -222,6 +230,7 @@ fn test_instantiate() {
             manager: None,
             denom: DENOM.to_string(),
             unstaking_duration: None,
+            inactivity_decay: None,
         },
     );
 }
@@ -239,6 +248,7 @@ fn test_instantiate_dao_owner() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -263,6 +273,7 @@ fn test_instantiate_invalid_unstaking_duration() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(0)),
+            inactivity_decay: None,
         },
     );
 
@@ -275,6 +286,7 @@ fn test_instantiate_invalid_unstaking_duration() {
             manager: None,
             denom: DENOM.to_string(),
             unstaking_duration: None,
+            inactivity_decay: None,
         },
     );
 }
@@ -292,6 +304,7 @@ fn test_stake_invalid_denom() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -311,6 +324,7 @@ fn test_stake_valid_denom() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -332,6 +346,7 @@ fn test_unstake_none_staked() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -351,6 +366,7 @@ fn test_unstake_zero_tokens() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -370,6 +386,7 @@ fn test_unstake_invalid_balance() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -393,6 +410,7 @@ fn test_unstake() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -428,6 +446,7 @@ fn test_unstake_no_unstaking_duration() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: None,
+            inactivity_decay: None,
         },
     );
 
@@ -465,6 +484,7 @@ fn test_claim_no_claims() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -484,6 +504,7 @@ fn test_claim_claim_not_reached() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -511,6 +532,7 @@ fn test_claim() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -562,6 +584,7 @@ fn test_update_config_invalid_sender() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -590,6 +613,7 @@ fn test_update_config_non_owner_changes_owner() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -609,6 +633,7 @@ fn test_update_config_as_owner() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -630,6 +655,7 @@ fn test_update_config_as_owner() {
             manager: Some(Addr::unchecked(DAO_ADDR)),
             unstaking_duration: Some(Duration::Height(10)),
             denom: DENOM.to_string(),
+            inactivity_decay: None,
         },
         config
     );
@@ -647,6 +673,7 @@ fn test_update_config_as_manager() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -668,6 +695,7 @@ fn test_update_config_as_manager() {
             manager: Some(Addr::unchecked(ADDR2)),
             unstaking_duration: Some(Duration::Height(10)),
             denom: DENOM.to_string(),
+            inactivity_decay: None,
         },
         config
     );
@@ -686,6 +714,7 @@ fn test_update_config_invalid_duration() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -713,6 +742,7 @@ fn test_query_dao() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -733,6 +763,7 @@ fn test_query_info() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -753,6 +784,7 @@ fn test_query_claims() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -789,6 +821,7 @@ fn test_query_get_config() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -800,6 +833,7 @@ fn test_query_get_config() {
             manager: Some(Addr::unchecked(ADDR1)),
             unstaking_duration: Some(Duration::Height(5)),
             denom: DENOM.to_string(),
+            inactivity_decay: None,
         }
     )
 }
@@ -816,6 +850,7 @@ fn test_voting_power_queries() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -922,6 +957,7 @@ fn test_query_list_stakers() {
             manager: Some(ADDR1.to_string()),
             denom: DENOM.to_string(),
             unstaking_duration: Some(Duration::Height(5)),
+            inactivity_decay: None,
         },
     );
 
@@ -994,6 +1030,175 @@ fn test_query_list_stakers() {
     assert_eq!(stakers, ListStakersResponse { stakers: vec![] });
 }
 
+/// A DAO with a single proposal module, `PROPOSAL_MODULE`.
+fn mock_dao_contract() -> Box<dyn Contract<Empty>> {
+    fn instantiate(deps: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+        dao_core::state::PROPOSAL_MODULES.save(
+            deps.storage,
+            Addr::unchecked(PROPOSAL_MODULE),
+            &dao_core::state::ProposalModule {
+                address: Addr::unchecked(PROPOSAL_MODULE),
+                prefix: "A".to_string(),
+                status: dao_core::state::ProposalModuleStatus::Enabled,
+            },
+        )?;
+        Ok(Response::default())
+    }
+    fn execute(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+    fn query(_: Deps, _: Env, _: Empty) -> StdResult<Binary> {
+        Ok(Binary::default())
+    }
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+fn new_proposal(app: &mut App, staking_addr: &Addr, sender: &str) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        staking_addr.clone(),
+        &ExecuteMsg::ProposalHook(ProposalHookMsg::NewProposal {
+            id: 0,
+            proposer: ADDR1.to_string(),
+        }),
+        &[],
+    )
+}
+
+fn new_vote(app: &mut App, staking_addr: &Addr, voter: &str) {
+    app.execute_contract(
+        Addr::unchecked(PROPOSAL_MODULE),
+        staking_addr.clone(),
+        &ExecuteMsg::VoteHook(VoteHookMsg::NewVote {
+            proposal_id: 0,
+            voter: voter.to_string(),
+            vote: "yes".to_string(),
+        }),
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_inactivity_decay() {
+    let mut app = mock_app();
+    let dao_id = app.store_code(mock_dao_contract());
+    let dao = app
+        .instantiate_contract(
+            dao_id,
+            Addr::unchecked(DAO_ADDR),
+            &Empty {},
+            &[],
+            "dao",
+            None,
+        )
+        .unwrap();
+    let staking_id = app.store_code(staking_contract());
+    let staking_addr = app
+        .instantiate_contract(
+            staking_id,
+            dao,
+            &InstantiateMsg {
+                owner: Some(Admin::Address {
+                    addr: DAO_ADDR.to_string(),
+                }),
+                manager: None,
+                denom: DENOM.to_string(),
+                unstaking_duration: None,
+                inactivity_decay: Some(InactivityDecay {
+                    inactive_after: 2,
+                    factor: Decimal::percent(50),
+                }),
+            },
+            &[],
+            "Staking",
+            None,
+        )
+        .unwrap();
+
+    stake_tokens(&mut app, staking_addr.clone(), ADDR1, 100, DENOM).unwrap();
+    stake_tokens(&mut app, staking_addr.clone(), ADDR2, 100, DENOM).unwrap();
+    app.update_block(next_block);
+    let before_proposals = app.block_info().height;
+
+    // Only the DAO's proposal modules may send hooks.
+    let err: ContractError = new_proposal(&mut app, &staking_addr, ADDR1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NotProposalModule {}));
+
+    new_proposal(&mut app, &staking_addr, PROPOSAL_MODULE).unwrap();
+    new_proposal(&mut app, &staking_addr, PROPOSAL_MODULE).unwrap();
+    new_vote(&mut app, &staking_addr, ADDR1);
+    app.update_block(next_block);
+
+    // ADDR2 has not voted on the last two proposals.
+    let power = |app: &mut App, address: &str, height: Option<u64>| {
+        get_voting_power_at_height(app, staking_addr.clone(), address.to_string(), height)
+            .power
+            .u128()
+    };
+    assert_eq!(power(&mut app, ADDR1, None), 100);
+    assert_eq!(power(&mut app, ADDR2, None), 50);
+    // Total power is not decayed.
+    let total = get_total_power_at_height(&mut app, staking_addr.clone(), None);
+    assert_eq!(total.power, Uint128::new(200));
+    // Historical power reflects activity at that height.
+    assert_eq!(power(&mut app, ADDR2, Some(before_proposals)), 100);
+
+    // One more proposal is not enough to make ADDR1 inactive.
+    new_proposal(&mut app, &staking_addr, PROPOSAL_MODULE).unwrap();
+    app.update_block(next_block);
+    assert_eq!(power(&mut app, ADDR1, None), 100);
+
+    new_proposal(&mut app, &staking_addr, PROPOSAL_MODULE).unwrap();
+    new_vote(&mut app, &staking_addr, ADDR2);
+    app.update_block(next_block);
+    assert_eq!(power(&mut app, ADDR1, None), 50);
+    assert_eq!(power(&mut app, ADDR2, None), 100);
+
+    // New stakers are not decayed for proposals created before they
+    // staked.
+    stake_tokens(&mut app, staking_addr.clone(), DAO_ADDR, 100, DENOM).unwrap();
+    app.update_block(next_block);
+    assert_eq!(power(&mut app, DAO_ADDR, None), 100);
+    let decayed = app.block_info().height;
+
+    // Removing the decay restores full voting power from the next
+    // block, without changing voting power at past heights.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        staking_addr.clone(),
+        &ExecuteMsg::UpdateInactivityDecay {
+            inactivity_decay: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    assert_eq!(power(&mut app, ADDR1, None), 100);
+    assert_eq!(power(&mut app, ADDR1, Some(decayed)), 50);
+
+    // Invalid decays are rejected.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO_ADDR),
+            staking_addr,
+            &ExecuteMsg::UpdateInactivityDecay {
+                inactivity_decay: Some(InactivityDecay {
+                    inactive_after: 0,
+                    factor: Decimal::percent(50),
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InvalidInactivityDecay {}));
+}
+
 #[test]
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
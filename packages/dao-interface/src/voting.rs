@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Timestamp, Uint128};
 use cw2::ContractVersion;
 use dao_macros::{active_query, token_query, voting_module_query};
 
@@ -32,6 +32,25 @@ pub struct IsActiveResponse {
     pub active: bool,
 }
 
+/// Queries for when an address last unstaked. Voting modules that
+/// stake through a separate contract respond to `StakingContract {}`
+/// with its address, and the staking contract responds to
+/// `LastUnstake {}`, as `dao-voting-cw20-staked` and `cw20-stake` do.
+#[cw_serde]
+pub enum StakingQuery {
+    StakingContract {},
+    LastUnstake { address: String },
+}
+
+/// The block at which an address last unstaked. Returned as an
+/// option by `StakingQuery::LastUnstake`, None if the address has
+/// never unstaked.
+#[cw_serde]
+pub struct LastUnstakeResponse {
+    pub height: u64,
+    pub time: Timestamp,
+}
+
 mod tests {
 
     /// Make sure the enum has all of the fields we expect. This will
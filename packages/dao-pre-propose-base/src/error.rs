@@ -1,6 +1,6 @@
 use cosmwasm_std::StdError;
 use cw_denom::DenomError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
 use thiserror::Error;
 
 use cw_hooks::HookError;
@@ -47,6 +47,15 @@ pub enum PreProposeError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("No deposit refund is waiting to be claimed for proposal ({id})")]
+    NoPendingRefund { id: u64 },
+
+    #[error("Proposal ({id}) does not have a deposit awaiting manual handling")]
+    NotManualDeposit { id: u64 },
+
+    #[error("Deposit refund is locked until ({claimable_at})")]
+    DepositLocked { claimable_at: Expiration },
+
     #[error("An unknown reply ID was received.")]
     UnknownReplyID {},
 }
@@ -1,21 +1,27 @@
 use cosmwasm_schema::schemars::JsonSchema;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg, WasmMsg,
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult,
+    Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
 
 use cw_denom::UncheckedDenom;
+use cw_storage_plus::Map;
+use cw_utils::Duration;
 use dao_interface::voting::{Query as CwCoreQuery, VotingPowerAtHeightResponse};
 use dao_voting::{
-    deposit::{DepositRefundPolicy, UncheckedDepositInfo},
+    deposit::{CheckedDepositInfo, DepositRefundPolicy, UncheckedDepositInfo},
     status::Status,
 };
 use serde::Serialize;
 
 use crate::{
     error::PreProposeError,
-    msg::{DepositInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
+    msg::{
+        DepositAccountingResponse, DepositInfoResponse, ExecuteMsg, HeldDeposit,
+        HeldDepositsResponse, InstantiateMsg, PendingRefund, QueryMsg,
+    },
     state::{Config, PreProposeContract},
 };
 
@@ -58,6 +64,8 @@ where
         let config = Config {
             deposit_info,
             open_proposal_submission: msg.open_proposal_submission,
+            deposit_min_lock: msg.deposit_min_lock,
+            dao_reimburses_passed_deposits: msg.dao_reimburses_passed_deposits,
         };
 
         self.config.save(deps.storage, &config)?;
@@ -85,7 +93,19 @@ where
             ExecuteMsg::UpdateConfig {
                 deposit_info,
                 open_proposal_submission,
-            } => self.execute_update_config(deps, info, deposit_info, open_proposal_submission),
+                deposit_min_lock,
+                dao_reimburses_passed_deposits,
+            } => self.execute_update_config(
+                deps,
+                info,
+                deposit_info,
+                open_proposal_submission,
+                deposit_min_lock,
+                dao_reimburses_passed_deposits,
+            ),
+            ExecuteMsg::UpdateDepositInfo { deposit_info } => {
+                self.execute_update_deposit_info(deps, info, deposit_info)
+            }
             ExecuteMsg::Withdraw { denom } => {
                 self.execute_withdraw(deps.as_ref(), env, info, denom)
             }
@@ -98,7 +118,14 @@ where
             ExecuteMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
-            } => self.execute_proposal_completed_hook(deps.as_ref(), info, proposal_id, new_status),
+            } => self.execute_proposal_completed_hook(deps, env, info, proposal_id, new_status),
+            ExecuteMsg::ClaimDeposit { proposal_id } => {
+                self.execute_claim_deposit(deps, env, proposal_id)
+            }
+            ExecuteMsg::ReleaseDeposit {
+                proposal_id,
+                recipient,
+            } => self.execute_release_deposit(deps, info, proposal_id, recipient),
 
             ExecuteMsg::Extension { .. } => Ok(Response::default()),
         }
@@ -133,8 +160,9 @@ where
         self.deposits.save(
             deps.storage,
             next_id,
-            &(config.deposit_info, info.sender.clone()),
+            &(config.deposit_info.clone(), info.sender.clone()),
         )?;
+        self.add_held_deposit(deps.storage, &config.deposit_info)?;
 
         let propose_messsage = WasmMsg::Execute {
             contract_addr: proposal_module.into_string(),
@@ -171,6 +199,8 @@ where
         info: MessageInfo,
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        deposit_min_lock: Option<Duration>,
+        dao_reimburses_passed_deposits: bool,
     ) -> Result<Response, PreProposeError> {
         let dao = self.dao.load(deps.storage)?;
         if info.sender != dao {
@@ -184,6 +214,8 @@ where
                 &Config {
                     deposit_info,
                     open_proposal_submission,
+                    deposit_min_lock,
+                    dao_reimburses_passed_deposits,
                 },
             )?;
 
@@ -193,6 +225,34 @@ where
         }
     }
 
+    pub fn execute_update_deposit_info(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        deposit_info: Option<UncheckedDepositInfo>,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let deposit_info = deposit_info
+            .map(|d| d.into_checked(deps.as_ref(), dao))
+            .transpose()?;
+        // Deposits for existing proposals are snapshotted in
+        // `deposits` when they are created, so this only applies to
+        // future proposals.
+        self.config
+            .update(deps.storage, |mut config| -> StdResult<_> {
+                config.deposit_info = deposit_info;
+                Ok(config)
+            })?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_deposit_info")
+            .add_attribute("sender", info.sender))
+    }
+
     pub fn execute_withdraw(
         &self,
         deps: Deps,
@@ -214,7 +274,16 @@ where
             match denom {
                 None => Err(PreProposeError::NoWithdrawalDenom {}),
                 Some(denom) => {
-                    let balance = denom.query_balance(&deps.querier, &env.contract.address)?;
+                    // Refunds waiting to be claimed belong to their
+                    // proposers, so are not withdrawn.
+                    let pending = self
+                        .pending_refund_totals
+                        .may_load(deps.storage, denom.to_string())?
+                        .map(|total| total.amount)
+                        .unwrap_or_default();
+                    let balance = denom
+                        .query_balance(&deps.querier, &env.contract.address)?
+                        .saturating_sub(pending);
                     if balance.is_zero() {
                         Err(PreProposeError::NothingToWithdraw {})
                     } else {
@@ -270,7 +339,8 @@ where
 
     pub fn execute_proposal_completed_hook(
         &self,
-        deps: Deps,
+        deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         id: u64,
         new_status: Status,
@@ -289,33 +359,76 @@ where
             return Err(PreProposeError::NotClosedOrExecuted { status: new_status });
         }
 
+        // Deposits awaiting manual handling that were not released by
+        // the proposal's messages are handled as usual so that they
+        // are not stranded in this module.
+        self.manual_deposits.remove(deps.storage, id);
+
         match self.deposits.may_load(deps.storage, id)? {
             Some((deposit_info, proposer)) => {
+                let config = self.config.load(deps.storage)?;
+                let mut claimable_at = None;
                 let messages = if let Some(ref deposit_info) = deposit_info {
                     // Refund can be issued if proposal if it is going to
-                    // closed or executed.
+                    // closed or executed. If the DAO reimburses passed
+                    // proposals' deposits they are refunded regardless
+                    // of the refund policy.
                     let should_refund_to_proposer = (new_status == Status::Closed
                         && deposit_info.refund_policy == DepositRefundPolicy::Always)
                         || (new_status == Status::Executed
-                            && deposit_info.refund_policy != DepositRefundPolicy::Never);
+                            && (deposit_info.refund_policy != DepositRefundPolicy::Never
+                                || config.dao_reimburses_passed_deposits));
 
                     if should_refund_to_proposer {
-                        deposit_info.get_return_deposit_message(&proposer)?
+                        match config.deposit_min_lock {
+                            // Locked refunds stay held until they
+                            // are claimed.
+                            Some(lock) => {
+                                let refund = PendingRefund {
+                                    deposit_info: deposit_info.clone(),
+                                    proposer,
+                                    claimable_at: lock.after(&env.block),
+                                };
+                                self.pending_refunds.save(deps.storage, id, &refund)?;
+                                if deposit_info.staking_contract.is_none() {
+                                    add_deposit_total(
+                                        deps.storage,
+                                        &self.pending_refund_totals,
+                                        deposit_info,
+                                    )?;
+                                }
+                                claimable_at = Some(refund.claimable_at);
+                                vec![]
+                            }
+                            None => {
+                                self.record_refunded_deposit(deps.storage, deposit_info)?;
+                                deposit_info.get_return_deposit_message(&proposer)?
+                            }
+                        }
                     } else {
                         // If the proposer doesn't get the deposit, the DAO does.
                         let dao = self.dao.load(deps.storage)?;
-                        deposit_info.get_return_deposit_message(&dao)?
+                        self.record_forfeited_deposit(deps.storage, deposit_info)?;
+                        deposit_info.get_forfeit_deposit_messages(&proposer, &dao)?
                     }
                 } else {
                     // No deposit info for this proposal. Nothing to do.
                     vec![]
                 };
+                if claimable_at.is_none() {
+                    self.remove_held_deposit(deps.storage, &deposit_info)?;
+                }
 
-                Ok(Response::default()
+                let mut response = Response::default()
                     .add_attribute("method", "execute_proposal_completed_hook")
                     .add_attribute("proposal", id.to_string())
                     .add_attribute("deposit_info", to_binary(&deposit_info)?.to_string())
-                    .add_messages(messages))
+                    .add_messages(messages);
+                if let Some(claimable_at) = claimable_at {
+                    response =
+                        response.add_attribute("refund_claimable_at", claimable_at.to_string());
+                }
+                Ok(response)
             }
 
             // If we do not have a deposit for this proposal it was
@@ -328,6 +441,156 @@ where
         }
     }
 
+    pub fn execute_claim_deposit(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let refund = self
+            .pending_refunds
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::NoPendingRefund { id })?;
+        if !refund.claimable_at.is_expired(&env.block) {
+            return Err(PreProposeError::DepositLocked {
+                claimable_at: refund.claimable_at,
+            });
+        }
+        self.pending_refunds.remove(deps.storage, id);
+        if refund.deposit_info.staking_contract.is_none() {
+            sub_deposit_total(
+                deps.storage,
+                &self.pending_refund_totals,
+                &refund.deposit_info,
+            )?;
+        }
+        self.remove_held_deposit(deps.storage, &Some(refund.deposit_info.clone()))?;
+        self.record_refunded_deposit(deps.storage, &refund.deposit_info)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "claim_deposit")
+            .add_attribute("proposal", id.to_string())
+            .add_attribute("proposer", refund.proposer.as_str())
+            .add_messages(
+                refund
+                    .deposit_info
+                    .get_return_deposit_message(&refund.proposer)?,
+            ))
+    }
+
+    pub fn execute_release_deposit(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        id: u64,
+        recipient: String,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+        if !self.manual_deposits.has(deps.storage, id) {
+            return Err(PreProposeError::NotManualDeposit { id });
+        }
+        let recipient = deps.api.addr_validate(&recipient)?;
+        let (deposit_info, proposer) = self.deposits.load(deps.storage, id)?;
+
+        // Clearing the snapshotted deposit stops the completed hook
+        // from paying it out a second time.
+        self.manual_deposits.remove(deps.storage, id);
+        self.deposits
+            .save(deps.storage, id, &(None, proposer.clone()))?;
+        self.remove_held_deposit(deps.storage, &deposit_info)?;
+
+        let messages = match deposit_info {
+            Some(ref deposit_info) => {
+                if recipient == proposer {
+                    self.record_refunded_deposit(deps.storage, deposit_info)?;
+                } else {
+                    self.record_forfeited_deposit(deps.storage, deposit_info)?;
+                }
+                deposit_info.get_forfeit_deposit_messages(&proposer, &recipient)?
+            }
+            None => vec![],
+        };
+
+        Ok(Response::default()
+            .add_attribute("method", "release_deposit")
+            .add_attribute("proposal", id.to_string())
+            .add_attribute("recipient", recipient)
+            .add_messages(messages))
+    }
+
+    /// Marks the deposit of the proposal that is about to be created
+    /// by this module as awaiting manual handling. Its deposit will
+    /// not be paid out until it is released with `ReleaseDeposit` or
+    /// the proposal completes. Must be called while handling the
+    /// propose message, before the proposal is created.
+    pub fn set_manual_deposit_handling(&self, deps: DepsMut) -> Result<u64, PreProposeError> {
+        let proposal_module = self.proposal_module.load(deps.storage)?;
+        let next_id = deps.querier.query_wasm_smart(
+            &proposal_module,
+            &dao_interface::proposal::Query::NextProposalId {},
+        )?;
+        self.manual_deposits
+            .save(deps.storage, next_id, &Empty {})?;
+        Ok(next_id)
+    }
+
+    /// Adds DEPOSIT_INFO to the running total of deposits held by
+    /// this module. Should be called whenever a deposit is taken.
+    pub fn add_held_deposit(
+        &self,
+        storage: &mut dyn Storage,
+        deposit_info: &Option<CheckedDepositInfo>,
+    ) -> StdResult<()> {
+        match deposit_info {
+            // Staked deposits are locked in their staking contract
+            // rather than held by this module, so they are not
+            // counted as held.
+            Some(deposit_info) if deposit_info.staking_contract.is_none() => {
+                add_deposit_total(storage, &self.held_deposits, deposit_info)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes DEPOSIT_INFO from the running total of deposits held
+    /// by this module. Should be called whenever a deposit is paid
+    /// out.
+    pub fn remove_held_deposit(
+        &self,
+        storage: &mut dyn Storage,
+        deposit_info: &Option<CheckedDepositInfo>,
+    ) -> StdResult<()> {
+        match deposit_info {
+            Some(deposit_info) if deposit_info.staking_contract.is_none() => {
+                sub_deposit_total(storage, &self.held_deposits, deposit_info)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds DEPOSIT_INFO to the lifetime total of deposits refunded
+    /// to proposers. Should be called whenever a deposit is returned.
+    pub fn record_refunded_deposit(
+        &self,
+        storage: &mut dyn Storage,
+        deposit_info: &CheckedDepositInfo,
+    ) -> StdResult<()> {
+        add_deposit_total(storage, &self.refunded_deposits, deposit_info)
+    }
+
+    /// Adds DEPOSIT_INFO to the lifetime total of deposits forfeited
+    /// to the DAO. Should be called whenever a deposit is forfeited.
+    pub fn record_forfeited_deposit(
+        &self,
+        storage: &mut dyn Storage,
+        deposit_info: &CheckedDepositInfo,
+    ) -> StdResult<()> {
+        add_deposit_total(storage, &self.forfeited_deposits, deposit_info)
+    }
+
     pub fn check_can_submit(&self, deps: Deps, who: Addr) -> Result<(), PreProposeError> {
         let config = self.config.load(deps.storage)?;
 
@@ -362,7 +625,69 @@ where
             QueryMsg::ProposalSubmittedHooks {} => {
                 to_binary(&self.proposal_submitted_hooks.query_hooks(deps)?)
             }
+            QueryMsg::HeldDeposits {} => to_binary(&HeldDepositsResponse {
+                deposits: list_deposit_totals(deps, &self.held_deposits)?,
+            }),
+            QueryMsg::DepositAccounting {} => to_binary(&DepositAccountingResponse {
+                total_refunded: list_deposit_totals(deps, &self.refunded_deposits)?,
+                total_forfeited: list_deposit_totals(deps, &self.forfeited_deposits)?,
+                currently_held: list_deposit_totals(deps, &self.held_deposits)?,
+            }),
+            QueryMsg::PendingRefund { proposal_id } => {
+                to_binary(&self.pending_refunds.may_load(deps.storage, proposal_id)?)
+            }
             QueryMsg::QueryExtension { .. } => Ok(Binary::default()),
         }
     }
 }
+
+fn list_deposit_totals(
+    deps: Deps,
+    totals: &Map<'static, String, HeldDeposit>,
+) -> StdResult<Vec<HeldDeposit>> {
+    totals
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, total)| total))
+        .collect()
+}
+
+/// Adds DEPOSIT_INFO to the per-denomination running total
+/// TOTALS.
+fn add_deposit_total(
+    storage: &mut dyn Storage,
+    totals: &Map<'static, String, HeldDeposit>,
+    deposit_info: &CheckedDepositInfo,
+) -> StdResult<()> {
+    let CheckedDepositInfo { denom, amount, .. } = deposit_info;
+    totals.update(storage, denom.to_string(), |total| -> StdResult<_> {
+        let mut total = total.unwrap_or(HeldDeposit {
+            denom: denom.clone(),
+            amount: Uint128::zero(),
+        });
+        total.amount = total.amount.checked_add(*amount)?;
+        Ok(total)
+    })?;
+    Ok(())
+}
+
+/// Removes DEPOSIT_INFO from the per-denomination running total
+/// TOTALS.
+fn sub_deposit_total(
+    storage: &mut dyn Storage,
+    totals: &Map<'static, String, HeldDeposit>,
+    deposit_info: &CheckedDepositInfo,
+) -> StdResult<()> {
+    let key = deposit_info.denom.to_string();
+    if let Some(mut total) = totals.may_load(storage, key.clone())? {
+        // Deposits taken before a total was tracked are not included
+        // in it, so saturate rather than error when they are paid
+        // out.
+        total.amount = total.amount.saturating_sub(deposit_info.amount);
+        if total.amount.is_zero() {
+            totals.remove(storage, key);
+        } else {
+            totals.save(storage, key, &total)?;
+        }
+    }
+    Ok(())
+}
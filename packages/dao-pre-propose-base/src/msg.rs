@@ -1,5 +1,7 @@
 use cosmwasm_schema::{cw_serde, schemars::JsonSchema, QueryResponses};
-use cw_denom::UncheckedDenom;
+use cosmwasm_std::{Addr, Uint128};
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_utils::{Duration, Expiration};
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
     status::Status,
@@ -14,6 +16,16 @@ pub struct InstantiateMsg<InstantiateExt> {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// How long after a proposal completes its deposit stays
+    /// locked. If set, refunded deposits must be claimed with
+    /// `ClaimDeposit` once the lock ends, which discourages creating
+    /// and quickly withdrawing proposals. If None, deposits are
+    /// refunded when proposals complete.
+    pub deposit_min_lock: Option<Duration>,
+    /// If true, the DAO reimburses the deposits of proposals that
+    /// pass, regardless of the deposit's refund policy.
+    #[serde(default)]
+    pub dao_reimburses_passed_deposits: bool,
     /// Extension for instantiation. The default implementation will
     /// do nothing with this data.
     pub extension: InstantiateExt,
@@ -32,12 +44,24 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     UpdateConfig {
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        deposit_min_lock: Option<Duration>,
+        #[serde(default)]
+        dao_reimburses_passed_deposits: bool,
+    },
+
+    /// Updates the deposit required to create proposals, leaving the
+    /// rest of the module's configuration unchanged. Proposals that
+    /// have already been created keep the deposit they were created
+    /// with and are refunded in that deposit's token. Only the DAO
+    /// may execute this message.
+    UpdateDepositInfo {
+        deposit_info: Option<UncheckedDepositInfo>,
     },
 
     /// Withdraws funds inside of this contract to the message
-    /// sender. The contracts entire balance for the specifed DENOM is
-    /// withdrawn to the message sender. Only the DAO may call this
-    /// method.
+    /// sender. The contracts entire balance for the specifed DENOM,
+    /// less any deposit refunds waiting to be claimed, is withdrawn
+    /// to the message sender. Only the DAO may call this method.
     ///
     /// This is intended only as an escape hatch in the event of a
     /// critical bug in this contract or it's proposal
@@ -65,6 +89,20 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
         denom: Option<UncheckedDenom>,
     },
 
+    /// Sends the locked deposit refund of the proposal identified by
+    /// PROPOSAL_ID to its proposer. Errors if the deposit's lock has
+    /// not ended. Anyone may call this method.
+    ClaimDeposit { proposal_id: u64 },
+
+    /// Sends the deposit of the proposal identified by PROPOSAL_ID to
+    /// RECIPIENT. Only proposals created with manual deposit handling
+    /// may have their deposit released this way, and only the DAO may
+    /// call this method. This is intended to be included in the
+    /// proposal's own messages so that it may decide what happens to
+    /// its deposit. Deposits that have not been released when their
+    /// proposal completes are handled according to the refund policy.
+    ReleaseDeposit { proposal_id: u64, recipient: String },
+
     /// Extension message. Contracts that extend this one should put
     /// their custom execute logic here. The default implementation
     /// will do nothing if this variant is executed.
@@ -112,6 +150,22 @@ where
     /// Returns list of proposal submitted hooks.
     #[returns(cw_hooks::HooksResponse)]
     ProposalSubmittedHooks {},
+    /// Gets the total amount of proposal deposits currently held by
+    /// this module for proposals that have not yet completed, per
+    /// denomination. Staked deposits are locked in their staking
+    /// contract rather than held, so they are not included.
+    #[returns(HeldDepositsResponse)]
+    HeldDeposits {},
+    /// Gets the total amount of proposal deposits this module has
+    /// refunded and forfeited over its lifetime, along with the
+    /// amount it currently holds, per denomination.
+    #[returns(DepositAccountingResponse)]
+    DepositAccounting {},
+    /// Gets the locked deposit refund of the proposal identified by
+    /// PROPOSAL_ID, or None if it has no refund waiting to be
+    /// claimed.
+    #[returns(Option<PendingRefund>)]
+    PendingRefund { proposal_id: u64 },
     /// Extension for queries. The default implementation will do
     /// nothing if queried for will return `Binary::default()`.
     #[returns(cosmwasm_std::Binary)]
@@ -125,3 +179,41 @@ pub struct DepositInfoResponse {
     /// The address that created the proposal.
     pub proposer: cosmwasm_std::Addr,
 }
+
+#[cw_serde]
+pub struct HeldDeposit {
+    /// The denomination of the deposits.
+    pub denom: CheckedDenom,
+    /// The total amount of deposits of this denomination being held.
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct HeldDepositsResponse {
+    pub deposits: Vec<HeldDeposit>,
+}
+
+#[cw_serde]
+pub struct DepositAccountingResponse {
+    /// Deposits that have been returned to their proposers, including
+    /// unlocked staked deposits.
+    pub total_refunded: Vec<HeldDeposit>,
+    /// Deposits that have been sent to the DAO, including forfeited
+    /// staked deposits.
+    pub total_forfeited: Vec<HeldDeposit>,
+    /// Deposits held for proposals that have not yet completed or
+    /// whose refunds have not yet been claimed. Staked deposits are
+    /// not held by this module and are not included.
+    pub currently_held: Vec<HeldDeposit>,
+}
+
+/// A deposit refund that may be claimed once its lock ends.
+#[cw_serde]
+pub struct PendingRefund {
+    /// The deposit to refund.
+    pub deposit_info: CheckedDepositInfo,
+    /// The address the deposit is refunded to.
+    pub proposer: Addr,
+    /// When the refund may be claimed.
+    pub claimable_at: Expiration,
+}
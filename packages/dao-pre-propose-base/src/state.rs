@@ -1,12 +1,15 @@
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Empty};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
 
 use dao_voting::deposit::CheckedDepositInfo;
 
+use crate::msg::{HeldDeposit, PendingRefund};
+
 #[cw_serde]
 pub struct Config {
     /// Information about the deposit required to create a
@@ -16,6 +19,20 @@ pub struct Config {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// How long after a proposal completes its deposit stays locked
+    /// in this module. If set, refunds must be claimed with
+    /// `ClaimDeposit` once the lock ends rather than being sent when
+    /// the proposal completes. Deposits that are not refunded are
+    /// unaffected.
+    pub deposit_min_lock: Option<Duration>,
+    /// If true, the deposits of proposals that pass are reimbursed
+    /// to their proposers by the DAO, regardless of the deposit's
+    /// refund policy. The DAO would receive the escrowed deposit and
+    /// pay the proposer the same amount, so the escrowed deposit is
+    /// sent to the proposer directly and the DAO's balance is
+    /// unchanged.
+    #[serde(default)]
+    pub dao_reimburses_passed_deposits: bool,
 }
 
 pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> {
@@ -28,6 +45,25 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
     pub config: Item<'static, Config>,
     /// Map between proposal IDs and (deposit, proposer) pairs.
     pub deposits: Map<'static, u64, (Option<CheckedDepositInfo>, Addr)>,
+    /// Running total of the deposits currently escrowed by this
+    /// module, keyed by the string form of their denomination.
+    pub held_deposits: Map<'static, String, HeldDeposit>,
+    /// Running total of the deposits this module has refunded to
+    /// proposers, keyed by the string form of their denomination.
+    pub refunded_deposits: Map<'static, String, HeldDeposit>,
+    /// Running total of the deposits this module has forfeited to
+    /// the DAO, keyed by the string form of their denomination.
+    pub forfeited_deposits: Map<'static, String, HeldDeposit>,
+    /// Refunds of completed proposals' deposits that are locked until
+    /// `deposit_min_lock` after the proposal completed.
+    pub pending_refunds: Map<'static, u64, PendingRefund>,
+    /// Running total of the escrowed deposits in `pending_refunds`,
+    /// keyed by the string form of their denomination. These may not
+    /// be withdrawn by the DAO.
+    pub pending_refund_totals: Map<'static, String, HeldDeposit>,
+    /// Proposals whose deposits are waiting to be released by their
+    /// own messages rather than refunded automatically.
+    pub manual_deposits: Map<'static, u64, Empty>,
     /// Consumers of proposal submitted hooks.
     pub proposal_submitted_hooks: Hooks<'static>,
 
@@ -48,6 +84,12 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
         dao_key: &'static str,
         config_key: &'static str,
         deposits_key: &'static str,
+        held_deposits_key: &'static str,
+        refunded_deposits_key: &'static str,
+        forfeited_deposits_key: &'static str,
+        pending_refunds_key: &'static str,
+        pending_refund_totals_key: &'static str,
+        manual_deposits_key: &'static str,
         proposal_submitted_hooks_key: &'static str,
     ) -> Self {
         Self {
@@ -55,6 +97,12 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
             dao: Item::new(dao_key),
             config: Item::new(config_key),
             deposits: Map::new(deposits_key),
+            held_deposits: Map::new(held_deposits_key),
+            refunded_deposits: Map::new(refunded_deposits_key),
+            forfeited_deposits: Map::new(forfeited_deposits_key),
+            pending_refunds: Map::new(pending_refunds_key),
+            pending_refund_totals: Map::new(pending_refund_totals_key),
+            manual_deposits: Map::new(manual_deposits_key),
             proposal_submitted_hooks: Hooks::new(proposal_submitted_hooks_key),
             execute_type: PhantomData,
             instantiate_type: PhantomData,
@@ -76,6 +124,12 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> Default
             "dao",
             "config",
             "deposits",
+            "held_deposits",
+            "refunded_deposits",
+            "forfeited_deposits",
+            "pending_refunds",
+            "pending_refund_totals",
+            "manual_deposits",
             "proposal_submitted_hooks",
         )
     }
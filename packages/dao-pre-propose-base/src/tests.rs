@@ -1,14 +1,22 @@
 use cosmwasm_std::{
-    from_binary,
-    testing::{mock_dependencies, mock_env, mock_info},
-    to_binary, Addr, Binary, ContractResult, Empty, Response, SubMsg, WasmMsg,
+    coins, from_binary,
+    testing::{
+        mock_dependencies, mock_dependencies_with_balance, mock_env, mock_info, MOCK_CONTRACT_ADDR,
+    },
+    to_binary, Addr, BankMsg, Binary, ContractResult, CosmosMsg, Empty, Response, SubMsg, Uint128,
+    WasmMsg,
 };
+use cw_denom::{CheckedDenom, UncheckedDenom};
 use cw_hooks::HooksResponse;
-use dao_voting::status::Status;
+use cw_utils::{Duration, Expiration};
+use dao_voting::{
+    deposit::{CheckedDepositInfo, DepositRefundPolicy},
+    status::Status,
+};
 
 use crate::{
     error::PreProposeError,
-    msg::{ExecuteMsg, QueryMsg},
+    msg::{ExecuteMsg, PendingRefund, QueryMsg},
     state::{Config, PreProposeContract},
 };
 
@@ -88,6 +96,8 @@ fn test_proposal_submitted_hooks() {
             &Config {
                 deposit_info: None,
                 open_proposal_submission: true,
+                deposit_min_lock: None,
+                dao_reimburses_passed_deposits: false,
             },
         )
         .unwrap();
@@ -200,3 +210,228 @@ fn test_execute_ext_does_nothing() {
         .unwrap();
     assert_eq!(res, Response::default())
 }
+
+#[test]
+fn test_locked_deposit_refund() {
+    let mut deps = mock_dependencies();
+    let module = Contract::default();
+
+    module
+        .dao
+        .save(&mut deps.storage, &Addr::unchecked("d"))
+        .unwrap();
+    module
+        .proposal_module
+        .save(&mut deps.storage, &Addr::unchecked("pm"))
+        .unwrap();
+    module
+        .config
+        .save(
+            &mut deps.storage,
+            &Config {
+                deposit_info: None,
+                open_proposal_submission: true,
+                deposit_min_lock: Some(Duration::Height(10)),
+                dao_reimburses_passed_deposits: false,
+            },
+        )
+        .unwrap();
+    let deposit_info = CheckedDepositInfo {
+        denom: CheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(10),
+        refund_policy: DepositRefundPolicy::Always,
+        staking_contract: None,
+    };
+    module
+        .deposits
+        .save(
+            &mut deps.storage,
+            1,
+            &(Some(deposit_info.clone()), Addr::unchecked("a")),
+        )
+        .unwrap();
+
+    // Completing the proposal locks the refund rather than sending
+    // it.
+    let env = mock_env();
+    let res = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("pm", &[]),
+            ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Closed,
+            },
+        )
+        .unwrap();
+    assert!(res.messages.is_empty());
+    let refund: Option<PendingRefund> = from_binary(
+        &module
+            .query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::PendingRefund { proposal_id: 1 },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    let claimable_at = Expiration::AtHeight(env.block.height + 10);
+    assert_eq!(
+        refund,
+        Some(PendingRefund {
+            deposit_info,
+            proposer: Addr::unchecked("a"),
+            claimable_at,
+        })
+    );
+
+    // The refund can not be claimed before the lock ends.
+    let claim = ExecuteMsg::ClaimDeposit { proposal_id: 1 };
+    let err = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("a", &[]),
+            claim.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::DepositLocked { claimable_at });
+
+    // Once it has, anyone may claim it for the proposer.
+    let mut env = env;
+    env.block.height += 10;
+    let res = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("b", &[]),
+            claim.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "a".to_string(),
+            amount: cosmwasm_std::coins(10, "ujuno"),
+        }))]
+    );
+
+    let err = module
+        .execute(deps.as_mut(), env, mock_info("a", &[]), claim)
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NoPendingRefund { id: 1 });
+}
+
+#[test]
+fn test_withdraw_keeps_pending_refunds() {
+    let mut deps = mock_dependencies_with_balance(&coins(15, "ujuno"));
+    let module = Contract::default();
+
+    module
+        .dao
+        .save(&mut deps.storage, &Addr::unchecked("d"))
+        .unwrap();
+    module
+        .proposal_module
+        .save(&mut deps.storage, &Addr::unchecked("pm"))
+        .unwrap();
+    module
+        .config
+        .save(
+            &mut deps.storage,
+            &Config {
+                deposit_info: None,
+                open_proposal_submission: true,
+                deposit_min_lock: Some(Duration::Height(10)),
+                dao_reimburses_passed_deposits: false,
+            },
+        )
+        .unwrap();
+    module
+        .deposits
+        .save(
+            &mut deps.storage,
+            1,
+            &(
+                Some(CheckedDepositInfo {
+                    denom: CheckedDenom::Native("ujuno".to_string()),
+                    amount: Uint128::new(10),
+                    refund_policy: DepositRefundPolicy::Always,
+                    staking_contract: None,
+                }),
+                Addr::unchecked("a"),
+            ),
+        )
+        .unwrap();
+
+    let mut env = mock_env();
+    module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("pm", &[]),
+            ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Closed,
+            },
+        )
+        .unwrap();
+
+    // Only the balance that is not waiting to be refunded is
+    // withdrawn.
+    let withdraw = ExecuteMsg::Withdraw {
+        denom: Some(UncheckedDenom::Native("ujuno".to_string())),
+    };
+    let res = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("d", &[]),
+            withdraw.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "d".to_string(),
+            amount: coins(5, "ujuno"),
+        }))]
+    );
+    deps.querier
+        .update_balance(MOCK_CONTRACT_ADDR, coins(10, "ujuno"));
+    let err = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("d", &[]),
+            withdraw.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NothingToWithdraw {});
+
+    // The refund may still be claimed.
+    env.block.height += 10;
+    let res = module
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("a", &[]),
+            ExecuteMsg::ClaimDeposit { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "a".to_string(),
+            amount: coins(10, "ujuno"),
+        }))]
+    );
+    assert_eq!(
+        module
+            .pending_refund_totals
+            .may_load(&deps.storage, "ujuno".to_string())
+            .unwrap(),
+        None
+    );
+}
@@ -142,6 +142,7 @@ pub fn instantiate_with_staked_balances_governance(
                     staking_code_id: cw20_stake_id,
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
             })
             .unwrap(),
@@ -257,6 +258,7 @@ pub fn instantiate_with_staking_active_threshold(
                     staking_code_id: cw20_staking_id,
                     unstaking_duration: None,
                     initial_dao_balance: None,
+                    auto_stake: false,
                 },
                 active_threshold,
             })
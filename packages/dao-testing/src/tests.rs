@@ -318,7 +318,9 @@ where
         Threshold::AbsolutePercentage {
             percentage: PercentageThreshold::Percent(Decimal::percent(50)),
         },
-        Status::Passed,
+        // Yes and no are exactly tied, so the tie policy decides the
+        // outcome. By default ties are rejected.
+        Status::Rejected,
         None,
     );
 
@@ -455,7 +457,9 @@ where
             threshold: PercentageThreshold::Percent(Decimal::percent(50)),
             quorum: PercentageThreshold::Majority {},
         },
-        Status::Passed,
+        // Yes and no are exactly tied, so the tie policy decides the
+        // outcome. By default ties are rejected.
+        Status::Rejected,
         None,
     );
 
@@ -39,6 +39,40 @@ pub enum DepositToken {
     /// `dao_macros::token_query`. Failing to implement that
     /// and using this option will cause instantiation to fail.
     VotingModuleToken {},
+    /// Lock the proposer's stake in a staking contract rather than
+    /// transferring tokens. The deposit is denominated in the token
+    /// staked in the contract. NOTE: the staking contract must
+    /// implement the messages in `StakedDepositExecuteMsg` and
+    /// `StakedDepositQueryMsg`, and should only accept them from
+    /// the pre-propose modules it trusts.
+    Staked { staking_contract: String },
+}
+
+/// Messages a staking contract must accept to hold staked proposal
+/// deposits. Locked stake may not be unstaked until it is unlocked
+/// or forfeited.
+#[cw_serde]
+pub enum StakedDepositExecuteMsg {
+    /// Locks AMOUNT of OWNER's stake.
+    LockStake { owner: String, amount: Uint128 },
+    /// Unlocks AMOUNT of OWNER's previously locked stake.
+    UnlockStake { owner: String, amount: Uint128 },
+    /// Unstakes AMOUNT of OWNER's previously locked stake and sends
+    /// the tokens to RECIPIENT.
+    ForfeitStake {
+        owner: String,
+        amount: Uint128,
+        recipient: String,
+    },
+}
+
+/// Queries a staking contract must answer to hold staked proposal
+/// deposits.
+#[cw_serde]
+pub enum StakedDepositQueryMsg {
+    /// Returns the address of the cw20 token being staked as an
+    /// `Addr`.
+    StakedToken {},
 }
 
 /// Information about the deposit required to create a proposal.
@@ -79,6 +113,9 @@ pub struct CheckedDepositInfo {
     pub amount: Uint128,
     /// The policy used for refunding proposal deposits.
     pub refund_policy: DepositRefundPolicy,
+    /// The staking contract the proposer's stake is locked in. If
+    /// set, deposits are locked there rather than transferred.
+    pub staking_contract: Option<Addr>,
 }
 
 impl UncheckedDepositInfo {
@@ -96,6 +133,7 @@ impl UncheckedDepositInfo {
             return Err(DepositError::ZeroDeposit);
         }
 
+        let mut staking_contract = None;
         let denom = match denom {
             DepositToken::Token { denom } => denom.into_checked(deps),
             DepositToken::VotingModuleToken {} => {
@@ -114,12 +152,23 @@ impl UncheckedDepositInfo {
                 // query.
                 UncheckedDenom::Cw20(token_addr.into_string()).into_checked(deps)
             }
+            DepositToken::Staked {
+                staking_contract: contract,
+            } => {
+                let contract = deps.api.addr_validate(&contract)?;
+                let token_addr: Addr = deps
+                    .querier
+                    .query_wasm_smart(&contract, &StakedDepositQueryMsg::StakedToken {})?;
+                staking_contract = Some(contract);
+                UncheckedDenom::Cw20(token_addr.into_string()).into_checked(deps)
+            }
         }?;
 
         Ok(CheckedDepositInfo {
             denom,
             amount,
             refund_policy,
+            staking_contract,
         })
     }
 }
@@ -151,11 +200,27 @@ impl CheckedDepositInfo {
         }
     }
 
+    /// Gets the messages to collect DEPOSITOR's deposit into
+    /// CONTRACT. cw20 deposits are pulled with a `TransferFrom`, so
+    /// the depositor must have approved an allowance for CONTRACT of
+    /// at least the deposit amount beforehand; if they have not, the
+    /// transfer fails and with it the proposal's creation. Refunds
+    /// are sent back with a `Transfer` by
+    /// `get_return_deposit_message`.
     pub fn get_take_deposit_messages(
         &self,
         depositor: &Addr,
         contract: &Addr,
     ) -> StdResult<Vec<CosmosMsg>> {
+        if let Some(staking_contract) = &self.staking_contract {
+            return self.get_staking_messages(
+                staking_contract,
+                StakedDepositExecuteMsg::LockStake {
+                    owner: depositor.to_string(),
+                    amount: self.amount,
+                },
+            );
+        }
         let take_deposit_msg: Vec<CosmosMsg> = if let Self {
             amount,
             denom: CheckedDenom::Cw20(address),
@@ -187,6 +252,15 @@ impl CheckedDepositInfo {
     }
 
     pub fn get_return_deposit_message(&self, depositor: &Addr) -> StdResult<Vec<CosmosMsg>> {
+        if let Some(staking_contract) = &self.staking_contract {
+            return self.get_staking_messages(
+                staking_contract,
+                StakedDepositExecuteMsg::UnlockStake {
+                    owner: depositor.to_string(),
+                    amount: self.amount,
+                },
+            );
+        }
         // Should get caught in `into_checked()`, but to be pedantic.
         if self.amount.is_zero() {
             return Ok(vec![]);
@@ -194,6 +268,42 @@ impl CheckedDepositInfo {
         let message = self.denom.get_transfer_to_message(depositor, self.amount)?;
         Ok(vec![message])
     }
+
+    /// Gets the messages to send DEPOSITOR's deposit to RECIPIENT
+    /// instead of refunding it.
+    pub fn get_forfeit_deposit_messages(
+        &self,
+        depositor: &Addr,
+        recipient: &Addr,
+    ) -> StdResult<Vec<CosmosMsg>> {
+        match &self.staking_contract {
+            Some(staking_contract) => self.get_staking_messages(
+                staking_contract,
+                StakedDepositExecuteMsg::ForfeitStake {
+                    owner: depositor.to_string(),
+                    amount: self.amount,
+                    recipient: recipient.to_string(),
+                },
+            ),
+            None => self.get_return_deposit_message(recipient),
+        }
+    }
+
+    fn get_staking_messages(
+        &self,
+        staking_contract: &Addr,
+        msg: StakedDepositExecuteMsg,
+    ) -> StdResult<Vec<CosmosMsg>> {
+        if self.amount.is_zero() {
+            return Ok(vec![]);
+        }
+        Ok(vec![WasmMsg::Execute {
+            contract_addr: staking_contract.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        }
+        .into()])
+    }
 }
 
 #[cfg(test)]
@@ -212,6 +322,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
         deposit_info.check_native_deposit_paid(&info).unwrap();
 
@@ -234,6 +345,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -252,6 +364,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -272,6 +385,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
 
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
@@ -285,6 +399,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(err, DepositError::Payment(PaymentError::NoFunds {}));
@@ -297,6 +412,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
         let messages = deposit_info
             .get_take_deposit_messages(&Addr::unchecked("ekez"), &Addr::unchecked(CW20))
@@ -337,6 +453,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -363,6 +480,7 @@ pub mod tests {
             denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            staking_contract: None,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -387,4 +505,55 @@ pub mod tests {
             .unwrap();
         assert_eq!(messages, vec![]);
     }
+
+    #[test]
+    fn test_staked_deposit_messages() {
+        let deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            staking_contract: Some(Addr::unchecked("staking")),
+        };
+        let staking_message = |msg: StakedDepositExecuteMsg| -> Vec<CosmosMsg> {
+            vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "staking".to_string(),
+                msg: to_binary(&msg).unwrap(),
+                funds: vec![],
+            })]
+        };
+
+        let messages = deposit_info
+            .get_take_deposit_messages(&Addr::unchecked("ekez"), &Addr::unchecked("contract"))
+            .unwrap();
+        assert_eq!(
+            messages,
+            staking_message(StakedDepositExecuteMsg::LockStake {
+                owner: "ekez".to_string(),
+                amount: Uint128::new(10),
+            })
+        );
+
+        let messages = deposit_info
+            .get_return_deposit_message(&Addr::unchecked("ekez"))
+            .unwrap();
+        assert_eq!(
+            messages,
+            staking_message(StakedDepositExecuteMsg::UnlockStake {
+                owner: "ekez".to_string(),
+                amount: Uint128::new(10),
+            })
+        );
+
+        let messages = deposit_info
+            .get_forfeit_deposit_messages(&Addr::unchecked("ekez"), &Addr::unchecked("dao"))
+            .unwrap();
+        assert_eq!(
+            messages,
+            staking_message(StakedDepositExecuteMsg::ForfeitStake {
+                owner: "ekez".to_string(),
+                amount: Uint128::new(10),
+                recipient: "dao".to_string(),
+            })
+        );
+    }
 }
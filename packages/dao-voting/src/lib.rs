@@ -4,8 +4,10 @@ pub mod deposit;
 pub mod error;
 pub mod multiple_choice;
 pub mod pre_propose;
+pub mod precondition;
 pub mod proposal;
 pub mod reply;
 pub mod status;
+pub mod text_limits;
 pub mod threshold;
 pub mod voting;
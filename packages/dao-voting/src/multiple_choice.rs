@@ -1,12 +1,15 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{CosmosMsg, Empty, StdError, StdResult, Uint128};
 
-use crate::threshold::{validate_quorum, PercentageThreshold, ThresholdError};
+use crate::threshold::{validate_quorum, NormalizedThreshold, PercentageThreshold, ThresholdError};
 
 /// Maximum number of choices for multiple choice votes. Chosen
 /// in order to impose a bound on state / queries.
 pub const MAX_NUM_CHOICES: u32 = 20;
 const NONE_OPTION_DESCRIPTION: &str = "None of the above";
+/// The option ID with which a voter abstains. Abstentions count
+/// toward quorum but are not cast for any option.
+pub const ABSTAIN_OPTION_ID: u32 = u32::MAX;
 
 /// Determines how many choices may be selected.
 #[cw_serde]
@@ -26,6 +29,41 @@ impl VotingStrategy {
             VotingStrategy::SingleChoice { quorum } => *quorum,
         }
     }
+
+    /// Converts the voting strategy into its normalized form. Under
+    /// `SingleChoice` the option with the most votes wins once quorum
+    /// is met, so only the quorum is set.
+    pub fn normalize(&self) -> NormalizedThreshold {
+        match self {
+            VotingStrategy::SingleChoice { quorum } => NormalizedThreshold {
+                quorum: Some(*quorum),
+                threshold: None,
+                absolute: None,
+                veto: None,
+            },
+        }
+    }
+}
+
+/// How a tie between the options of a multiple choice proposal with
+/// the most votes is resolved.
+#[cw_serde]
+#[derive(Copy)]
+pub enum TieBreak {
+    /// Ties are not resolved and tied proposals can not pass.
+    None,
+    /// The tied option with the lowest index wins.
+    FirstIndex,
+    /// The tied option the proposer voted for wins. If the proposer
+    /// did not vote for one of the tied options the tie is not
+    /// resolved.
+    Proposer,
+}
+
+impl Default for TieBreak {
+    fn default() -> Self {
+        Self::None
+    }
 }
 
 /// A multiple choice vote, picking the desired option
@@ -36,9 +74,21 @@ pub struct MultipleChoiceVote {
     pub option_id: u32,
 }
 
+impl MultipleChoiceVote {
+    /// Returns true if this vote abstains rather than selecting an
+    /// option.
+    pub fn is_abstain(&self) -> bool {
+        self.option_id == ABSTAIN_OPTION_ID
+    }
+}
+
 impl std::fmt::Display for MultipleChoiceVote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.option_id)
+        if self.is_abstain() {
+            write!(f, "abstain")
+        } else {
+            write!(f, "{}", self.option_id)
+        }
     }
 }
 
@@ -48,27 +98,37 @@ pub struct MultipleChoiceVotes {
     // Vote counts is a vector of integers indicating the vote weight for each option
     // (the index corresponds to the option).
     pub vote_weights: Vec<Uint128>,
+    /// The vote weight of voters who abstained. Counts toward quorum
+    /// but not toward any option.
+    #[serde(default)]
+    pub abstain: Uint128,
 }
 
 impl MultipleChoiceVotes {
-    /// Sum of all vote weights
+    /// Sum of all vote weights, including abstentions
     pub fn total(&self) -> Uint128 {
-        self.vote_weights.iter().sum()
+        self.vote_weights.iter().sum::<Uint128>() + self.abstain
+    }
+
+    fn tally_mut(&mut self, vote: MultipleChoiceVote) -> &mut Uint128 {
+        if vote.is_abstain() {
+            &mut self.abstain
+        } else {
+            &mut self.vote_weights[vote.option_id as usize]
+        }
     }
 
     // Add a vote to the tally
     pub fn add_vote(&mut self, vote: MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
-        self.vote_weights[vote.option_id as usize] = self.vote_weights[vote.option_id as usize]
-            .checked_add(weight)
-            .map_err(StdError::overflow)?;
+        let tally = self.tally_mut(vote);
+        *tally = tally.checked_add(weight).map_err(StdError::overflow)?;
         Ok(())
     }
 
     // Remove a vote from the tally
     pub fn remove_vote(&mut self, vote: MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
-        self.vote_weights[vote.option_id as usize] = self.vote_weights[vote.option_id as usize]
-            .checked_sub(weight)
-            .map_err(StdError::overflow)?;
+        let tally = self.tally_mut(vote);
+        *tally = tally.checked_sub(weight).map_err(StdError::overflow)?;
         Ok(())
     }
 
@@ -76,6 +136,7 @@ impl MultipleChoiceVotes {
     pub fn zero(num_choices: usize) -> Self {
         Self {
             vote_weights: vec![Uint128::zero(); num_choices],
+            abstain: Uint128::zero(),
         }
     }
 }
@@ -153,7 +214,7 @@ impl MultipleChoiceOptions {
 
         // Add a "None of the above" option, required for every multiple choice proposal.
         let none_option = CheckedMultipleChoiceOption {
-            index: (checked_options.capacity() - 1) as u32,
+            index: checked_options.len() as u32,
             option_type: MultipleChoiceOptionType::None,
             description: NONE_OPTION_DESCRIPTION.to_string(),
             msgs: vec![],
@@ -179,13 +240,19 @@ mod test {
     #[test]
     fn test_display_multiple_choice_vote() {
         let vote = MultipleChoiceVote { option_id: 0 };
-        assert_eq!("0", vote.to_string())
+        assert_eq!("0", vote.to_string());
+
+        let vote = MultipleChoiceVote {
+            option_id: ABSTAIN_OPTION_ID,
+        };
+        assert_eq!("abstain", vote.to_string())
     }
 
     #[test]
     fn test_multiple_choice_votes() {
         let mut votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(10), Uint128::new(100)],
+            abstain: Uint128::zero(),
         };
         let total = votes.total();
         assert_eq!(total, Uint128::new(110));
@@ -203,6 +270,18 @@ mod test {
             .remove_vote(MultipleChoiceVote { option_id: 1 }, Uint128::new(100))
             .unwrap();
 
+        assert_eq!(votes, MultipleChoiceVotes::zero(2));
+
+        // Abstentions count toward the total but not toward any
+        // option.
+        let abstain = MultipleChoiceVote {
+            option_id: ABSTAIN_OPTION_ID,
+        };
+        votes.add_vote(abstain, Uint128::new(5)).unwrap();
+        assert_eq!(votes.total(), Uint128::new(5));
+        assert_eq!(votes.vote_weights, vec![Uint128::zero(); 2]);
+
+        votes.remove_vote(abstain, Uint128::new(5)).unwrap();
         assert_eq!(votes, MultipleChoiceVotes::zero(2))
     }
 
@@ -251,6 +330,37 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_into_checked_indices() {
+        for count in 2..=MAX_NUM_CHOICES {
+            let options = (0..count)
+                .map(|i| super::MultipleChoiceOption {
+                    description: format!("multiple choice option {i}"),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                })
+                .collect();
+            let checked = super::MultipleChoiceOptions { options }
+                .into_checked()
+                .unwrap()
+                .options;
+
+            // Options are indexed in order, start with no votes, and
+            // end with a single "None of the above" option.
+            assert_eq!(checked.len(), count as usize + 1);
+            for (i, option) in checked.iter().enumerate() {
+                assert_eq!(option.index, i as u32);
+                assert_eq!(option.vote_count, Uint128::zero());
+            }
+            let none_options: Vec<u32> = checked
+                .iter()
+                .filter(|option| option.option_type == MultipleChoiceOptionType::None)
+                .map(|option| option.index)
+                .collect();
+            assert_eq!(none_options, vec![count]);
+        }
+    }
+
     #[should_panic(expected = "Wrong number of choices")]
     #[test]
     fn test_into_checked_wrong_num_choices() {
@@ -0,0 +1,124 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, StdError, StdResult, Uint128};
+use cw_denom::{CheckedDenom, DenomError, UncheckedDenom};
+use thiserror::Error;
+
+/// Error type for precondition methods.
+#[derive(Error, Debug, PartialEq)]
+pub enum PreconditionError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+}
+
+/// A condition on chain state that must hold for a passed proposal
+/// to be executed. Preconditions are evaluated when execution of the
+/// proposal begins, not when the proposal is created or voted on.
+///
+/// New kinds of preconditions may be added by adding a variant here
+/// and to `CheckedPrecondition`, validating it in `into_checked`, and
+/// evaluating it in `is_met`. Evaluation should only query chain
+/// state so that a precondition may never cause side effects.
+#[cw_serde]
+pub enum Precondition {
+    /// Met if ADDRESS holds at least AMOUNT of DENOM.
+    MinBalance {
+        address: String,
+        denom: UncheckedDenom,
+        amount: Uint128,
+    },
+}
+
+/// Counterpart to `Precondition` which has been validated. This type
+/// should never be constructed literally and should always be built
+/// by calling `into_checked` on a `Precondition` instance.
+#[cw_serde]
+pub enum CheckedPrecondition {
+    /// Met if ADDRESS holds at least AMOUNT of DENOM.
+    MinBalance {
+        address: Addr,
+        denom: CheckedDenom,
+        amount: Uint128,
+    },
+}
+
+impl Precondition {
+    /// Validates the addresses and denominations referenced by the
+    /// precondition.
+    pub fn into_checked(self, deps: Deps) -> Result<CheckedPrecondition, PreconditionError> {
+        match self {
+            Self::MinBalance {
+                address,
+                denom,
+                amount,
+            } => Ok(CheckedPrecondition::MinBalance {
+                address: deps.api.addr_validate(&address)?,
+                denom: denom.into_checked(deps)?,
+                amount,
+            }),
+        }
+    }
+}
+
+impl CheckedPrecondition {
+    /// Evaluates the precondition against the current chain state.
+    pub fn is_met(&self, deps: Deps) -> StdResult<bool> {
+        match self {
+            Self::MinBalance {
+                address,
+                denom,
+                amount,
+            } => Ok(denom.query_balance(&deps.querier, address)? >= *amount),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        coins,
+        testing::{mock_dependencies_with_balances, mock_env},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_min_balance() {
+        let env = mock_env();
+        let deps = mock_dependencies_with_balances(&[(
+            env.contract.address.as_str(),
+            &coins(100, "ujuno"),
+        )]);
+        let precondition = |amount: u128| {
+            Precondition::MinBalance {
+                address: env.contract.address.to_string(),
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+                amount: Uint128::new(amount),
+            }
+            .into_checked(deps.as_ref())
+            .unwrap()
+        };
+
+        assert!(precondition(99).is_met(deps.as_ref()).unwrap());
+        assert!(precondition(100).is_met(deps.as_ref()).unwrap());
+        assert!(!precondition(101).is_met(deps.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_denom() {
+        let deps = mock_dependencies_with_balances(&[]);
+        let err = Precondition::MinBalance {
+            address: "treasury".to_string(),
+            denom: UncheckedDenom::Native("u".to_string()),
+            amount: Uint128::new(1),
+        }
+        .into_checked(deps.as_ref())
+        .unwrap_err();
+        assert_eq!(
+            err,
+            PreconditionError::Denom(DenomError::NativeDenomLength { len: 1 })
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty};
+use cosmwasm_std::{attr, Addr, Attribute, CosmosMsg, Empty, Uint128};
+use cw_utils::Duration;
+
+use crate::{precondition::Precondition, status::Status};
 
 /// Default limit for proposal pagination.
 pub const DEFAULT_LIMIT: u64 = 30;
@@ -31,4 +34,60 @@ pub struct SingleChoiceProposeMsg {
     /// pre-propose module is attached, this must be Some and will
     /// set the proposer of the proposal it creates.
     pub proposer: Option<String>,
+    /// A condition that must hold for the proposal to be executed
+    /// once passed. If it does not hold execution fails and the
+    /// proposal may be closed.
+    pub precondition: Option<Precondition>,
+    /// An optional voting period for this proposal that is shorter
+    /// than the module's max voting period. Must use the same units
+    /// as the max voting period. If None, the max voting period is
+    /// used.
+    pub proposal_duration: Option<Duration>,
+    /// A reference to the thread where this proposal was discussed,
+    /// for example a forum post URL. Required if the module is
+    /// configured with `require_discussion_url`.
+    pub discussion_url: Option<String>,
+}
+
+/// Voting participation across the open proposals of a proposal
+/// module. Returned by the `Participation` query of the single and
+/// multiple choice proposal modules.
+///
+/// Participation is reported as a sum over proposals so that the
+/// responses of several modules may be added together to compute a
+/// combined participation rate of `votes_cast / total_power`.
+#[cw_serde]
+#[derive(Default)]
+pub struct ParticipationResponse {
+    /// The number of proposals that are currently open for voting.
+    pub open_proposals: u64,
+    /// The sum of the voting power that has been cast on open
+    /// proposals.
+    pub votes_cast: Uint128,
+    /// The sum of the total voting power of open proposals.
+    pub total_power: Uint128,
+}
+
+impl ParticipationResponse {
+    /// Adds the participation reported by OTHER to this one.
+    pub fn checked_add(self, other: Self) -> cosmwasm_std::StdResult<Self> {
+        Ok(Self {
+            open_proposals: self.open_proposals + other.open_proposals,
+            votes_cast: self.votes_cast.checked_add(other.votes_cast)?,
+            total_power: self.total_power.checked_add(other.total_power)?,
+        })
+    }
+}
+
+/// Gets the attributes identifying a proposal and the STATUS it was
+/// left in after SENDER acted on it. Proposal modules add these to
+/// the responses of every message that creates a proposal or changes
+/// its status so that indexers may reconstruct a proposal's lifecycle
+/// from events alone.
+pub fn proposal_attributes(proposal_id: u64, status: Status, sender: &Addr) -> Vec<Attribute> {
+    vec![
+        attr("proposal_id", proposal_id.to_string()),
+        attr("status", status.to_string()),
+        attr("sender", sender.as_str()),
+    ]
 }
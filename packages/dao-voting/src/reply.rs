@@ -10,6 +10,10 @@ const FAILED_VOTE_HOOK_MASK: u64 = 0b010;
 const PRE_PROPOSE_MODULE_INSTANTIATION_ID: u64 = 0b011;
 const FAILED_PRE_PROPOSE_MODULE_HOOK_ID: u64 = 0b100;
 
+const PROPOSAL_EXECUTION_STATE_HASH_MASK: u64 = 0b101;
+const PROPOSAL_MESSAGE_EXECUTION_MASK: u64 = 0b110;
+const FAILED_OUTCOME_PACKET_MASK: u64 = 0b111;
+
 const BITS_RESERVED_FOR_REPLY_TYPE: u8 = 3;
 const REPLY_TYPE_MASK: u64 = (1 << BITS_RESERVED_FOR_REPLY_TYPE) - 1;
 
@@ -32,6 +36,15 @@ pub enum TaggedReplyId {
     FailedPreProposeModuleHook,
     /// Fired when a pre-propose module is successfully instantiated.
     PreProposeModuleInstantiation,
+    /// Fired after a proposal that changes the config of a known
+    /// contract is executed, so that the resulting config may be
+    /// hashed.
+    ProposalExecutionStateHash(u64),
+    /// Fired after each message of a proposal executed in best-effort
+    /// mode, whether or not it succeeded.
+    ProposalMessageExecution(u64),
+    /// Fired when posting the outcome of a proposal over IBC fails.
+    FailedOutcomePacket(u64),
 }
 
 impl TaggedReplyId {
@@ -50,6 +63,13 @@ impl TaggedReplyId {
             FAILED_VOTE_HOOK_MASK => Ok(TaggedReplyId::FailedVoteHook(id_after_shift)),
             PRE_PROPOSE_MODULE_INSTANTIATION_ID => Ok(TaggedReplyId::PreProposeModuleInstantiation),
             FAILED_PRE_PROPOSE_MODULE_HOOK_ID => Ok(TaggedReplyId::FailedPreProposeModuleHook),
+            PROPOSAL_EXECUTION_STATE_HASH_MASK => {
+                Ok(TaggedReplyId::ProposalExecutionStateHash(id_after_shift))
+            }
+            PROPOSAL_MESSAGE_EXECUTION_MASK => {
+                Ok(TaggedReplyId::ProposalMessageExecution(id_after_shift))
+            }
+            FAILED_OUTCOME_PACKET_MASK => Ok(TaggedReplyId::FailedOutcomePacket(id_after_shift)),
             _ => Err(error::TagError::UnknownReplyId { id }),
         }
     }
@@ -68,6 +88,18 @@ pub const fn mask_vote_hook_index(index: u64) -> u64 {
     FAILED_VOTE_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
 }
 
+pub const fn mask_proposal_execution_state_hash_id(proposal_id: u64) -> u64 {
+    PROPOSAL_EXECUTION_STATE_HASH_MASK | (proposal_id << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
+pub const fn mask_proposal_message_execution_id(proposal_id: u64) -> u64 {
+    PROPOSAL_MESSAGE_EXECUTION_MASK | (proposal_id << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
+pub const fn mask_outcome_packet_proposal_id(proposal_id: u64) -> u64 {
+    FAILED_OUTCOME_PACKET_MASK | (proposal_id << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
 pub const fn pre_propose_module_instantiation_id() -> u64 {
     PRE_PROPOSE_MODULE_INSTANTIATION_ID
 }
@@ -100,6 +132,9 @@ mod test {
         let m_proposal_id = mask_proposal_execution_proposal_id(proposal_id_max);
         let m_proposal_hook_idx = mask_proposal_hook_index(proposal_hook_idx);
         let m_vote_hook_idx = mask_vote_hook_index(vote_hook_idx);
+        let m_state_hash_id = mask_proposal_execution_state_hash_id(proposal_id_max);
+        let m_message_execution_id = mask_proposal_message_execution_id(proposal_id_max);
+        let m_outcome_packet_id = mask_outcome_packet_proposal_id(proposal_id_max);
 
         assert_eq!(
             TaggedReplyId::new(m_proposal_id).unwrap(),
@@ -114,8 +149,16 @@ mod test {
             TaggedReplyId::FailedVoteHook(vote_hook_idx)
         );
         assert_eq!(
-            TaggedReplyId::new(0b110).unwrap_err(),
-            error::TagError::UnknownReplyId { id: 0b110 }
+            TaggedReplyId::new(m_state_hash_id).unwrap(),
+            TaggedReplyId::ProposalExecutionStateHash(proposal_id_max)
+        );
+        assert_eq!(
+            TaggedReplyId::new(m_message_execution_id).unwrap(),
+            TaggedReplyId::ProposalMessageExecution(proposal_id_max)
+        );
+        assert_eq!(
+            TaggedReplyId::new(m_outcome_packet_id).unwrap(),
+            TaggedReplyId::FailedOutcomePacket(proposal_id_max)
         );
     }
 }
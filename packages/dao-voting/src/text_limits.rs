@@ -0,0 +1,102 @@
+use cosmwasm_schema::cw_serde;
+use thiserror::Error;
+
+/// Error type for proposal text limit methods.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum TextLimitError {
+    #[error("proposal text limits must be greater than zero")]
+    ZeroLimit {},
+
+    #[error("proposal title is ({length}) bytes, must be <= ({max}) bytes")]
+    TitleTooLong { length: u64, max: u64 },
+
+    #[error("proposal description is ({length}) bytes, must be <= ({max}) bytes")]
+    DescriptionTooLong { length: u64, max: u64 },
+}
+
+/// Limits on the length of proposal titles and descriptions. Each
+/// proposal module configures its own limits so that modules using
+/// different voting strategies may allow different amounts of text,
+/// for example a longer description for a signaling poll than for a
+/// parameter change.
+#[cw_serde]
+#[derive(Default)]
+pub struct ProposalTextLimits {
+    /// The maximum length of a proposal's title in bytes. If None,
+    /// titles are only limited by the maximum proposal size.
+    pub max_title_length: Option<u64>,
+    /// The maximum length of a proposal's description in bytes. If
+    /// None, descriptions are only limited by the maximum proposal
+    /// size.
+    pub max_description_length: Option<u64>,
+}
+
+impl ProposalTextLimits {
+    pub fn validate(&self) -> Result<(), TextLimitError> {
+        if self.max_title_length == Some(0) || self.max_description_length == Some(0) {
+            return Err(TextLimitError::ZeroLimit {});
+        }
+        Ok(())
+    }
+
+    /// Errors if TITLE or DESCRIPTION exceed these limits.
+    pub fn check(&self, title: &str, description: &str) -> Result<(), TextLimitError> {
+        if let Some(max) = self.max_title_length {
+            let length = title.len() as u64;
+            if length > max {
+                return Err(TextLimitError::TitleTooLong { length, max });
+            }
+        }
+        if let Some(max) = self.max_description_length {
+            let length = description.len() as u64;
+            if length > max {
+                return Err(TextLimitError::DescriptionTooLong { length, max });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let limits = ProposalTextLimits {
+            max_title_length: Some(5),
+            max_description_length: Some(10),
+        };
+        limits.check("title", "0123456789").unwrap();
+        assert_eq!(
+            limits.check("titles", "").unwrap_err(),
+            TextLimitError::TitleTooLong { length: 6, max: 5 }
+        );
+        assert_eq!(
+            limits.check("", "0123456789a").unwrap_err(),
+            TextLimitError::DescriptionTooLong {
+                length: 11,
+                max: 10
+            }
+        );
+
+        // No limits allows any length.
+        ProposalTextLimits::default()
+            .check(&"a".repeat(1000), &"b".repeat(1000))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_validate() {
+        ProposalTextLimits::default().validate().unwrap();
+        assert_eq!(
+            ProposalTextLimits {
+                max_title_length: Some(0),
+                max_description_length: None,
+            }
+            .validate()
+            .unwrap_err(),
+            TextLimitError::ZeroLimit {}
+        );
+    }
+}
@@ -66,6 +66,54 @@ pub enum Threshold {
     /// An absolute number of votes needed for something to cross the
     /// threshold. Useful for multisig style voting.
     AbsoluteCount { threshold: Uint128 },
+
+    /// Requires both that `percentage` of the total weight, as in
+    /// `AbsolutePercentage`, and at least `absolute` weight cast Yes
+    /// votes in order for a proposal to pass.
+    ThresholdAndAbsolute {
+        percentage: PercentageThreshold,
+        absolute: Uint128,
+    },
+
+    /// Declares a percentage of the non-abstaining votes that must be
+    /// Yes votes in order for a proposal to pass, computed as `yes /
+    /// (yes + no)`. Unlike `AbsolutePercentage`, abstentions do not
+    /// count against the proposal once voting has closed. Proposals
+    /// where no one votes yes or no are rejected.
+    NonAbstainPercentage { percentage: PercentageThreshold },
+
+    /// As `ThresholdQuorum`, but a proposal is rejected, even if it
+    /// has met its threshold, once `veto` of the total weight has
+    /// voted No. Proposals may pass before voting closes only once
+    /// the outstanding weight can no longer reach the veto.
+    ThresholdQuorumVeto {
+        threshold: PercentageThreshold,
+        quorum: PercentageThreshold,
+        veto: PercentageThreshold,
+    },
+}
+
+/// A proposal module's passing requirements in a form that is
+/// comparable across proposal modules with different threshold
+/// types. See `Threshold::normalize` and `VotingStrategy::normalize`
+/// for how each concrete type maps onto it.
+#[cw_serde]
+pub struct NormalizedThreshold {
+    /// The percentage of voting power that must vote on a proposal
+    /// for the vote to be considered at all. None if the module has
+    /// no separate quorum requirement.
+    pub quorum: Option<PercentageThreshold>,
+    /// The percentage of votes that must be cast for the winning
+    /// option for a proposal to pass. None if passing is not
+    /// determined by a percentage, for example under an absolute
+    /// count or when the option with the most votes wins.
+    pub threshold: Option<PercentageThreshold>,
+    /// An absolute amount of voting power that must vote for the
+    /// winning option for a proposal to pass, if any.
+    pub absolute: Option<Uint128>,
+    /// The percentage of voting power that, by voting against a
+    /// proposal, rejects it regardless of its other votes, if any.
+    pub veto: Option<PercentageThreshold>,
 }
 
 /// Asserts that the 0.0 < percent <= 1.0
@@ -103,6 +151,11 @@ impl Threshold {
     /// - Quorums must never be over 100%.
     /// - Passing thresholds must never be over 100%, nor be 0%.
     /// - Absolute count thresholds must be non-zero.
+    /// - Both parts of a percentage and absolute threshold must be
+    ///   valid.
+    /// - Non-abstain percentage thresholds follow the same rules as
+    ///   other passing thresholds.
+    /// - Vetoes follow the same rules as passing thresholds.
     pub fn validate(&self) -> Result<(), ThresholdError> {
         match self {
             Threshold::AbsolutePercentage {
@@ -119,6 +172,82 @@ impl Threshold {
                     Ok(())
                 }
             }
+            Threshold::ThresholdAndAbsolute {
+                percentage,
+                absolute,
+            } => {
+                validate_percentage(percentage)?;
+                if absolute.is_zero() {
+                    Err(ThresholdError::ZeroThreshold {})
+                } else {
+                    Ok(())
+                }
+            }
+            Threshold::NonAbstainPercentage { percentage } => validate_percentage(percentage),
+            Threshold::ThresholdQuorumVeto {
+                threshold,
+                quorum,
+                veto,
+            } => {
+                validate_percentage(threshold)?;
+                validate_quorum(quorum)?;
+                validate_percentage(veto)
+            }
+        }
+    }
+
+    /// Converts the threshold into its normalized form.
+    ///
+    /// - `AbsolutePercentage` has no quorum and a threshold measured
+    ///   against the total voting power.
+    /// - `ThresholdQuorum` maps directly onto a quorum and threshold.
+    /// - `AbsoluteCount` has only an absolute requirement.
+    /// - `ThresholdAndAbsolute` has both a threshold measured against
+    ///   the total voting power and an absolute requirement.
+    /// - `NonAbstainPercentage` has no quorum and a threshold
+    ///   measured against the non-abstaining votes.
+    /// - `ThresholdQuorumVeto` maps onto a quorum, threshold, and
+    ///   veto.
+    pub fn normalize(&self) -> NormalizedThreshold {
+        match self {
+            Threshold::AbsolutePercentage { percentage }
+            | Threshold::NonAbstainPercentage { percentage } => NormalizedThreshold {
+                quorum: None,
+                threshold: Some(*percentage),
+                absolute: None,
+                veto: None,
+            },
+            Threshold::ThresholdQuorum { threshold, quorum } => NormalizedThreshold {
+                quorum: Some(*quorum),
+                threshold: Some(*threshold),
+                absolute: None,
+                veto: None,
+            },
+            Threshold::AbsoluteCount { threshold } => NormalizedThreshold {
+                quorum: None,
+                threshold: None,
+                absolute: Some(*threshold),
+                veto: None,
+            },
+            Threshold::ThresholdAndAbsolute {
+                percentage,
+                absolute,
+            } => NormalizedThreshold {
+                quorum: None,
+                threshold: Some(*percentage),
+                absolute: Some(*absolute),
+                veto: None,
+            },
+            Threshold::ThresholdQuorumVeto {
+                threshold,
+                quorum,
+                veto,
+            } => NormalizedThreshold {
+                quorum: Some(*quorum),
+                threshold: Some(*threshold),
+                absolute: None,
+                veto: Some(*veto),
+            },
         }
     }
 }
@@ -179,5 +308,123 @@ mod tests {
             t.validate().unwrap_err(),
             ThresholdError::UnreachableThreshold {}
         );
+
+        let t = Threshold::ThresholdAndAbsolute {
+            percentage: p!(101),
+            absolute: Uint128::new(1),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::UnreachableThreshold {}
+        );
+
+        let t = Threshold::ThresholdAndAbsolute {
+            percentage: p!(60),
+            absolute: Uint128::zero(),
+        };
+        assert_eq!(t.validate().unwrap_err(), ThresholdError::ZeroThreshold {});
+
+        let t = Threshold::ThresholdAndAbsolute {
+            percentage: p!(60),
+            absolute: Uint128::new(1),
+        };
+        t.validate().unwrap();
+
+        let t = Threshold::NonAbstainPercentage { percentage: p!(0) };
+        assert_eq!(t.validate().unwrap_err(), ThresholdError::ZeroThreshold {});
+
+        let t = Threshold::NonAbstainPercentage { percentage: p!(67) };
+        t.validate().unwrap();
+    }
+
+    #[test]
+    fn test_threshold_normalize() {
+        let t = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: p!(20),
+        };
+        assert_eq!(
+            t.normalize(),
+            NormalizedThreshold {
+                quorum: Some(p!(20)),
+                threshold: Some(PercentageThreshold::Majority {}),
+                absolute: None,
+                veto: None,
+            }
+        );
+
+        let t = Threshold::AbsolutePercentage { percentage: p!(60) };
+        assert_eq!(
+            t.normalize(),
+            NormalizedThreshold {
+                quorum: None,
+                threshold: Some(p!(60)),
+                absolute: None,
+                veto: None,
+            }
+        );
+
+        let t = Threshold::AbsoluteCount {
+            threshold: Uint128::new(3),
+        };
+        assert_eq!(
+            t.normalize(),
+            NormalizedThreshold {
+                quorum: None,
+                threshold: None,
+                absolute: Some(Uint128::new(3)),
+                veto: None,
+            }
+        );
+
+        let t = Threshold::ThresholdAndAbsolute {
+            percentage: p!(50),
+            absolute: Uint128::new(10),
+        };
+        assert_eq!(
+            t.normalize(),
+            NormalizedThreshold {
+                quorum: None,
+                threshold: Some(p!(50)),
+                absolute: Some(Uint128::new(10)),
+                veto: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_veto_threshold() {
+        let t = Threshold::ThresholdQuorumVeto {
+            threshold: PercentageThreshold::Majority {},
+            quorum: p!(20),
+            veto: p!(101),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::UnreachableThreshold {}
+        );
+
+        let t = Threshold::ThresholdQuorumVeto {
+            threshold: PercentageThreshold::Majority {},
+            quorum: p!(20),
+            veto: p!(0),
+        };
+        assert_eq!(t.validate().unwrap_err(), ThresholdError::ZeroThreshold {});
+
+        let t = Threshold::ThresholdQuorumVeto {
+            threshold: PercentageThreshold::Majority {},
+            quorum: p!(20),
+            veto: p!(33),
+        };
+        t.validate().unwrap();
+        assert_eq!(
+            t.normalize(),
+            NormalizedThreshold {
+                quorum: Some(p!(20)),
+                threshold: Some(PercentageThreshold::Majority {}),
+                absolute: None,
+                veto: Some(p!(33)),
+            }
+        );
     }
 }
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128, Uint256};
+use cosmwasm_std::{Addr, Decimal, Deps, QuerierWrapper, StdResult, Uint128, Uint256};
 use cw_utils::Duration;
 use dao_interface::voting;
 
@@ -196,21 +196,44 @@ pub fn get_voting_power(
     dao: &Addr,
     height: Option<u64>,
 ) -> StdResult<Uint128> {
-    let response: voting::VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
-        dao,
+    voting_power_at_height(&deps.querier, dao, address, height)
+}
+
+/// A height of None will query for the current block height.
+pub fn get_total_power(deps: Deps, dao: &Addr, height: Option<u64>) -> StdResult<Uint128> {
+    total_power_at_height(&deps.querier, dao, height)
+}
+
+/// Queries MODULE for the voting power of ADDRESS at HEIGHT. MODULE
+/// may be any contract implementing the voting module interface,
+/// including a DAO, which forwards the query to its voting
+/// module. A height of None will query for the current block height.
+pub fn voting_power_at_height(
+    querier: &QuerierWrapper,
+    module: impl Into<String>,
+    address: impl Into<String>,
+    height: Option<u64>,
+) -> StdResult<Uint128> {
+    let response: voting::VotingPowerAtHeightResponse = querier.query_wasm_smart(
+        module,
         &voting::Query::VotingPowerAtHeight {
-            address: address.into_string(),
+            address: address.into(),
             height,
         },
     )?;
     Ok(response.power)
 }
 
-/// A height of None will query for the current block height.
-pub fn get_total_power(deps: Deps, dao: &Addr, height: Option<u64>) -> StdResult<Uint128> {
-    let response: voting::TotalPowerAtHeightResponse = deps
-        .querier
-        .query_wasm_smart(dao, &voting::Query::TotalPowerAtHeight { height })?;
+/// Queries MODULE for the total voting power at HEIGHT. See
+/// `voting_power_at_height` for the contracts MODULE may be. A height
+/// of None will query for the current block height.
+pub fn total_power_at_height(
+    querier: &QuerierWrapper,
+    module: impl Into<String>,
+    height: Option<u64>,
+) -> StdResult<Uint128> {
+    let response: voting::TotalPowerAtHeightResponse =
+        querier.query_wasm_smart(module, &voting::Query::TotalPowerAtHeight { height })?;
     Ok(response.power)
 }
 
@@ -240,6 +263,11 @@ pub fn validate_voting_period(
 
 #[cfg(test)]
 mod test {
+    use cosmwasm_std::{
+        from_slice, testing::MockQuerier, to_binary, ContractResult, Empty, SystemError,
+        SystemResult, WasmQuery,
+    };
+
     use super::*;
 
     #[test]
@@ -451,4 +479,47 @@ mod test {
             Decimal::percent(0)
         ))
     }
+
+    #[test]
+    fn test_voting_module_queries() {
+        let mut querier = MockQuerier::<Empty>::new(&[]);
+        querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "module" => {
+                let response = match from_slice(msg).unwrap() {
+                    voting::Query::VotingPowerAtHeight { address, height } => {
+                        to_binary(&voting::VotingPowerAtHeightResponse {
+                            power: Uint128::new(if address == "ekez" { 10 } else { 0 }),
+                            height: height.unwrap_or(12345),
+                        })
+                    }
+                    voting::Query::TotalPowerAtHeight { height } => {
+                        to_binary(&voting::TotalPowerAtHeightResponse {
+                            power: Uint128::new(100),
+                            height: height.unwrap_or(12345),
+                        })
+                    }
+                    _ => unimplemented!(),
+                };
+                SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+            }
+            _ => SystemResult::Err(SystemError::NoSuchContract {
+                addr: "other".to_string(),
+            }),
+        });
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+
+        assert_eq!(
+            voting_power_at_height(&querier, "module", "ekez", Some(5)).unwrap(),
+            Uint128::new(10)
+        );
+        assert_eq!(
+            voting_power_at_height(&querier, "module", "zeke", None).unwrap(),
+            Uint128::zero()
+        );
+        assert_eq!(
+            total_power_at_height(&querier, "module", None).unwrap(),
+            Uint128::new(100)
+        );
+        assert!(total_power_at_height(&querier, "other", None).is_err());
+    }
 }